@@ -0,0 +1,26 @@
+use std::{
+    fs,
+    io::{self, stdin},
+};
+
+/// Path, that stands for the standard input.
+const STDIN_PATH: &str = "-";
+
+/// Reads the whole source either from the file or from the standard input,
+/// if the path is `-`.
+pub(crate) fn read_source(filepath: &str) -> io::Result<String> {
+    if filepath == STDIN_PATH {
+        io::read_to_string(stdin())
+    } else {
+        fs::read_to_string(filepath)
+    }
+}
+
+/// Returns the name of the source used in messages.
+pub(crate) fn source_name(filepath: &str) -> &str {
+    if filepath == STDIN_PATH {
+        "<stdin>"
+    } else {
+        filepath
+    }
+}
@@ -1,13 +1,43 @@
-use std::{fs, process::exit};
+use std::{collections::HashMap, process::exit};
 
+use clap::ValueEnum;
+use ry_ast::token::RawToken;
 use ry_interner::Interner;
 use ry_lexer::Lexer;
+use serde::Serialize;
 
-use crate::prefix::log_with_prefix;
+use crate::{
+    input::{read_source, source_name},
+    prefix::log_with_prefix,
+};
 
-pub fn command(filepath: &str, show_locations: bool) {
-    match fs::read_to_string(filepath) {
+/// Format in which tokens are printed by the `lex` command.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LexOutputFormat {
+    /// Human readable list of tokens.
+    #[default]
+    Human,
+
+    /// One JSON object per token.
+    Json,
+}
+
+#[derive(Serialize)]
+struct JsonToken<'a> {
+    raw: String,
+    start: usize,
+    end: usize,
+    text: &'a str,
+}
+
+pub fn command(filepath: &str, show_locations: bool, format: LexOutputFormat, count: bool) {
+    match read_source(filepath) {
         Ok(source) => {
+            if count {
+                print_token_counts(&source);
+                return;
+            }
+
             let mut interner = Interner::default();
             let mut lexer = Lexer::new(&source, &mut interner);
             let mut current_token_index = 0;
@@ -17,23 +47,89 @@ pub fn command(filepath: &str, show_locations: bool) {
 
                 if token.raw.eof() {
                     break;
-                } else {
-                    if show_locations {
-                        println!(
-                            "{:08}: [{}]@{}..{}",
-                            current_token_index, token.raw, token.span.start, token.span.end,
-                        );
-                    } else {
-                        println!("{:08}: [{}]", current_token_index, token.raw);
+                }
+
+                match format {
+                    LexOutputFormat::Human => {
+                        if show_locations {
+                            println!(
+                                "{:08}: [{}]@{}..{}",
+                                current_token_index, token.raw, token.span.start, token.span.end,
+                            );
+                        } else {
+                            println!("{:08}: [{}]", current_token_index, token.raw);
+                        }
                     }
+                    LexOutputFormat::Json => {
+                        // Identifiers and strings are printed resolved, other
+                        // tokens as they are written in the source.
+                        let text = match token.raw {
+                            RawToken::Identifier => lexer
+                                .interner
+                                .resolve(lexer.scanned_identifier)
+                                .unwrap_or_default(),
+                            RawToken::StringLiteral => lexer.scanned_string_slice(),
+                            _ => source
+                                .get(token.span.start..token.span.end)
+                                .unwrap_or_default(),
+                        };
+
+                        let json = serde_json::to_string(&JsonToken {
+                            raw: token.raw.to_string(),
+                            start: token.span.start,
+                            end: token.span.end,
+                            text,
+                        })
+                        .expect("Cannot serialize the token");
 
-                    current_token_index += 1;
+                        println!("{json}");
+                    }
                 }
+
+                current_token_index += 1;
             }
         }
         Err(_) => {
-            log_with_prefix("error", ": cannot read given file");
+            log_with_prefix("error", format!(": cannot read {}", source_name(filepath)));
             exit(1);
         }
     }
 }
+
+/// Prints how many tokens of every kind are in the source, the most common
+/// kinds first, followed by the total amount of tokens and errors.
+fn print_token_counts(source: &str) {
+    let mut interner = Interner::default();
+    let mut lexer = Lexer::new(source, &mut interner);
+
+    let mut counts = HashMap::<String, usize>::new();
+    let mut total = 0;
+    let mut errors = 0;
+
+    loop {
+        let token = lexer.next_token();
+
+        if token.raw.eof() {
+            break;
+        }
+
+        if matches!(token.raw, RawToken::Error(..)) {
+            errors += 1;
+        }
+
+        *counts.entry(token.raw.to_string()).or_default() += 1;
+        total += 1;
+    }
+
+    let mut counts = counts.into_iter().collect::<Vec<_>>();
+    counts.sort_by(|(a_kind, a_count), (b_kind, b_count)| {
+        b_count.cmp(a_count).then_with(|| a_kind.cmp(b_kind))
+    });
+
+    for (kind, count) in counts {
+        println!("{count:>8} {kind}");
+    }
+
+    println!("total: {total}");
+    println!("errors: {errors}");
+}
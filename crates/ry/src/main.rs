@@ -48,6 +48,7 @@ use std::env;
 
 use clap::{Parser, Subcommand};
 
+mod input;
 mod lex;
 mod new;
 mod parse;
@@ -70,6 +71,13 @@ enum Commands {
         filepath: String,
         #[arg(long)]
         show_locations: bool,
+        #[arg(long, value_enum, default_value_t)]
+        format: lex::LexOutputFormat,
+        #[arg(
+            long,
+            help = "Print the amount of tokens of every kind instead of tokens"
+        )]
+        count: bool,
     },
     #[command(about = "Parse Ry source file")]
     Parse { filepath: String },
@@ -91,7 +99,9 @@ fn main() {
         Commands::Lex {
             filepath,
             show_locations,
-        } => lex::command(&filepath, show_locations),
+            format,
+            count,
+        } => lex::command(&filepath, show_locations, format, count),
         Commands::Parse { filepath } => {
             parse::command(&filepath);
         }
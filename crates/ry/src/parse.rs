@@ -3,12 +3,18 @@ use std::{io::Write, path::Path, time::Instant};
 use codespan_reporting::diagnostic::Diagnostic;
 use ry_ast::serialize::serialize_ast;
 use ry_diagnostics::{check_file_diagnostics, DiagnosticsEmitter, DiagnosticsStatus};
+use ry_filesystem::file::InMemoryFile;
 use ry_interner::Interner;
-use ry_parser::read_and_parse_module;
+use ry_parser::parse_module;
 
-use crate::{prefix::log_with_left_padded_prefix, unique_file::create_unique_file};
+use crate::{
+    input::{read_source, source_name},
+    prefix::log_with_left_padded_prefix,
+    unique_file::create_unique_file,
+};
 
-pub fn command(path_str: &str) {
+pub fn command(filepath: &str) {
+    let path_str = source_name(filepath);
     let path = Path::new(path_str);
 
     let diagnostics_emitter = DiagnosticsEmitter::new();
@@ -18,17 +24,25 @@ pub fn command(path_str: &str) {
 
     let now = Instant::now();
 
-    match read_and_parse_module(path, &mut diagnostics, &mut interner) {
+    match read_source(filepath).map(|source| {
+        (
+            parse_module(&source, &mut diagnostics, &mut interner),
+            source,
+        )
+    }) {
         Err(..) => {
             diagnostics_emitter.emit_context_free_diagnostic(
                 &Diagnostic::error().with_message(format!("cannot read the file {}", path_str)),
             );
         }
-        Ok(ast) => {
+        Ok((ast, source)) => {
             log_with_left_padded_prefix("Parsed", path_str);
             let parsing_time = now.elapsed().as_secs_f64();
 
-            diagnostics_emitter.emit_file_diagnostics(path, &diagnostics);
+            diagnostics_emitter.emit_in_memory_file_diagnostics(
+                &InMemoryFile::new_from_source(path, source),
+                &diagnostics,
+            );
 
             if check_file_diagnostics(&diagnostics) == DiagnosticsStatus::Ok {
                 log_with_left_padded_prefix("Parsed", format!("in {}s", parsing_time));
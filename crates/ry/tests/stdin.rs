@@ -0,0 +1,71 @@
+use std::{
+    io::Write,
+    process::{Command, Output, Stdio},
+};
+
+/// Runs the CLI with the given arguments, feeding `source` into stdin.
+fn run_with_stdin(arguments: &[&str], source: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ry"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("cannot run the CLI");
+
+    child
+        .stdin
+        .take()
+        .expect("stdin is not piped")
+        .write_all(source.as_bytes())
+        .expect("cannot write to stdin");
+
+    child.wait_with_output().expect("cannot wait for the CLI")
+}
+
+#[test]
+fn lex_stdin() {
+    let output = run_with_stdin(&["lex", "-", "--show-locations"], "fun main() {}");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "00000000: [`fun`]@0..3
+00000001: [<identifier>]@4..8
+00000002: [`(`]@8..9
+00000003: [`)`]@9..10
+00000004: [`{`]@11..12
+00000005: [`}`]@12..13
+"
+    );
+}
+
+#[test]
+fn parse_stdin_diagnostics() {
+    let output = run_with_stdin(&["parse", "-"], "fun main( {}");
+
+    assert!(String::from_utf8_lossy(&output.stdout).contains("<stdin>"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("<stdin>"));
+}
+
+#[test]
+fn lex_count() {
+    let output = run_with_stdin(&["lex", "-", "--count"], "fun main() { a + b; ١ }");
+
+    assert!(output.status.success());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stdout),
+        "       3 <identifier>
+       1 <error>
+       1 `(`
+       1 `)`
+       1 `+`
+       1 `;`
+       1 `fun`
+       1 `{`
+       1 `}`
+total: 11
+errors: 1
+"
+    );
+}
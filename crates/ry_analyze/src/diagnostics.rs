@@ -1,6 +1,6 @@
 //! Defines diagnostics related to scopes.
 
-use ry_diagnostics::{BuildDiagnostic, Diagnostic};
+use ry_diagnostics::{BuildDiagnostic, Diagnostic, DiagnosticCode};
 use ry_filesystem::span::Span;
 
 /// Diagnostics related to scopes.
@@ -15,6 +15,27 @@ pub enum ScopeDiagnostic {
         /// The place where the symbol was tried to be used.
         span: Span,
     },
+
+    /// Variable was defined, but never used.
+    UnusedVariable {
+        /// The symbol itself.
+        symbol: String,
+
+        /// The place where the variable was defined.
+        span: Span,
+    },
+
+    /// Variable shadows another variable defined in the same scope.
+    Shadowed {
+        /// The symbol itself.
+        symbol: String,
+
+        /// The place where the shadowed variable was defined.
+        previous_span: Span,
+
+        /// The place where the new variable was defined.
+        new_span: Span,
+    },
 }
 
 impl BuildDiagnostic for ScopeDiagnostic {
@@ -22,8 +43,30 @@ impl BuildDiagnostic for ScopeDiagnostic {
         match self {
             Self::NotFound { symbol, span } => Diagnostic::error()
                 .with_message(format!("`{symbol}` is not found in this scope"))
-                .with_code("E004")
+                .with_code(DiagnosticCode::NotFound.to_string())
                 .with_labels(vec![span.to_primary_label()]),
+            Self::UnusedVariable { symbol, span } => Diagnostic::warning()
+                .with_message(format!("unused variable `{symbol}`"))
+                .with_code(DiagnosticCode::UnusedVariable.to_string())
+                .with_labels(vec![span.to_primary_label()])
+                .with_notes(vec![format!(
+                    "if this is intentional, prefix it with an underscore: `_{symbol}`"
+                )]),
+            Self::Shadowed {
+                symbol,
+                previous_span,
+                new_span,
+            } => Diagnostic::warning()
+                .with_message(format!(
+                    "variable `{symbol}` shadows a variable in the same scope"
+                ))
+                .with_code(DiagnosticCode::ShadowedVariable.to_string())
+                .with_labels(vec![
+                    new_span.to_primary_label(),
+                    previous_span
+                        .to_secondary_label()
+                        .with_message("previously defined here"),
+                ]),
         }
     }
 }
@@ -62,5 +62,8 @@
     clippy::unnested_or_patterns
 )]
 
+#[cfg(test)]
+use ry_parser as _;
+
 pub mod diagnostics;
 pub mod scope;
@@ -1,7 +1,8 @@
 //! Defines [`Scope`] to work with scopes in statement blocks.
 
-use std::collections::HashMap;
+use std::{cell::Cell, collections::HashMap};
 
+use ry_ast::{FunctionParameter, IdentifierAst, Item, Module, Path};
 use ry_diagnostics::{BuildDiagnostic, Diagnostic};
 use ry_filesystem::span::Span;
 use ry_interner::{Interner, Symbol};
@@ -19,11 +20,90 @@ pub struct ValueConstructor {
     pub ty: Type,
 }
 
+/// Symbols exported by a module, that can be accessed from other modules
+/// using paths, e.g. `std.io.println`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ModuleScope {
+    /// Values defined in the module.
+    values: HashMap<Symbol, ValueConstructor>,
+
+    /// Modules nested in the module.
+    submodules: HashMap<Symbol, Self>,
+}
+
+impl ModuleScope {
+    /// Creates a new empty [`ModuleScope`] instance.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a value to the module.
+    pub fn add_symbol(&mut self, symbol: Symbol, data: ValueConstructor) {
+        self.values.insert(symbol, data);
+    }
+
+    /// Adds a nested module.
+    pub fn add_submodule(&mut self, symbol: Symbol, module: Self) {
+        self.submodules.insert(symbol, module);
+    }
+
+    /// Returns the value with the given name defined in the module.
+    #[must_use]
+    pub fn lookup(&self, symbol: Symbol) -> Option<&ValueConstructor> {
+        self.values.get(&symbol)
+    }
+
+    /// Returns the nested module with the given name.
+    #[must_use]
+    pub fn submodule(&self, symbol: Symbol) -> Option<&Self> {
+        self.submodules.get(&symbol)
+    }
+
+    /// Follows the path through nested modules. Returns the module or the
+    /// first identifier that could not be resolved.
+    fn resolve_module(&self, identifiers: &[IdentifierAst]) -> Result<&Self, IdentifierAst> {
+        identifiers.iter().try_fold(self, |module, identifier| {
+            module.submodule(identifier.symbol).ok_or(*identifier)
+        })
+    }
+}
+
+/// A symbol defined in a scope together with the number of times it was
+/// looked up.
+#[derive(Debug)]
+struct ScopeEntity {
+    data: ValueConstructor,
+    uses: Cell<usize>,
+}
+
+impl ScopeEntity {
+    const fn new(data: ValueConstructor) -> Self {
+        Self {
+            data,
+            uses: Cell::new(0),
+        }
+    }
+}
+
 /// Represents a local scope (a scope that is not a global).
 #[derive(Debug)]
 pub struct Scope<'scope> {
     /// Symbols in this scope (not the ones contained in the parent scopes).
-    entities: HashMap<Symbol, ValueConstructor>,
+    entities: HashMap<Symbol, ScopeEntity>,
+
+    /// Symbols that were shadowed by other symbols in this scope, together
+    /// with the location of the shadowing definition. They are kept to be
+    /// able to report them if they were never used.
+    shadowed: Vec<(Symbol, ScopeEntity, Span)>,
+
+    /// Whether shadowing a symbol in the same scope should be reported, see
+    /// [`Scope::check_shadowed_variables`]. Inherited from the parent scope.
+    shadowing_warnings: bool,
+
+    /// Modules imported in this scope, by their names or aliases.
+    imports: HashMap<Symbol, &'scope ModuleScope>,
 
     /// Parent scope.
     pub parent: Option<&'scope Scope<'scope>>,
@@ -36,25 +116,105 @@ impl<'scope> Scope<'scope> {
     pub fn new(parent: Option<&'scope Scope<'scope>>) -> Self {
         Self {
             entities: HashMap::new(),
+            shadowed: Vec::new(),
+            shadowing_warnings: parent.is_some_and(|parent| parent.shadowing_warnings),
+            imports: HashMap::new(),
             parent,
         }
     }
 
+    /// Enables or disables warnings about symbols shadowing other symbols in
+    /// the same scope. Disabled by default. Scopes created with this scope as
+    /// a parent inherit the setting.
+    #[inline]
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)] // false-positive clippy lint
+    pub fn with_shadowing_warnings(mut self, enabled: bool) -> Self {
+        self.shadowing_warnings = enabled;
+        self
+    }
+
     /// Adds a symbol to this scope.
     pub fn add_symbol(&mut self, symbol: Symbol, data: ValueConstructor) {
+        let origin = data.origin;
+
         // shadowing
-        if self.entities.contains_key(&symbol) {
-            self.entities.remove(&symbol);
+        if let Some(shadowed) = self.entities.insert(symbol, ScopeEntity::new(data)) {
+            self.shadowed.push((symbol, shadowed, origin));
+        }
+    }
+
+    /// Adds parameters of a function to this scope, the same way as `let`
+    /// bindings are added, so they are reported by
+    /// [`Scope::check_unused_variables`] if never used, unless their names
+    /// start with `_`. `self` parameter is skipped.
+    ///
+    /// Types of the parameters are resolved with `resolve_type`.
+    pub fn add_function_parameters(
+        &mut self,
+        parameters: &[FunctionParameter],
+        mut resolve_type: impl FnMut(&ry_ast::Type) -> Type,
+    ) {
+        for parameter in parameters {
+            if let FunctionParameter::Just(parameter) = parameter {
+                self.add_symbol(
+                    parameter.name.symbol,
+                    ValueConstructor {
+                        origin: parameter.name.span,
+                        ty: resolve_type(&parameter.ty),
+                    },
+                );
+            }
+        }
+    }
+
+    /// Adds a module imported with the given name (last segment of the import path
+    /// or an alias) to this scope.
+    pub fn add_import(&mut self, symbol: Symbol, module: &'scope ModuleScope) {
+        self.imports.insert(symbol, module);
+    }
+
+    /// Adds modules imported in the `import` items of `module` into this scope.
+    /// Import paths are resolved relatively to the `root` module.
+    ///
+    /// Imports, that cannot be resolved, are skipped: the error is reported
+    /// when the imported name is used.
+    pub fn add_imports(&mut self, module: &Module, root: &'scope ModuleScope) {
+        for item in &module.items {
+            let Item::Import { path, .. } = item else {
+                continue;
+            };
+
+            let Some(name) = path.r#as.or_else(|| path.left.identifiers.last().copied()) else {
+                continue;
+            };
+
+            if let Ok(imported) = root.resolve_module(&path.left.identifiers) {
+                self.add_import(name.symbol, imported);
+            }
         }
+    }
 
-        self.entities.insert(symbol, data);
+    /// Returns the module imported with the given name in this scope or one
+    /// of the parent scopes.
+    fn lookup_import(&self, symbol: Symbol) -> Option<&'scope ModuleScope> {
+        if let Some(module) = self.imports.get(&symbol) {
+            Some(module)
+        } else if let Some(parent) = self.parent {
+            parent.lookup_import(symbol)
+        } else {
+            None
+        }
     }
 
     /// Returns the symbol data for the given symbol. If the symbol is not in this scope, `None` is returned.
+    ///
+    /// The symbol is marked as used.
     #[must_use]
     pub fn lookup(&self, symbol: Symbol) -> Option<&ValueConstructor> {
-        if let data @ Some(..) = self.entities.get(&symbol) {
-            data
+        if let Some(entity) = self.entities.get(&symbol) {
+            entity.uses.set(entity.uses.get() + 1);
+            Some(&entity.data)
         } else if let Some(parent) = self.parent {
             parent.lookup(symbol)
         } else {
@@ -95,4 +255,134 @@ impl<'scope> Scope<'scope> {
             None
         }
     }
+
+    /// Returns the symbol data for the given path. Single identifier paths are
+    /// looked up in the scope, other paths are resolved against the imported
+    /// modules: the first identifier is matched against the import names and
+    /// the rest are followed through the nested modules.
+    #[must_use]
+    pub fn resolve_path(&self, path: &Path) -> Option<&ValueConstructor> {
+        self.resolve_path_segments(&path.identifiers).ok()
+    }
+
+    /// Returns the symbol data for the given path (see [`Scope::resolve_path`]).
+    /// If the path cannot be resolved, `None` is returned and the error with
+    /// the span of the first unresolved identifier will be added into `diagnostics`.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if the symbol is not interned in the `interner`.
+    pub fn resolve_path_or_save_diagnostic(
+        &self,
+        path: &Path,
+        interner: &Interner,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) -> Option<&ValueConstructor> {
+        match self.resolve_path_segments(&path.identifiers) {
+            Ok(data) => Some(data),
+            Err(None) => None,
+            Err(Some(identifier)) => {
+                diagnostics.push(
+                    ScopeDiagnostic::NotFound {
+                        symbol: interner
+                            .resolve(identifier.symbol)
+                            .unwrap_or_else(|| {
+                                panic!("Symbol {} cannot be resolved", identifier.symbol)
+                            })
+                            .to_owned(),
+                        span: identifier.span,
+                    }
+                    .build(),
+                );
+                None
+            }
+        }
+    }
+
+    /// Resolves the path. Returns the symbol data or the first identifier that
+    /// could not be resolved (`None` if the path is empty).
+    fn resolve_path_segments(
+        &self,
+        identifiers: &[IdentifierAst],
+    ) -> Result<&ValueConstructor, Option<IdentifierAst>> {
+        match identifiers {
+            [] => Err(None),
+            [name] => self.lookup(name.symbol).ok_or(Some(*name)),
+            [module, inner @ .., name] => self
+                .lookup_import(module.symbol)
+                .ok_or(*module)
+                .and_then(|module| module.resolve_module(inner))
+                .map_err(Some)?
+                .lookup(name.symbol)
+                .ok_or(Some(*name)),
+        }
+    }
+
+    /// Adds a diagnostic into `diagnostics` for every symbol defined in this
+    /// scope (not in the parent scopes) that was never looked up. Symbols
+    /// starting with `_` are not reported.
+    ///
+    /// Should be called after the block corresponding to the scope is analyzed.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if some symbol is not interned in the `interner`.
+    pub fn check_unused_variables(&self, interner: &Interner, diagnostics: &mut Vec<Diagnostic>) {
+        let mut unused = self
+            .entities
+            .iter()
+            .chain(
+                self.shadowed
+                    .iter()
+                    .map(|(symbol, entity, _)| (symbol, entity)),
+            )
+            .filter(|(_, entity)| entity.uses.get() == 0)
+            .map(|(symbol, entity)| {
+                (
+                    interner
+                        .resolve(*symbol)
+                        .unwrap_or_else(|| panic!("Symbol {symbol} cannot be resolved")),
+                    entity.data.origin,
+                )
+            })
+            .filter(|(name, _)| !name.starts_with('_'))
+            .collect::<Vec<_>>();
+
+        // Report variables in the order they were defined.
+        unused.sort_by_key(|(_, span)| span.start);
+
+        diagnostics.extend(unused.into_iter().map(|(symbol, span)| {
+            ScopeDiagnostic::UnusedVariable {
+                symbol: symbol.to_owned(),
+                span,
+            }
+            .build()
+        }));
+    }
+
+    /// Adds a warning into `diagnostics` for every symbol that shadowed
+    /// another symbol defined in this scope, if shadowing warnings are
+    /// enabled (see [`Scope::with_shadowing_warnings`]). Shadowing a symbol
+    /// from a parent scope is not reported.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if some symbol is not interned in the `interner`.
+    pub fn check_shadowed_variables(&self, interner: &Interner, diagnostics: &mut Vec<Diagnostic>) {
+        if !self.shadowing_warnings {
+            return;
+        }
+
+        diagnostics.extend(self.shadowed.iter().map(|(symbol, entity, new_span)| {
+            ScopeDiagnostic::Shadowed {
+                symbol: interner
+                    .resolve(*symbol)
+                    .unwrap_or_else(|| panic!("Symbol {symbol} cannot be resolved"))
+                    .to_owned(),
+                previous_span: entity.data.origin,
+                new_span: *new_span,
+            }
+            .build()
+        }));
+    }
 }
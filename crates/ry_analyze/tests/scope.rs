@@ -1,7 +1,10 @@
-use ry_analyze::scope::{Scope, ValueConstructor};
-use ry_filesystem::span::DUMMY_SPAN;
+use ry_analyze::scope::{ModuleScope, Scope, ValueConstructor};
+use ry_ast::{IdentifierAst, ImportPath, Item, Module, Path};
+use ry_diagnostics::Diagnostic;
+use ry_filesystem::span::{Span, DUMMY_SPAN};
 use ry_interner::Interner;
-use ry_typed_ast::ty::{string, uint8};
+use ry_parser::parse_item;
+use ry_typed_ast::ty::{string, uint32, uint8};
 
 // ```
 // let a = 1;
@@ -173,3 +176,388 @@ fn inherited_scope_shadowed_variable_lookup() {
         })
     );
 }
+
+// ```
+// let a = 1;
+// a
+// ```
+#[test]
+fn used_variable() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern("a");
+    let mut diagnostics = vec![];
+
+    let mut scope = Scope::new(None);
+    scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 4, end: 5 },
+            ty: uint8(),
+        },
+    );
+
+    let _ = scope.lookup(a);
+
+    scope.check_unused_variables(&interner, &mut diagnostics);
+    assert!(diagnostics.is_empty());
+}
+
+// ```
+// let a = 1;
+// let _b = 2;
+// {
+//   let c = a;
+// }
+// ```
+#[test]
+fn unused_variable() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern("a");
+    let b = interner.get_or_intern("_b");
+    let c = interner.get_or_intern("c");
+    let mut diagnostics = vec![];
+
+    let mut parent_scope = Scope::new(None);
+    parent_scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 4, end: 5 },
+            ty: uint8(),
+        },
+    );
+    parent_scope.add_symbol(
+        b,
+        ValueConstructor {
+            origin: Span { start: 15, end: 17 },
+            ty: uint8(),
+        },
+    );
+
+    let mut inner_scope = Scope::new(Some(&parent_scope));
+    inner_scope.add_symbol(
+        c,
+        ValueConstructor {
+            origin: Span { start: 34, end: 35 },
+            ty: uint8(),
+        },
+    );
+
+    // used in the inner scope
+    let _ = inner_scope.lookup(a);
+
+    inner_scope.check_unused_variables(&interner, &mut diagnostics);
+    parent_scope.check_unused_variables(&interner, &mut diagnostics);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "unused variable `c`");
+    assert_eq!(diagnostics[0].labels[0].range, 34..35);
+}
+
+// ```
+// let a = 1;
+// let a = "hello"; // shadowing, the first `a` is never used
+// a
+// ```
+#[test]
+fn unused_shadowed_variable() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern("a");
+    let mut diagnostics = vec![];
+
+    let mut scope = Scope::new(None);
+    scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 4, end: 5 },
+            ty: uint8(),
+        },
+    );
+    scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 15, end: 16 },
+            ty: string(),
+        },
+    );
+
+    let _ = scope.lookup(a);
+
+    scope.check_unused_variables(&interner, &mut diagnostics);
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].labels[0].range, 4..5);
+}
+
+/// Adds parameters of the function defined in `source` into a new scope and
+/// returns diagnostics about unused ones.
+fn unused_parameters(source: &str) -> Vec<Diagnostic> {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Function(function)) = parse_item(source, &mut diagnostics, &mut interner) else {
+        panic!("failed to parse `{source}`");
+    };
+    assert!(diagnostics.is_empty());
+
+    let mut scope = Scope::new(None);
+    scope.add_function_parameters(&function.parameters, |_| uint32());
+    scope.check_unused_variables(&interner, &mut diagnostics);
+
+    diagnostics
+}
+
+#[test]
+fn unused_parameter() {
+    assert!(unused_parameters("fun f(_unused: uint32) {}").is_empty());
+    assert!(unused_parameters("fun f(self, _: uint32) {}").is_empty());
+
+    let diagnostics = unused_parameters("fun f(used: uint32) {}");
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].message, "unused variable `used`");
+    assert_eq!(diagnostics[0].labels[0].range, 6..10);
+}
+
+// ```
+// let a = 3;
+// let a = "hello world"; // shadowing
+// ```
+#[test]
+fn same_scope_shadowing_warning() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern("a");
+
+    for enabled in [true, false] {
+        let mut diagnostics = vec![];
+
+        let mut scope = Scope::new(None).with_shadowing_warnings(enabled);
+        scope.add_symbol(
+            a,
+            ValueConstructor {
+                origin: Span { start: 4, end: 5 },
+                ty: uint8(),
+            },
+        );
+        scope.add_symbol(
+            a,
+            ValueConstructor {
+                origin: Span { start: 15, end: 16 },
+                ty: string(),
+            },
+        );
+
+        scope.check_shadowed_variables(&interner, &mut diagnostics);
+
+        if enabled {
+            assert_eq!(diagnostics.len(), 1);
+            assert_eq!(diagnostics[0].labels[0].range, 15..16);
+            assert_eq!(diagnostics[0].labels[1].range, 4..5);
+        } else {
+            assert!(diagnostics.is_empty());
+        }
+    }
+}
+
+// ```
+// let a = 3;
+// {
+//   let a = "hello world"; // shadowing in an inner scope
+// }
+// ```
+#[test]
+fn inner_scope_shadowing_is_silent() {
+    let mut interner = Interner::default();
+    let a = interner.get_or_intern("a");
+    let mut diagnostics = vec![];
+
+    let mut parent_scope = Scope::new(None).with_shadowing_warnings(true);
+    parent_scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 4, end: 5 },
+            ty: uint8(),
+        },
+    );
+
+    let mut inner_scope = Scope::new(Some(&parent_scope));
+    inner_scope.add_symbol(
+        a,
+        ValueConstructor {
+            origin: Span { start: 21, end: 22 },
+            ty: string(),
+        },
+    );
+
+    inner_scope.check_shadowed_variables(&interner, &mut diagnostics);
+    parent_scope.check_shadowed_variables(&interner, &mut diagnostics);
+
+    assert!(diagnostics.is_empty());
+}
+
+/// Builds a path from identifiers separated with `.`, assuming that the path
+/// starts at offset 0.
+fn path(source: &str, interner: &mut Interner) -> Path {
+    let mut start = 0;
+
+    let identifiers = source
+        .split('.')
+        .map(|identifier| {
+            let span = Span {
+                start,
+                end: start + identifier.len(),
+            };
+            start = span.end + 1;
+
+            IdentifierAst {
+                span,
+                symbol: interner.get_or_intern(identifier),
+            }
+        })
+        .collect();
+
+    Path {
+        span: Span {
+            start: 0,
+            end: source.len(),
+        },
+        identifiers,
+    }
+}
+
+/// Builds a `std` module containing `std.io.println`.
+fn std_module(interner: &mut Interner) -> ModuleScope {
+    let mut io = ModuleScope::new();
+    io.add_symbol(
+        interner.get_or_intern("println"),
+        ValueConstructor {
+            origin: DUMMY_SPAN,
+            ty: string(),
+        },
+    );
+
+    let mut std = ModuleScope::new();
+    std.add_submodule(interner.get_or_intern("io"), io);
+
+    let mut root = ModuleScope::new();
+    root.add_submodule(interner.get_or_intern("std"), std);
+    root
+}
+
+// ```
+// import std.io;
+// import std.io as myio;
+//
+// io.println
+// myio.println
+// std.io.println // `std` is not imported
+// ```
+#[test]
+fn resolvable_path() {
+    let mut interner = Interner::default();
+    let root = std_module(&mut interner);
+
+    let module = Module {
+        items: vec![
+            Item::Import {
+                attributes: vec![],
+                path: ImportPath {
+                    left: path("std.io", &mut interner),
+                    r#as: None,
+                },
+            },
+            Item::Import {
+                attributes: vec![],
+                path: ImportPath {
+                    left: path("std.io", &mut interner),
+                    r#as: Some(IdentifierAst {
+                        span: DUMMY_SPAN,
+                        symbol: interner.get_or_intern("myio"),
+                    }),
+                },
+            },
+        ],
+        docstring: None,
+    };
+
+    let mut scope = Scope::new(None);
+    scope.add_imports(&module, &root);
+
+    let expected = Some(&ValueConstructor {
+        origin: DUMMY_SPAN,
+        ty: string(),
+    });
+
+    assert_eq!(
+        scope.resolve_path(&path("io.println", &mut interner)),
+        expected
+    );
+    assert_eq!(
+        scope.resolve_path(&path("myio.println", &mut interner)),
+        expected
+    );
+    assert_eq!(
+        Scope::new(Some(&scope)).resolve_path(&path("io.println", &mut interner)),
+        expected
+    );
+    assert_eq!(
+        scope.resolve_path(&path("std.io.println", &mut interner)),
+        None
+    );
+}
+
+// ```
+// import std.io;
+//
+// io.print
+// ```
+#[test]
+fn unresolvable_path() {
+    let mut interner = Interner::default();
+    let root = std_module(&mut interner);
+    let mut diagnostics = vec![];
+
+    let mut scope = Scope::new(None);
+    scope.add_import(
+        interner.get_or_intern("io"),
+        root.submodule(interner.get_or_intern("std"))
+            .and_then(|std| std.submodule(interner.get_or_intern("io")))
+            .unwrap(),
+    );
+
+    let unresolved = path("io.print", &mut interner);
+    assert_eq!(
+        scope.resolve_path_or_save_diagnostic(&unresolved, &interner, &mut diagnostics),
+        None
+    );
+
+    let unresolved = path("fs.read", &mut interner);
+    assert_eq!(
+        scope.resolve_path_or_save_diagnostic(&unresolved, &interner, &mut diagnostics),
+        None
+    );
+
+    assert_eq!(diagnostics.len(), 2);
+    assert_eq!(diagnostics[0].message, "`print` is not found in this scope");
+    assert_eq!(diagnostics[0].labels[0].range, 3..8);
+    assert_eq!(diagnostics[1].message, "`fs` is not found in this scope");
+    assert_eq!(diagnostics[1].labels[0].range, 0..2);
+}
+
+#[test]
+fn empty_path() {
+    let interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let empty = Path {
+        span: DUMMY_SPAN,
+        identifiers: vec![],
+    };
+    let scope = Scope::new(None);
+
+    assert_eq!(scope.resolve_path(&empty), None);
+    assert_eq!(
+        scope.resolve_path_or_save_diagnostic(&empty, &interner, &mut diagnostics),
+        None
+    );
+    assert!(diagnostics.is_empty());
+}
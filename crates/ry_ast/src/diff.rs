@@ -0,0 +1,681 @@
+//! Defines [`diff_modules`] for locating the first difference between two
+//! ASTs, which makes failing parser tests much easier to debug.
+//!
+//! Both trees are walked in lockstep. Items, statements and expressions are
+//! compared node by node, while smaller nodes (types, patterns, identifiers,
+//! etc.) are compared as a whole.
+
+use crate::{
+    Expression, Function, Impl, Item, MatchExpressionItem, Module, Statement, StructExpressionItem,
+    TraitItem,
+};
+
+/// Path to the first difference relative to the nodes being compared. Empty
+/// path means that the nodes themselves differ (for example they are
+/// different enum variants).
+type Difference = Option<String>;
+
+/// Returns a human-readable path to the first node, in which two modules
+/// differ, or [`None`], if the modules are equal.
+///
+/// # Example
+/// ```
+/// # use ry_ast::{diff::diff_modules, Expression, Function, Item, Literal, Module, Statement, IdentifierAst, Visibility};
+/// # use ry_filesystem::span::{Span, DUMMY_SPAN};
+/// let module = |value| Module {
+///     items: vec![Item::Function(Function {
+///         visibility: Visibility::private(),
+///         name: IdentifierAst { span: DUMMY_SPAN, symbol: 0 },
+///         generic_parameters: None,
+///         parameters: vec![],
+///         return_type: None,
+///         where_clause: None,
+///         body: Some(vec![Statement::Return {
+///             span: DUMMY_SPAN,
+///             expression: Some(Expression::Literal(Literal::Integer { value, span: DUMMY_SPAN })),
+///         }]),
+///         attributes: vec![],
+///         docstring: None,
+///     })],
+///     docstring: None,
+/// };
+///
+/// assert_eq!(diff_modules(&module(1), &module(1)), None);
+/// assert_eq!(
+///     diff_modules(&module(1), &module(2)).as_deref(),
+///     Some("items[0].Function.body[0].Return.Literal")
+/// );
+/// ```
+#[must_use]
+pub fn diff_modules(a: &Module, b: &Module) -> Option<String> {
+    if a == b {
+        return None;
+    }
+
+    leaf("docstring", &a.docstring, &b.docstring)
+        .or_else(|| list("items", &a.items, &b.items, diff_items))
+        .or_else(|| Some(String::new()))
+}
+
+fn join(name: &str, path: &str) -> String {
+    if path.is_empty() {
+        name.to_owned()
+    } else {
+        format!("{name}.{path}")
+    }
+}
+
+/// Compares nodes as a whole.
+fn equal<T: PartialEq>(a: &T, b: &T) -> Difference {
+    (a != b).then(String::new)
+}
+
+fn leaf<T: PartialEq>(name: &str, a: &T, b: &T) -> Difference {
+    (a != b).then(|| name.to_owned())
+}
+
+fn node<T>(name: &str, a: &T, b: &T, diff: fn(&T, &T) -> Difference) -> Difference {
+    diff(a, b).map(|path| join(name, &path))
+}
+
+fn optional<T: PartialEq>(
+    name: &str,
+    a: Option<&T>,
+    b: Option<&T>,
+    diff: fn(&T, &T) -> Difference,
+) -> Difference {
+    match (a, b) {
+        (Some(a), Some(b)) => node(name, a, b, diff),
+        _ => leaf(name, &a, &b),
+    }
+}
+
+fn list<T>(name: &str, a: &[T], b: &[T], diff: fn(&T, &T) -> Difference) -> Difference {
+    for (idx, (a, b)) in a.iter().zip(b).enumerate() {
+        if let Some(path) = diff(a, b) {
+            return Some(join(&format!("{name}[{idx}]"), &path));
+        }
+    }
+
+    (a.len() != b.len()).then(|| format!("{name}[{}]", a.len().min(b.len())))
+}
+
+/// Joins the name of the variant with a path to the difference inside of it.
+/// When the difference cannot be located more precisely (for instance, only
+/// spans differ), the path points to the variant itself.
+fn variant(name: &str, path: Difference) -> String {
+    path.map_or_else(|| name.to_owned(), |path| join(name, &path))
+}
+
+fn diff_items(a: &Item, b: &Item) -> Difference {
+    if a == b {
+        return None;
+    }
+
+    Some(match (a, b) {
+        (Item::Function(a), Item::Function(b)) => variant("Function", diff_functions(a, b)),
+        (Item::Impl(a), Item::Impl(b)) => variant("Impl", diff_impls(a, b)),
+        (
+            Item::Trait {
+                items: a_items,
+                attributes: a_attributes,
+                docstring: a_docstring,
+                ..
+            },
+            Item::Trait {
+                items: b_items,
+                attributes: b_attributes,
+                docstring: b_docstring,
+                ..
+            },
+        ) => variant(
+            "Trait",
+            list("items", a_items, b_items, diff_trait_items)
+                .or_else(|| leaf("attributes", a_attributes, b_attributes))
+                .or_else(|| leaf("docstring", a_docstring, b_docstring)),
+        ),
+        (
+            Item::Const {
+                value: a_value,
+                ty: a_ty,
+                ..
+            },
+            Item::Const {
+                value: b_value,
+                ty: b_ty,
+                ..
+            },
+        ) => variant(
+            "Const",
+            leaf("ty", a_ty, b_ty).or_else(|| node("value", a_value, b_value, diff_expressions)),
+        ),
+        (
+            Item::Static {
+                value: a_value,
+                ty: a_ty,
+                ..
+            },
+            Item::Static {
+                value: b_value,
+                ty: b_ty,
+                ..
+            },
+        ) => variant(
+            "Static",
+            leaf("ty", a_ty, b_ty).or_else(|| node("value", a_value, b_value, diff_expressions)),
+        ),
+        (Item::Enum { .. }, Item::Enum { .. }) => variant("Enum", None),
+        (Item::Import { .. }, Item::Import { .. }) => variant("Import", None),
+        (Item::Struct { .. }, Item::Struct { .. }) => variant("Struct", None),
+        (Item::TupleLikeStruct { .. }, Item::TupleLikeStruct { .. }) => {
+            variant("TupleLikeStruct", None)
+        }
+        (Item::TypeAlias(..), Item::TypeAlias(..)) => variant("TypeAlias", None),
+        _ => String::new(),
+    })
+}
+
+fn diff_trait_items(a: &TraitItem, b: &TraitItem) -> Difference {
+    if a == b {
+        return None;
+    }
+
+    Some(match (a, b) {
+        (TraitItem::AssociatedFunction(a), TraitItem::AssociatedFunction(b)) => {
+            variant("AssociatedFunction", diff_functions(a, b))
+        }
+        (TraitItem::TypeAlias(..), TraitItem::TypeAlias(..)) => variant("TypeAlias", None),
+        _ => String::new(),
+    })
+}
+
+fn diff_functions(a: &Function, b: &Function) -> Difference {
+    leaf("visibility", &a.visibility, &b.visibility)
+        .or_else(|| leaf("name", &a.name, &b.name))
+        .or_else(|| {
+            leaf(
+                "generic_parameters",
+                &a.generic_parameters,
+                &b.generic_parameters,
+            )
+        })
+        .or_else(|| list("parameters", &a.parameters, &b.parameters, equal))
+        .or_else(|| leaf("return_type", &a.return_type, &b.return_type))
+        .or_else(|| leaf("where_clause", &a.where_clause, &b.where_clause))
+        .or_else(|| match (&a.body, &b.body) {
+            (Some(a), Some(b)) => list("body", a, b, diff_statements),
+            _ => leaf("body", &a.body, &b.body),
+        })
+        .or_else(|| leaf("attributes", &a.attributes, &b.attributes))
+        .or_else(|| leaf("docstring", &a.docstring, &b.docstring))
+}
+
+fn diff_impls(a: &Impl, b: &Impl) -> Difference {
+    leaf(
+        "generic_parameters",
+        &a.generic_parameters,
+        &b.generic_parameters,
+    )
+    .or_else(|| leaf("ty", &a.ty, &b.ty))
+    .or_else(|| leaf("trait", &a.r#trait, &b.r#trait))
+    .or_else(|| leaf("where_clause", &a.where_clause, &b.where_clause))
+    .or_else(|| list("items", &a.items, &b.items, diff_trait_items))
+    .or_else(|| leaf("attributes", &a.attributes, &b.attributes))
+    .or_else(|| leaf("docstring", &a.docstring, &b.docstring))
+}
+
+fn diff_statements(a: &Statement, b: &Statement) -> Difference {
+    if a == b {
+        return None;
+    }
+
+    Some(match (a, b) {
+        (
+            Statement::Expression {
+                expression: a_expression,
+                has_semicolon: a_has_semicolon,
+            },
+            Statement::Expression {
+                expression: b_expression,
+                has_semicolon: b_has_semicolon,
+            },
+        ) => variant(
+            "Expression",
+            diff_expressions(a_expression, b_expression)
+                .or_else(|| leaf("has_semicolon", a_has_semicolon, b_has_semicolon)),
+        ),
+        (Statement::Defer { call: a }, Statement::Defer { call: b }) => {
+            variant("Defer", diff_expressions(a, b))
+        }
+        (Statement::Return { expression: a, .. }, Statement::Return { expression: b, .. }) => {
+            variant(
+                "Return",
+                match (a, b) {
+                    (Some(a), Some(b)) => diff_expressions(a, b),
+                    _ => leaf("expression", a, b),
+                },
+            )
+        }
+        (
+            Statement::Let {
+                pattern: a_pattern,
+                value: a_value,
+                ty: a_ty,
+            },
+            Statement::Let {
+                pattern: b_pattern,
+                value: b_value,
+                ty: b_ty,
+            },
+        ) => variant(
+            "Let",
+            leaf("pattern", a_pattern, b_pattern)
+                .or_else(|| leaf("ty", a_ty, b_ty))
+                .or_else(|| node("value", a_value, b_value, diff_expressions)),
+        ),
+        (Statement::Break { label: a_label, .. }, Statement::Break { label: b_label, .. }) => {
+            variant("Break", leaf("label", a_label, b_label))
+        }
+        (
+            Statement::Continue { label: a_label, .. },
+            Statement::Continue { label: b_label, .. },
+        ) => variant("Continue", leaf("label", a_label, b_label)),
+        _ => String::new(),
+    })
+}
+
+fn diff_expressions(a: &Expression, b: &Expression) -> Difference {
+    if a == b {
+        return None;
+    }
+
+    Some(match (a, b) {
+        (
+            Expression::As {
+                left: a_left,
+                right: a_right,
+                ..
+            },
+            Expression::As {
+                left: b_left,
+                right: b_right,
+                ..
+            },
+        ) => variant(
+            "As",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| leaf("right", a_right, b_right)),
+        ),
+        (
+            Expression::TupleFieldAccess {
+                left: a_left,
+                index: a_index,
+                ..
+            },
+            Expression::TupleFieldAccess {
+                left: b_left,
+                index: b_index,
+                ..
+            },
+        ) => variant(
+            "TupleFieldAccess",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| leaf("index", a_index, b_index)),
+        ),
+        (
+            Expression::Binary {
+                left: a_left,
+                operator: a_operator,
+                right: a_right,
+                ..
+            },
+            Expression::Binary {
+                left: b_left,
+                operator: b_operator,
+                right: b_right,
+                ..
+            },
+        ) => variant(
+            "Binary",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| leaf("operator", a_operator, b_operator))
+                .or_else(|| {
+                    node(
+                        "right",
+                        a_right.as_ref(),
+                        b_right.as_ref(),
+                        diff_expressions,
+                    )
+                }),
+        ),
+        (
+            Expression::Call {
+                left: a_left,
+                arguments: a_arguments,
+                ..
+            },
+            Expression::Call {
+                left: b_left,
+                arguments: b_arguments,
+                ..
+            },
+        ) => variant(
+            "Call",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| list("arguments", a_arguments, b_arguments, diff_expressions)),
+        ),
+        (
+            Expression::Index {
+                left: a_left,
+                index: a_index,
+                ..
+            },
+            Expression::Index {
+                left: b_left,
+                index: b_index,
+                ..
+            },
+        ) => variant(
+            "Index",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions).or_else(|| {
+                node(
+                    "index",
+                    a_index.as_ref(),
+                    b_index.as_ref(),
+                    diff_expressions,
+                )
+            }),
+        ),
+        (
+            Expression::FieldAccess {
+                left: a_left,
+                right: a_right,
+                ..
+            },
+            Expression::FieldAccess {
+                left: b_left,
+                right: b_right,
+                ..
+            },
+        ) => variant(
+            "FieldAccess",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| leaf("right", a_right, b_right)),
+        ),
+        (
+            Expression::GenericArguments {
+                left: a_left,
+                generic_arguments: a_generic_arguments,
+                ..
+            },
+            Expression::GenericArguments {
+                left: b_left,
+                generic_arguments: b_generic_arguments,
+                ..
+            },
+        ) => variant(
+            "GenericArguments",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions).or_else(|| {
+                list(
+                    "generic_arguments",
+                    a_generic_arguments,
+                    b_generic_arguments,
+                    equal,
+                )
+            }),
+        ),
+        (
+            Expression::If {
+                if_blocks: a_if_blocks,
+                r#else: a_else,
+                ..
+            },
+            Expression::If {
+                if_blocks: b_if_blocks,
+                r#else: b_else,
+                ..
+            },
+        ) => variant(
+            "If",
+            list("if_blocks", a_if_blocks, b_if_blocks, |a, b| {
+                node("condition", &a.0, &b.0, diff_expressions)
+                    .or_else(|| list("block", &a.1, &b.1, diff_statements))
+            })
+            .or_else(|| match (a_else, b_else) {
+                (Some(a), Some(b)) => list("else", a, b, diff_statements),
+                _ => leaf("else", a_else, b_else),
+            }),
+        ),
+        (
+            Expression::Lambda {
+                parameters: a_parameters,
+                return_type: a_return_type,
+                block: a_block,
+                ..
+            },
+            Expression::Lambda {
+                parameters: b_parameters,
+                return_type: b_return_type,
+                block: b_block,
+                ..
+            },
+        ) => variant(
+            "Lambda",
+            list("parameters", a_parameters, b_parameters, equal)
+                .or_else(|| leaf("return_type", a_return_type, b_return_type))
+                .or_else(|| list("block", a_block, b_block, diff_statements)),
+        ),
+        (
+            Expression::List {
+                elements: a_elements,
+                ..
+            },
+            Expression::List {
+                elements: b_elements,
+                ..
+            },
+        ) => variant(
+            "List",
+            list("elements", a_elements, b_elements, diff_expressions),
+        ),
+        (
+            Expression::Match {
+                expression: a_expression,
+                block: a_block,
+                ..
+            },
+            Expression::Match {
+                expression: b_expression,
+                block: b_block,
+                ..
+            },
+        ) => variant(
+            "Match",
+            node(
+                "expression",
+                a_expression.as_ref(),
+                b_expression.as_ref(),
+                diff_expressions,
+            )
+            .or_else(|| list("block", a_block, b_block, diff_match_expression_items)),
+        ),
+        (
+            Expression::Parenthesized { inner: a_inner, .. },
+            Expression::Parenthesized { inner: b_inner, .. },
+        ) => variant("Parenthesized", diff_expressions(a_inner, b_inner)),
+        (
+            Expression::Prefix {
+                inner: a_inner,
+                operator: a_operator,
+                ..
+            },
+            Expression::Prefix {
+                inner: b_inner,
+                operator: b_operator,
+                ..
+            },
+        ) => variant(
+            "Prefix",
+            leaf("operator", a_operator, b_operator).or_else(|| {
+                node(
+                    "inner",
+                    a_inner.as_ref(),
+                    b_inner.as_ref(),
+                    diff_expressions,
+                )
+            }),
+        ),
+        (
+            Expression::Postfix {
+                inner: a_inner,
+                operator: a_operator,
+                ..
+            },
+            Expression::Postfix {
+                inner: b_inner,
+                operator: b_operator,
+                ..
+            },
+        ) => variant(
+            "Postfix",
+            node(
+                "inner",
+                a_inner.as_ref(),
+                b_inner.as_ref(),
+                diff_expressions,
+            )
+            .or_else(|| leaf("operator", a_operator, b_operator)),
+        ),
+        (
+            Expression::Range {
+                start: a_start,
+                end: a_end,
+                inclusive: a_inclusive,
+                ..
+            },
+            Expression::Range {
+                start: b_start,
+                end: b_end,
+                inclusive: b_inclusive,
+                ..
+            },
+        ) => variant(
+            "Range",
+            optional("start", a_start.as_ref(), b_start.as_ref(), |a, b| {
+                diff_expressions(a, b)
+            })
+            .or_else(|| leaf("inclusive", a_inclusive, b_inclusive))
+            .or_else(|| {
+                optional("end", a_end.as_ref(), b_end.as_ref(), |a, b| {
+                    diff_expressions(a, b)
+                })
+            }),
+        ),
+        (
+            Expression::StatementsBlock { block: a_block, .. },
+            Expression::StatementsBlock { block: b_block, .. },
+        ) => variant(
+            "StatementsBlock",
+            list("block", a_block, b_block, diff_statements),
+        ),
+        (
+            Expression::Struct {
+                left: a_left,
+                fields: a_fields,
+                ..
+            },
+            Expression::Struct {
+                left: b_left,
+                fields: b_fields,
+                ..
+            },
+        ) => variant(
+            "Struct",
+            node("left", a_left.as_ref(), b_left.as_ref(), diff_expressions)
+                .or_else(|| list("fields", a_fields, b_fields, diff_struct_expression_items)),
+        ),
+        (
+            Expression::Tuple {
+                elements: a_elements,
+                ..
+            },
+            Expression::Tuple {
+                elements: b_elements,
+                ..
+            },
+        ) => variant(
+            "Tuple",
+            list("elements", a_elements, b_elements, diff_expressions),
+        ),
+        (
+            Expression::While {
+                label: a_label,
+                condition: a_condition,
+                body: a_body,
+                ..
+            },
+            Expression::While {
+                label: b_label,
+                condition: b_condition,
+                body: b_body,
+                ..
+            },
+        ) => variant(
+            "While",
+            leaf("label", a_label, b_label)
+                .or_else(|| {
+                    node(
+                        "condition",
+                        a_condition.as_ref(),
+                        b_condition.as_ref(),
+                        diff_expressions,
+                    )
+                })
+                .or_else(|| list("body", a_body, b_body, diff_statements)),
+        ),
+        (
+            Expression::For {
+                pattern: a_pattern,
+                iterable: a_iterable,
+                body: a_body,
+                ..
+            },
+            Expression::For {
+                pattern: b_pattern,
+                iterable: b_iterable,
+                body: b_body,
+                ..
+            },
+        ) => variant(
+            "For",
+            leaf("pattern", a_pattern, b_pattern)
+                .or_else(|| {
+                    node(
+                        "iterable",
+                        a_iterable.as_ref(),
+                        b_iterable.as_ref(),
+                        diff_expressions,
+                    )
+                })
+                .or_else(|| list("body", a_body, b_body, diff_statements)),
+        ),
+        (Expression::Identifier(..), Expression::Identifier(..)) => variant("Identifier", None),
+        (Expression::Literal(..), Expression::Literal(..)) => variant("Literal", None),
+        _ => String::new(),
+    })
+}
+
+fn diff_match_expression_items(a: &MatchExpressionItem, b: &MatchExpressionItem) -> Difference {
+    leaf("left", &a.left, &b.left).or_else(|| node("right", &a.right, &b.right, diff_expressions))
+}
+
+fn diff_struct_expression_items(a: &StructExpressionItem, b: &StructExpressionItem) -> Difference {
+    leaf("name", &a.name, &b.name).or_else(|| {
+        optional(
+            "value",
+            a.value.as_ref(),
+            b.value.as_ref(),
+            diff_expressions,
+        )
+    })
+}
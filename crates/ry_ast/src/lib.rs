@@ -18,6 +18,9 @@
 //!
 //! See [`Serializer`] for more details.
 //!
+//! With `serde` feature enabled, AST nodes implement `serde::Serialize` and
+//! can be serialized into JSON using `serialize_ast_json()`.
+//!
 //! [`Serializer`]: crate::serialize::Serializer
 //! [`serialize_ast()`]: crate::serialize::serialize_ast
 //! [`Token`]: crate::token::Token
@@ -85,17 +88,21 @@
 
 use std::fmt::Display;
 
-use ry_filesystem::span::Span;
+use ry_filesystem::span::{Span, Spanned};
 use ry_interner::Symbol;
 use token::RawToken;
 
+pub mod diff;
 pub mod precedence;
 pub mod serialize;
 pub mod token;
+pub mod unparse;
 pub mod visit;
+pub mod visit_mut;
 
 /// Represents a literal.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Literal {
     Boolean { value: bool, span: Span },
     Character { value: char, span: Span },
@@ -120,6 +127,7 @@ impl Literal {
 
 /// Represents a symbol with a specified span.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct IdentifierAst {
     pub span: Span,
     pub symbol: Symbol,
@@ -137,6 +145,7 @@ pub struct IdentifierAst {
 ///     ^^^^^^
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Path {
     pub span: Span,
     pub identifiers: Vec<IdentifierAst>,
@@ -151,6 +160,7 @@ pub struct Path {
 /// import std.io as myio;
 /// ```
 #[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ImportPath {
     pub left: Path,
     pub r#as: Option<IdentifierAst>,
@@ -163,6 +173,7 @@ pub struct ImportPath {
 ///        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TypePath {
     pub span: Span,
     pub segments: Vec<TypePathSegment>,
@@ -174,6 +185,7 @@ pub struct TypePath {
 /// let a: Iterator[Item = uint32].Item = 3;
 ///        ^^^^^^^^^^^^^^^^^^^^^^^ ^^^^
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TypePathSegment {
     pub span: Span,
     pub path: Path,
@@ -194,6 +206,7 @@ pub struct TypePathSegment {
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Pattern {
     /// A literal pattern.
     ///
@@ -208,6 +221,22 @@ pub enum Pattern {
     /// ```
     Literal(Literal),
 
+    /// A negative numeric literal pattern.
+    ///
+    /// Integer literals are unsigned, so the sign is stored in the pattern
+    /// itself, while the literal keeps the magnitude as written.
+    ///
+    /// # Example
+    ///
+    /// ```txt
+    /// match x {
+    ///     -1 => { println("x is -1!"); }
+    ///     ^^ negative literal pattern
+    ///     .. => { println("x is not -1!"); }
+    /// }
+    /// ```
+    NegativeLiteral { span: Span, literal: Literal },
+
     /// An identifier pattern.
     ///
     /// Used to store a value corresponding to some pattern.
@@ -361,6 +390,16 @@ pub enum Pattern {
     ///     ^^ rest pattern
     /// }
     Rest { span: Span },
+
+    /// A wildcard pattern. Unlike an identifier pattern, doesn't bind
+    /// any variable.
+    ///
+    /// # Example
+    /// ```txt
+    /// let (a, _) = (1, 2);
+    ///         ^ wildcard pattern
+    /// ```
+    Wildcard { span: Span },
 }
 
 impl Pattern {
@@ -380,7 +419,9 @@ impl Pattern {
             | Self::Identifier { span, .. }
             | Self::List { span, .. }
             | Self::Or { span, .. }
+            | Self::NegativeLiteral { span, .. }
             | Self::Rest { span }
+            | Self::Wildcard { span }
             | Self::Struct { span, .. }
             | Self::Tuple { span, .. }
             | Self::TupleLike { span, .. }
@@ -405,6 +446,7 @@ impl Pattern {
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum StructFieldPattern {
     NotRest {
         span: Span,
@@ -434,6 +476,7 @@ pub type TypeBounds = Vec<TypePath>;
 ///        ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ type node
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Type {
     /// A type path.
     ///
@@ -480,6 +523,30 @@ pub enum Type {
     /// ```
     TraitObject { span: Span, bounds: TypeBounds },
 
+    /// A reference type.
+    ///
+    /// ```txt
+    /// &uint32
+    /// &mut List[uint32]
+    /// ```
+    Reference {
+        span: Span,
+        mutable: bool,
+        inner: Box<Self>,
+    },
+
+    /// An array type, with an optional size.
+    ///
+    /// ```txt
+    /// [uint8]
+    /// [uint8; 4]
+    /// ```
+    Array {
+        span: Span,
+        element: Box<Self>,
+        size: Option<Box<Expression>>,
+    },
+
     /// A type with a qualified path.
     ///
     /// ```txt
@@ -505,10 +572,19 @@ impl Type {
             | Self::Parenthesized { span, .. }
             | Self::Path(TypePath { span, .. })
             | Self::TraitObject { span, .. }
+            | Self::Reference { span, .. }
+            | Self::Array { span, .. }
             | Self::Tuple { span, .. }
             | Self::WithQualifiedPath { span, .. } => *span,
         }
     }
+
+    /// Returns `true` if the type is a unit type, i.e. an empty tuple `()`.
+    #[inline]
+    #[must_use]
+    pub const fn is_unit(&self) -> bool {
+        matches!(self, Self::Tuple { element_types, .. } if element_types.is_empty())
+    }
 }
 
 /// Represents a generic parameter.
@@ -520,6 +596,7 @@ impl Type {
 ///          ^ generic parameter
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct GenericParameter {
     pub name: IdentifierAst,
     pub bounds: Option<TypeBounds>,
@@ -538,13 +615,20 @@ pub type WhereClause = Vec<WhereClauseItem>;
 ///
 /// ```txt
 /// type StringRes[E] = Result[String, E];
+/// type Cloned[T] = Wrapper[T] where T: Clone;
+/// ```
+///
+/// The where clause, if any, follows the aliased type.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TypeAlias {
     pub visibility: Visibility,
     pub name: IdentifierAst,
     pub generic_parameters: Option<Vec<GenericParameter>>,
     pub bounds: Option<TypeBounds>,
     pub value: Option<Type>,
+    pub where_clause: Option<WhereClause>,
+    pub attributes: Vec<Attribute>,
     pub docstring: Option<String>,
 }
 
@@ -556,6 +640,7 @@ pub struct TypeAlias {
 ///                                                        ^^^^^^^^^^^^^^^^^^^^ where clause item #2
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum WhereClauseItem {
     Eq { left: Type, right: Type },
     Satisfies { ty: Type, bounds: TypeBounds },
@@ -563,6 +648,7 @@ pub enum WhereClauseItem {
 
 /// Represents an expression in an untyped AST.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Expression {
     /// List expression.
     ///
@@ -651,6 +737,17 @@ pub enum Expression {
         right: IdentifierAst,
     },
 
+    /// Tuple field access expression.
+    ///
+    /// ```txt
+    /// x.0
+    /// ```
+    TupleFieldAccess {
+        span: Span,
+        left: Box<Self>,
+        index: usize,
+    },
+
     /// Prefix expression.
     ///
     /// ```txt
@@ -673,19 +770,59 @@ pub enum Expression {
         operator: PostfixOperator,
     },
 
+    /// Range expression. Both bounds are optional.
+    ///
+    /// ```txt
+    /// 0..10
+    /// 0..=9
+    /// ..10
+    /// 0..
+    /// ..
+    /// ```
+    Range {
+        span: Span,
+        start: Option<Box<Self>>,
+        end: Option<Box<Self>>,
+        inclusive: bool,
+    },
+
     /// While expression (always returns `Unit` type).
     ///
     /// ```txt
     /// while x < 2 {
     ///     break;
     /// }
+    ///
+    /// 'outer: while true {
+    ///     break 'outer;
+    /// }
     /// ```
     While {
         span: Span,
+        /// Label of the loop (the span includes the leading `'`).
+        label: Option<IdentifierAst>,
         condition: Box<Self>,
         body: Vec<Statement>,
     },
 
+    /// For expression (always returns `Unit` type).
+    ///
+    /// ```txt
+    /// for x in list {
+    ///     println(x);
+    /// }
+    ///
+    /// for (k, v) in m {
+    ///     println(k);
+    /// }
+    /// ```
+    For {
+        span: Span,
+        pattern: Pattern,
+        iterable: Box<Self>,
+        body: Vec<Statement>,
+    },
+
     /// Call expression.
     ///
     /// ```txt
@@ -710,6 +847,17 @@ pub enum Expression {
         generic_arguments: Vec<GenericArgument>,
     },
 
+    /// Index expression.
+    ///
+    /// ```txt
+    /// list[0]
+    /// ```
+    Index {
+        span: Span,
+        left: Box<Self>,
+        index: Box<Self>,
+    },
+
     /// Tuple expression.
     ///
     /// ```txt
@@ -759,6 +907,7 @@ pub enum Expression {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct LambdaFunctionParameter {
     pub name: IdentifierAst,
     pub ty: Option<Type>,
@@ -766,6 +915,7 @@ pub struct LambdaFunctionParameter {
 
 /// Represents a generic argument.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum GenericArgument {
     Type(Type),
     AssociatedType { name: IdentifierAst, value: Type },
@@ -792,11 +942,15 @@ impl Expression {
             | Self::Parenthesized { span, .. }
             | Self::If { span, .. }
             | Self::FieldAccess { span, .. }
+            | Self::TupleFieldAccess { span, .. }
             | Self::Prefix { span, .. }
             | Self::Postfix { span, .. }
+            | Self::Range { span, .. }
             | Self::While { span, .. }
+            | Self::For { span, .. }
             | Self::Call { span, .. }
             | Self::GenericArguments { span, .. }
+            | Self::Index { span, .. }
             | Self::Tuple { span, .. }
             | Self::Struct { span, .. }
             | Self::Match { span, .. }
@@ -807,6 +961,7 @@ impl Expression {
 
 /// Represents a binary operator with a specific span.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct BinaryOperator {
     pub span: Span,
     pub raw: RawBinaryOperator,
@@ -814,6 +969,7 @@ pub struct BinaryOperator {
 
 /// Represents a binary operator.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RawBinaryOperator {
     PlusEq,
     Plus,
@@ -927,6 +1083,7 @@ impl Display for RawBinaryOperator {
 
 /// Represents a prefix operator with a specific span.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PrefixOperator {
     pub span: Span,
     pub raw: RawPrefixOperator,
@@ -934,6 +1091,7 @@ pub struct PrefixOperator {
 
 /// Represents a prefix operator.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RawPrefixOperator {
     Bang,
     Not,
@@ -984,6 +1142,7 @@ impl Display for RawPrefixOperator {
 
 /// Represents a postfix operator with a specific span.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PostfixOperator {
     pub span: Span,
     pub raw: RawPostfixOperator,
@@ -991,6 +1150,7 @@ pub struct PostfixOperator {
 
 /// Represents a postfix operator.
 #[derive(Debug, PartialEq, Copy, Clone, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum RawPostfixOperator {
     QuestionMark,
     PlusPlus,
@@ -1041,6 +1201,7 @@ impl Display for RawPostfixOperator {
 ///    ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ match expression item
 /// }
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MatchExpressionItem {
     pub left: Pattern,
     pub right: Expression,
@@ -1059,6 +1220,7 @@ pub struct MatchExpressionItem {
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StructExpressionItem {
     pub name: IdentifierAst,
     pub value: Option<Expression>,
@@ -1071,12 +1233,16 @@ impl Expression {
     #[inline]
     #[must_use]
     pub const fn with_block(&self) -> bool {
-        matches!(self, Self::If { .. } | Self::While { .. })
+        matches!(
+            self,
+            Self::If { .. } | Self::While { .. } | Self::For { .. } | Self::StatementsBlock { .. }
+        )
     }
 }
 
 /// Represents a statement.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Statement {
     /// Defer statement
     ///
@@ -1101,25 +1267,40 @@ pub enum Statement {
     ///
     /// ```txt
     /// break;
+    /// break 'outer;
     /// ```
-    Break { span: Span },
+    Break {
+        span: Span,
+        label: Option<IdentifierAst>,
+    },
 
     /// Continue statement
     ///
     /// ```txt
     /// continue;
+    /// continue 'outer;
     /// ```
-    Continue { span: Span },
+    Continue {
+        span: Span,
+        label: Option<IdentifierAst>,
+    },
 
-    /// Return statement
+    /// Return statement, the expression is omitted when returning `Unit`.
     ///
     /// ```txt
     /// /// Answer to the Ultimate Question of Life, the Universe, and Everything
     /// fun the_answer(): uint32 {
     ///     return 42;
     /// }
+    ///
+    /// fun nothing() {
+    ///     return;
+    /// }
     /// ```
-    Return { expression: Expression },
+    Return {
+        span: Span,
+        expression: Option<Expression>,
+    },
 
     /// Let statement
     ///
@@ -1133,6 +1314,27 @@ pub enum Statement {
     },
 }
 
+impl Statement {
+    /// Returns the span of the statement.
+    ///
+    /// Keywords (except `break`, `continue` and `return`) and semicolons are not stored
+    /// in the AST, so the span only covers the nodes of the statement, e.g.
+    /// `x = 1` in `let x = 1;`.
+    #[inline]
+    #[must_use]
+    pub const fn span(&self) -> Span {
+        match self {
+            Self::Break { span, .. } | Self::Continue { span, .. } | Self::Return { span, .. } => {
+                *span
+            }
+            Self::Defer { call: expression } | Self::Expression { expression, .. } => {
+                expression.span()
+            }
+            Self::Let { pattern, value, .. } => pattern.span().to(value.span()),
+        }
+    }
+}
+
 /// Represents a block of statements.
 ///
 /// ```txt
@@ -1141,6 +1343,25 @@ pub enum Statement {
 /// ```
 pub type StatementsBlock = Vec<Statement>;
 
+/// Returns the expression, that a block of statements evaluates to: the
+/// last statement, if it is an expression statement without a semicolon.
+/// [`None`] means that the block evaluates to `Unit`.
+///
+/// ```txt
+/// { let a = 1; a + 1 } // evaluates to `a + 1`
+/// { foo(); }           // evaluates to `Unit`
+/// ```
+#[must_use]
+pub const fn block_value(block: &[Statement]) -> Option<&Expression> {
+    match block.last() {
+        Some(Statement::Expression {
+            expression,
+            has_semicolon: false,
+        }) => Some(expression),
+        _ => None,
+    }
+}
+
 /// Type implementation.
 ///
 /// ```txt
@@ -1153,17 +1374,43 @@ pub type StatementsBlock = Vec<Statement>;
 /// }
 /// ```
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Impl {
     pub generic_parameters: Option<Vec<GenericParameter>>,
     pub ty: Type,
     pub r#trait: Option<Type>,
     pub where_clause: Option<WhereClause>,
     pub items: Vec<TraitItem>,
+    pub attributes: Vec<Attribute>,
     pub docstring: Option<String>,
 }
 
+/// Represents an attribute of an item.
+///
+/// ```txt
+/// #[derive(Debug, Clone)]
+///   ^^^^^^ ^^^^^^^^^^^^
+///   path   arguments
+/// fun foo() {}
+/// ```
+///
+/// Attributes without arguments can also be written as decorators:
+///
+/// ```txt
+/// @inline
+/// fun foo() {}
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Attribute {
+    pub span: Span,
+    pub path: Path,
+    pub arguments: Vec<Expression>,
+}
+
 /// Represents an item.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Item {
     /// Enum item.
     ///
@@ -1184,6 +1431,7 @@ pub enum Item {
         generic_parameters: Option<Vec<GenericParameter>>,
         where_clause: Option<WhereClause>,
         items: Vec<EnumItem>,
+        attributes: Vec<Attribute>,
         docstring: Option<String>,
     },
 
@@ -1201,7 +1449,10 @@ pub enum Item {
     /// ```txt
     /// import std.io;
     /// ```
-    Import { path: ImportPath },
+    Import {
+        attributes: Vec<Attribute>,
+        path: ImportPath,
+    },
 
     /// Trait item.
     ///
@@ -1216,6 +1467,7 @@ pub enum Item {
         generic_parameters: Option<Vec<GenericParameter>>,
         where_clause: Option<WhereClause>,
         items: Vec<TraitItem>,
+        attributes: Vec<Attribute>,
         docstring: Option<String>,
     },
 
@@ -1247,6 +1499,7 @@ pub enum Item {
         generic_parameters: Option<Vec<GenericParameter>>,
         where_clause: Option<WhereClause>,
         fields: Vec<StructField>,
+        attributes: Vec<Attribute>,
         docstring: Option<String>,
     },
 
@@ -1261,15 +1514,45 @@ pub enum Item {
         generic_parameters: Option<Vec<GenericParameter>>,
         where_clause: Option<WhereClause>,
         fields: Vec<TupleField>,
+        attributes: Vec<Attribute>,
         docstring: Option<String>,
     },
 
     /// Type alias item.
     TypeAlias(TypeAlias),
+
+    /// Constant item.
+    ///
+    /// ```txt
+    /// pub const MAX_SIZE: uint32 = 1024;
+    /// ```
+    Const {
+        visibility: Visibility,
+        name: IdentifierAst,
+        ty: Type,
+        value: Expression,
+        attributes: Vec<Attribute>,
+        docstring: Option<String>,
+    },
+
+    /// Static item.
+    ///
+    /// ```txt
+    /// static GREETING: String = "hello";
+    /// ```
+    Static {
+        visibility: Visibility,
+        name: IdentifierAst,
+        ty: Type,
+        value: Expression,
+        attributes: Vec<Attribute>,
+        docstring: Option<String>,
+    },
 }
 
 /// Represents a kind of top level item.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum ItemKind {
     Enum,
     Function,
@@ -1278,6 +1561,8 @@ pub enum ItemKind {
     Impl,
     Struct,
     TypeAlias,
+    Const,
+    Static,
 }
 
 impl AsRef<str> for ItemKind {
@@ -1290,6 +1575,8 @@ impl AsRef<str> for ItemKind {
             Self::Impl => "type implementation",
             Self::Struct => "struct",
             Self::TypeAlias => "type alias",
+            Self::Const => "constant",
+            Self::Static => "static",
         }
     }
 }
@@ -1300,12 +1587,31 @@ impl ToString for ItemKind {
     }
 }
 
+impl From<&Item> for ItemKind {
+    /// Returns the kind of an item. Tuple-like structs are [`ItemKind::Struct`].
+    fn from(item: &Item) -> Self {
+        match item {
+            Item::Enum { .. } => Self::Enum,
+            Item::Function(..) => Self::Function,
+            Item::Import { .. } => Self::Import,
+            Item::Trait { .. } => Self::Trait,
+            Item::Impl(..) => Self::Impl,
+            Item::Struct { .. } | Item::TupleLikeStruct { .. } => Self::Struct,
+            Item::TypeAlias(..) => Self::TypeAlias,
+            Item::Const { .. } => Self::Const,
+            Item::Static { .. } => Self::Static,
+        }
+    }
+}
+
 /// Represents an enum item.
 ///
 /// ```txt
 /// enum UserCredentials {
 ///     None,
 ///     ^^^^ enum item
+///     Anonymous = 1,
+///     ^^^^^^^^^^^^^ enum item with a discriminant
 ///     EmailOnly(String),
 ///     ^^^^^^^^^^^^^^^^^ enum item
 ///     PhoneNumberOnly(String),
@@ -1315,9 +1621,11 @@ impl ToString for ItemKind {
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum EnumItem {
     Just {
         name: IdentifierAst,
+        discriminant: Option<Expression>,
         docstring: Option<String>,
     },
     Tuple {
@@ -1338,6 +1646,7 @@ pub enum EnumItem {
 /// struct Test(pub String);
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct TupleField {
     pub visibility: Visibility,
     pub ty: Type,
@@ -1354,6 +1663,7 @@ pub struct TupleField {
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct StructField {
     pub visibility: Visibility,
     pub name: IdentifierAst,
@@ -1363,6 +1673,7 @@ pub struct StructField {
 
 /// Represents a trait item.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum TraitItem {
     TypeAlias(TypeAlias),
     AssociatedFunction(Function),
@@ -1374,6 +1685,7 @@ pub enum TraitItem {
 /// fun sum[T](a: T, b: T) -> T where T: Add[T, T] { a + b }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Function {
     pub visibility: Visibility,
     pub name: IdentifierAst,
@@ -1382,11 +1694,24 @@ pub struct Function {
     pub return_type: Option<Type>,
     pub where_clause: Option<WhereClause>,
     pub body: Option<StatementsBlock>,
+    pub attributes: Vec<Attribute>,
     pub docstring: Option<String>,
 }
 
+impl Function {
+    /// Returns `true` if the function is a method, i.e. its first parameter
+    /// is `self`. Otherwise the function is a static associated function (or
+    /// a free function, if it is not inside of a trait or type implementation).
+    #[inline]
+    #[must_use]
+    pub fn is_method(&self) -> bool {
+        matches!(self.parameters.first(), Some(FunctionParameter::Self_(..)))
+    }
+}
+
 /// Represents a function parameter.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum FunctionParameter {
     Just(JustFunctionParameter),
     Self_(SelfParameter),
@@ -1399,6 +1724,7 @@ pub enum FunctionParameter {
 ///               ^^^^
 /// }
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct SelfParameter {
     pub self_span: Span,
     pub ty: Option<Type>,
@@ -1407,26 +1733,206 @@ pub struct SelfParameter {
 /// Represents a function parameter that is not `self`.
 ///
 /// ```txt
-/// pub fun sum[T](a: T, b: T) -> T where T: Add[T, T] {
-///                ^^^^  ^^^^
+/// pub fun sum[T](a: T, b: T = T.zero()) -> T where T: Add[T, T] {
+///                ^^^^  ^^^^^^^^^^^^^^^
 ///     a + b
 /// }
 /// ```
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct JustFunctionParameter {
     pub name: IdentifierAst,
     pub ty: Type,
+    pub default_value: Option<Expression>,
 }
 
 /// Represents Ry source file.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Module {
     pub items: Vec<Item>,
     pub docstring: Option<String>,
 }
 
+impl Module {
+    /// Returns names of top-level items that have docstrings, paired with
+    /// their docstrings, in the order of their appearance in the module.
+    ///
+    /// Type implementations are skipped, because they don't have names.
+    #[must_use]
+    pub fn documented_items(&self) -> Vec<(&IdentifierAst, &str)> {
+        let mut items = vec![];
+
+        for item in &self.items {
+            push_documented_item(&mut items, item, false);
+        }
+
+        items
+    }
+
+    /// Same as [`Module::documented_items`], but also includes documented
+    /// enum variants, struct fields and items of traits and type
+    /// implementations. Nested entries follow their parent item.
+    #[must_use]
+    pub fn documented_items_recursive(&self) -> Vec<(&IdentifierAst, &str)> {
+        let mut items = vec![];
+
+        for item in &self.items {
+            push_documented_item(&mut items, item, true);
+        }
+
+        items
+    }
+
+    /// Returns an iterator over top-level items marked with `pub`, in the
+    /// order of their appearance in the module.
+    ///
+    /// Imports and type implementations are skipped, because they don't
+    /// have visibility qualifiers.
+    pub fn public_items(&self) -> impl Iterator<Item = &Item> {
+        self.items.iter().filter(|item| {
+            item_visibility(item).is_some_and(|visibility| visibility.span_of_pub().is_some())
+        })
+    }
+}
+
+/// Returns the visibility qualifier of an item, or [`None`] if the item
+/// cannot have one.
+const fn item_visibility(item: &Item) -> Option<Visibility> {
+    match item {
+        Item::Enum { visibility, .. }
+        | Item::Trait { visibility, .. }
+        | Item::Struct { visibility, .. }
+        | Item::TupleLikeStruct { visibility, .. }
+        | Item::Const { visibility, .. }
+        | Item::Static { visibility, .. } => Some(*visibility),
+        Item::Function(function) => Some(function.visibility),
+        Item::TypeAlias(alias) => Some(alias.visibility),
+        Item::Import { .. } | Item::Impl(..) => None,
+    }
+}
+
+fn push_documented<'a>(
+    items: &mut Vec<(&'a IdentifierAst, &'a str)>,
+    name: &'a IdentifierAst,
+    docstring: Option<&'a String>,
+) {
+    if let Some(docstring) = docstring {
+        items.push((name, docstring));
+    }
+}
+
+fn push_documented_struct_fields<'a>(
+    items: &mut Vec<(&'a IdentifierAst, &'a str)>,
+    fields: &'a [StructField],
+) {
+    for field in fields {
+        push_documented(items, &field.name, field.docstring.as_ref());
+    }
+}
+
+fn push_documented_trait_items<'a>(
+    items: &mut Vec<(&'a IdentifierAst, &'a str)>,
+    trait_items: &'a [TraitItem],
+) {
+    for item in trait_items {
+        match item {
+            TraitItem::AssociatedFunction(function) => {
+                push_documented(items, &function.name, function.docstring.as_ref());
+            }
+            TraitItem::TypeAlias(alias) => {
+                push_documented(items, &alias.name, alias.docstring.as_ref());
+            }
+        }
+    }
+}
+
+fn push_documented_item<'a>(
+    items: &mut Vec<(&'a IdentifierAst, &'a str)>,
+    item: &'a Item,
+    recursive: bool,
+) {
+    match item {
+        Item::Enum {
+            name,
+            items: enum_items,
+            docstring,
+            ..
+        } => {
+            push_documented(items, name, docstring.as_ref());
+
+            if recursive {
+                for enum_item in enum_items {
+                    match enum_item {
+                        EnumItem::Just {
+                            name, docstring, ..
+                        }
+                        | EnumItem::Tuple {
+                            name, docstring, ..
+                        } => push_documented(items, name, docstring.as_ref()),
+                        EnumItem::Struct {
+                            name,
+                            fields,
+                            docstring,
+                        } => {
+                            push_documented(items, name, docstring.as_ref());
+                            push_documented_struct_fields(items, fields);
+                        }
+                    }
+                }
+            }
+        }
+        Item::Function(function) => {
+            push_documented(items, &function.name, function.docstring.as_ref());
+        }
+        Item::TypeAlias(alias) => {
+            push_documented(items, &alias.name, alias.docstring.as_ref());
+        }
+        Item::Struct {
+            name,
+            fields,
+            docstring,
+            ..
+        } => {
+            push_documented(items, name, docstring.as_ref());
+
+            if recursive {
+                push_documented_struct_fields(items, fields);
+            }
+        }
+        Item::TupleLikeStruct {
+            name, docstring, ..
+        }
+        | Item::Const {
+            name, docstring, ..
+        }
+        | Item::Static {
+            name, docstring, ..
+        } => push_documented(items, name, docstring.as_ref()),
+        Item::Trait {
+            name,
+            items: trait_items,
+            docstring,
+            ..
+        } => {
+            push_documented(items, name, docstring.as_ref());
+
+            if recursive {
+                push_documented_trait_items(items, trait_items);
+            }
+        }
+        Item::Impl(implementation) => {
+            if recursive {
+                push_documented_trait_items(items, &implementation.items);
+            }
+        }
+        Item::Import { .. } => {}
+    }
+}
+
 /// Represents a visibility qualifier.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Visibility(Option<Span>);
 
 impl Visibility {
@@ -1454,3 +1960,43 @@ impl Default for Visibility {
         Self::private()
     }
 }
+
+/// Implements [`Spanned`] for nodes, which have an inherent `span()` method.
+macro_rules! impl_spanned_via_method {
+    ($($ty:ty),*) => {
+        $(
+            impl Spanned for $ty {
+                #[inline]
+                fn span(&self) -> Span {
+                    Self::span(self)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements [`Spanned`] for nodes, which store their span in the `span` field.
+macro_rules! impl_spanned_via_field {
+    ($($ty:ty),*) => {
+        $(
+            impl Spanned for $ty {
+                #[inline]
+                fn span(&self) -> Span {
+                    self.span
+                }
+            }
+        )*
+    };
+}
+
+impl_spanned_via_method!(Literal, Pattern, Type, Expression, Statement);
+impl_spanned_via_field!(
+    IdentifierAst,
+    Path,
+    TypePath,
+    TypePathSegment,
+    BinaryOperator,
+    PrefixOperator,
+    PostfixOperator,
+    Attribute
+);
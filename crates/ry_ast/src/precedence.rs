@@ -8,6 +8,8 @@
 //!
 //! See [`Precedence`] for more details.
 
+use crate::token::RawToken;
+
 /// Defines an enum representing different operator precedences.
 ///
 /// In Ry programming language, operators have different levels of precedence.
@@ -23,9 +25,12 @@ pub enum Precedence {
     Lowest,
 
     /// Precedence corresponding to assignment operators: `=`, `+=`, `-=`,
-    /// `*=`, `/=`, `^=`, `|=`.
+    /// `*=`, `/=`, `^=`, `|=`, `&=`, `%=`.
     Assign,
 
+    /// Precedence corresponding to range expressions: `a..b`, `a..=b`.
+    Range,
+
     /// Precedence corresponding to binary expressions with `||` operator.
     OrOr,
 
@@ -92,9 +97,60 @@ pub enum Precedence {
     /// ```
     Property,
 
-    /// Precedence corresponding to generic arguments expressions:
+    /// Precedence corresponding to generic arguments and index expressions:
     /// ```txt
     /// a[i32]
+    /// a[0]
     /// ```
     GenericArgument,
 }
+
+/// Defines the order in which a sequence of binary operators of the same
+/// [`Precedence`] is grouped.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Associativity {
+    /// `a - b - c` is grouped as `(a - b) - c`.
+    Left,
+
+    /// `a ** b ** c` is grouped as `a ** (b ** c)`.
+    Right,
+}
+
+impl Precedence {
+    /// Returns the associativity of binary operators with the precedence.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_ast::precedence::{Associativity, Precedence};
+    /// assert_eq!(Precedence::Power.associativity(), Associativity::Right);
+    /// assert_eq!(Precedence::Sum.associativity(), Associativity::Left);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn associativity(self) -> Associativity {
+        match self {
+            Self::Power => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
+}
+
+/// Returns the precedence of the operator the token corresponds to when it
+/// is found after an expression, or [`None`] if the token cannot continue
+/// an expression.
+///
+/// # Example
+/// ```
+/// # use ry_ast::{precedence::{precedence_of, Precedence}, Token};
+/// assert!(precedence_of(&Token![*]) > precedence_of(&Token![+]));
+/// assert_eq!(precedence_of(&Token![**]), Some(Precedence::Power));
+/// assert_eq!(precedence_of(&Token![;]), None);
+/// ```
+#[inline]
+#[must_use]
+pub const fn precedence_of(token: &RawToken) -> Option<Precedence> {
+    match token.to_precedence() {
+        Precedence::Lowest => None,
+        precedence => Some(precedence),
+    }
+}
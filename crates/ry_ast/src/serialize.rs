@@ -1,25 +1,34 @@
-//! Defines [`Serializer`] to serialize AST into a string.
-
+//! Defines [`Serializer`] to serialize AST into a string and [`SExprSerializer`]
+//! to serialize it into a compact S-expression. Types can be displayed in
+//! their surface syntax using [`display_type()`].
+//!
+//! When `serde` feature is enabled, AST can also be serialized into JSON
+//! using [`serialize_ast_json()`].
+
+use ry_filesystem::span::Span;
 use ry_interner::Interner;
 
 use crate::{
+    unparse::unparse_type,
     visit::{
-        walk_enum_items, walk_expression, walk_function, walk_generic_argument,
-        walk_generic_arguments, walk_generic_parameter, walk_generic_parameters, walk_if_block,
-        walk_if_blocks, walk_item, walk_lambda_function_parameter, walk_lambda_function_parameters,
-        walk_match_expression_item, walk_match_expression_items, walk_module, walk_path,
-        walk_statement, walk_statements_block, walk_struct_expression_item,
-        walk_struct_expression_items, walk_struct_field, walk_struct_field_pattern,
-        walk_struct_field_patterns, walk_struct_fields, walk_trait_bounds, walk_trait_item,
-        walk_trait_items, walk_tuple_field, walk_tuple_fields, walk_type, walk_type_alias,
-        walk_type_implementation, walk_type_path, walk_type_path_segment, walk_where_clause,
-        walk_where_clause_item, Visitor,
+        walk_attribute, walk_attributes, walk_enum_item, walk_enum_items, walk_expression,
+        walk_function, walk_function_parameters, walk_generic_argument, walk_generic_arguments,
+        walk_generic_parameter, walk_generic_parameters, walk_if_block, walk_if_blocks, walk_item,
+        walk_just_function_parameter, walk_lambda_function_parameter,
+        walk_lambda_function_parameters, walk_match_expression_item, walk_match_expression_items,
+        walk_module, walk_path, walk_pattern, walk_self_function_parameter, walk_statement,
+        walk_statements_block, walk_struct_expression_item, walk_struct_expression_items,
+        walk_struct_field, walk_struct_field_pattern, walk_struct_field_patterns,
+        walk_struct_fields, walk_trait_bounds, walk_trait_item, walk_trait_items, walk_tuple_field,
+        walk_tuple_fields, walk_type, walk_type_alias, walk_type_implementation, walk_type_path,
+        walk_type_path_segment, walk_where_clause, walk_where_clause_item, Visitor,
     },
-    BinaryOperator, EnumItem, Expression, Function, GenericArgument, GenericParameter,
-    IdentifierAst, Impl, ImportPath, Item, LambdaFunctionParameter, Literal, MatchExpressionItem,
-    Module, Path, Pattern, PostfixOperator, PrefixOperator, Statement, StatementsBlock,
-    StructExpressionItem, StructField, StructFieldPattern, TraitItem, TupleField, Type, TypeAlias,
-    TypePath, TypePathSegment, Visibility, WhereClauseItem,
+    Attribute, BinaryOperator, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
+    GenericParameter, IdentifierAst, Impl, ImportPath, Item, JustFunctionParameter,
+    LambdaFunctionParameter, Literal, MatchExpressionItem, Module, Path, Pattern, PostfixOperator,
+    PrefixOperator, SelfParameter, Statement, StatementsBlock, StructExpressionItem, StructField,
+    StructFieldPattern, TraitItem, TupleField, Type, TypeAlias, TypePath, TypePathSegment,
+    Visibility, WhereClauseItem,
 };
 
 /// A struct that allows to serialize a Ry module into a string, for debug purposes.
@@ -31,18 +40,66 @@ pub struct Serializer<'interner> {
     /// Current indentation level
     identation: usize,
 
+    /// A string written once per indentation level.
+    indent: String,
+
     /// An output string produced,
     output: String,
 }
 
 impl<'interner> Serializer<'interner> {
-    /// Creates a new serializer instance.
+    /// Creates a new serializer instance, that uses tabs for indentation.
     #[inline]
     #[must_use]
-    pub const fn new(interner: &'interner Interner) -> Self {
+    pub fn new(interner: &'interner Interner) -> Self {
+        Self::with_indent(interner, "\t")
+    }
+
+    /// Creates a new serializer instance, that writes `indent` once
+    /// per indentation level.
+    ///
+    /// ```
+    /// # use ry_ast::{serialize::Serializer, visit::Visitor, IdentifierAst, ImportPath, Item, Module, Path};
+    /// # use ry_filesystem::span::Span;
+    /// # use ry_interner::Interner;
+    /// let mut interner = Interner::default();
+    /// let foo = IdentifierAst {
+    ///     span: Span { start: 7, end: 10 },
+    ///     symbol: interner.get_or_intern("foo"),
+    /// };
+    ///
+    /// let module = Module {
+    ///     items: vec![Item::Import {
+    ///         attributes: vec![],
+    ///         path: ImportPath {
+    ///             left: Path {
+    ///                 span: foo.span,
+    ///                 identifiers: vec![foo],
+    ///             },
+    ///             r#as: None,
+    ///         },
+    ///     }],
+    ///     docstring: None,
+    /// };
+    ///
+    /// let mut serializer = Serializer::with_indent(&interner, "  ");
+    /// serializer.visit_module(&module);
+    ///
+    /// assert_eq!(
+    ///     serializer.output(),
+    ///     "MODULE\n  IMPORT\n    IMPORT_PATH\n      IDENTIFIER: foo <7..10>\n"
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_indent<S>(interner: &'interner Interner, indent: S) -> Self
+    where
+        S: Into<String>,
+    {
         Self {
             interner,
             identation: 0,
+            indent: indent.into(),
             output: String::new(),
         }
     }
@@ -90,7 +147,7 @@ impl<'interner> Serializer<'interner> {
     /// Adds indentation symbols into the output.
     pub fn write_identation(&mut self) {
         for _ in 0..self.identation() {
-            self.write("\t");
+            self.output.push_str(&self.indent);
         }
     }
 
@@ -141,6 +198,10 @@ impl Visitor<'_> for Serializer<'_> {
             Expression::Binary { .. } => self.write("BINARY"),
             Expression::Call { .. } => self.write("CALL"),
             Expression::FieldAccess { .. } => self.write("FIELD_ACCESS"),
+            Expression::Index { .. } => self.write("INDEX"),
+            Expression::TupleFieldAccess { index, .. } => {
+                self.write(format!("TUPLE_FIELD_ACCESS {index}"));
+            }
             Expression::GenericArguments { .. } => self.write("GENERIC_AGRUMENTS"),
             Expression::Identifier(..) => self.write("IDENTIFIER"),
             Expression::If { .. } => self.write("IF"),
@@ -151,10 +212,17 @@ impl Visitor<'_> for Serializer<'_> {
             Expression::Parenthesized { .. } => self.write("PARENTHESIZED"),
             Expression::Postfix { .. } => self.write("POSTFIX"),
             Expression::Prefix { .. } => self.write("PREFIX"),
+            Expression::Range {
+                inclusive: false, ..
+            } => self.write("RANGE"),
+            Expression::Range {
+                inclusive: true, ..
+            } => self.write("INCLUSIVE_RANGE"),
             Expression::StatementsBlock { .. } => self.write("STATEMENTS_BLOCK"),
             Expression::Struct { .. } => self.write("STRUCT"),
             Expression::Tuple { .. } => self.write("TUPLE"),
             Expression::While { .. } => self.write("WHILE"),
+            Expression::For { .. } => self.write("FOR"),
         }
 
         self.write(format!(" <{}>", expression.span()));
@@ -255,6 +323,32 @@ impl Visitor<'_> for Serializer<'_> {
         self.decrement_indentation();
     }
 
+    fn visit_attributes(&mut self, attributes: &'_ [Attribute]) {
+        if attributes.is_empty() {
+            return;
+        }
+
+        self.increment_indentation();
+        self.write_identation();
+
+        self.write("ATTRIBUTES");
+        self.write_newline();
+        walk_attributes(self, attributes);
+
+        self.decrement_indentation();
+    }
+
+    fn visit_attribute(&mut self, attribute: &'_ Attribute) {
+        self.increment_indentation();
+        self.write_identation();
+
+        self.write(format!("ATTRIBUTE <{}>", attribute.span));
+        self.write_newline();
+        walk_attribute(self, attribute);
+
+        self.decrement_indentation();
+    }
+
     fn visit_import_path(&mut self, path: &'_ ImportPath) {
         self.increment_indentation();
         self.write_identation();
@@ -288,6 +382,8 @@ impl Visitor<'_> for Serializer<'_> {
             Item::Trait { .. } => self.write("TRAIT_GLOBAL_ITEM"),
             Item::TupleLikeStruct { .. } => self.write("TUPLE_LIKE_STRUCT_GLOBAL_ITEM"),
             Item::TypeAlias(..) => self.write("TYPE_ALIAS_GLOBAL_ITEM"),
+            Item::Const { .. } => self.write("CONST_GLOBAL_ITEM"),
+            Item::Static { .. } => self.write("STATIC_GLOBAL_ITEM"),
         }
 
         self.write_newline();
@@ -388,9 +484,11 @@ impl Visitor<'_> for Serializer<'_> {
             Pattern::Identifier { .. } => self.write("IDENTIFIER_PATTERN"),
             Pattern::List { .. } => self.write("LIST_PATTERN"),
             Pattern::Literal(..) => self.write("LITERAL_PATTERN"),
+            Pattern::NegativeLiteral { .. } => self.write("NEGATIVE_LITERAL_PATTERN"),
             Pattern::Or { .. } => self.write("OR_PATTERN"),
             Pattern::Path { .. } => self.write("PATH_PATTERN"),
             Pattern::Rest { .. } => self.write("REST_PATTERN"),
+            Pattern::Wildcard { .. } => self.write("WILDCARD_PATTERN"),
             Pattern::Struct { .. } => self.write("STRUCT_PATTERN"),
             Pattern::Tuple { .. } => self.write("TUPLE_PATTERN"),
             Pattern::TupleLike { .. } => self.write("TUPLE_LIKE_PATTERN"),
@@ -594,6 +692,9 @@ impl Visitor<'_> for Serializer<'_> {
             Type::Tuple { .. } => self.write("TUPLE_TYPE"),
             Type::Path { .. } => self.write("PATH_TYPE"),
             Type::TraitObject { .. } => self.write("TRAIT_OBJECT_TYPE"),
+            Type::Reference { mutable: false, .. } => self.write("REFERENCE_TYPE"),
+            Type::Reference { mutable: true, .. } => self.write("MUTABLE_REFERENCE_TYPE"),
+            Type::Array { .. } => self.write("ARRAY_TYPE"),
             Type::Parenthesized { .. } => self.write("PARENTHESIZED_TYPE"),
             Type::WithQualifiedPath { .. } => self.write("WITH_QUALIFIED_PATH_TYPE"),
         }
@@ -699,3 +800,775 @@ pub fn serialize_ast(module: &Module, interner: &Interner) -> String {
     serializer.visit_module(module);
     serializer.take_output()
 }
+
+/// A struct that allows to serialize a Ry module into a compact S-expression,
+/// e.g. `(binary + (integer 1) (integer 2))`.
+///
+/// Unlike [`Serializer`], the output doesn't contain spans by default, so it
+/// is stable across formatting changes of the source and can be used for
+/// snapshot tests. Spans can be included with [`SExprSerializer::with_spans`],
+/// in which case they are written as `:span start..end` at the end of a form.
+///
+/// Identifiers and paths are written as bare atoms (`foo`, `std.io`).
+#[derive(Debug)]
+pub struct SExprSerializer<'interner> {
+    /// An interner used to resolve symbols in an AST.
+    interner: &'interner Interner,
+
+    /// Whether spans of nodes are included into the output.
+    include_spans: bool,
+
+    /// An output string produced,
+    output: String,
+}
+
+impl<'interner> SExprSerializer<'interner> {
+    /// Creates a new [`SExprSerializer`] instance, that doesn't include spans.
+    #[inline]
+    #[must_use]
+    pub const fn new(interner: &'interner Interner) -> Self {
+        Self {
+            interner,
+            include_spans: false,
+            output: String::new(),
+        }
+    }
+
+    /// Creates a new [`SExprSerializer`] instance, that includes spans
+    /// of nodes into the output.
+    ///
+    /// ```
+    /// # use ry_ast::{serialize::SExprSerializer, visit::Visitor, Expression, Literal};
+    /// # use ry_filesystem::span::Span;
+    /// # use ry_interner::Interner;
+    /// let interner = Interner::default();
+    /// let mut serializer = SExprSerializer::with_spans(&interner);
+    ///
+    /// serializer.visit_expression(&Expression::Literal(Literal::Integer {
+    ///     value: 1,
+    ///     span: Span { start: 0, end: 1 },
+    /// }));
+    ///
+    /// assert_eq!(serializer.output(), "(integer 1 :span 0..1)");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_spans(interner: &'interner Interner) -> Self {
+        Self {
+            interner,
+            include_spans: true,
+            output: String::new(),
+        }
+    }
+
+    /// Returns the interner used to resolve symbols in the AST of the module being serialized.
+    #[inline]
+    #[must_use]
+    pub const fn interner(&self) -> &Interner {
+        self.interner
+    }
+
+    /// Returns the output string produced.
+    #[inline]
+    #[must_use]
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Returns the owned output string produced.
+    #[inline]
+    #[must_use]
+    pub fn take_output(self) -> String {
+        self.output
+    }
+
+    /// Writes a space, if the atom being written is not the first one in the form.
+    fn write_separator(&mut self) {
+        if !self.output.is_empty() && !self.output.ends_with('(') {
+            self.output.push(' ');
+        }
+    }
+
+    /// Writes an atom into the output.
+    fn write_atom<S>(&mut self, atom: S)
+    where
+        S: AsRef<str>,
+    {
+        self.write_separator();
+        self.output.push_str(atom.as_ref());
+    }
+
+    /// Writes an operator atom (without backticks used in diagnostics).
+    fn write_operator<S>(&mut self, operator: S)
+    where
+        S: Into<String>,
+    {
+        self.write_atom(operator.into().trim_matches('`'));
+    }
+
+    /// Opens a new form with a given name.
+    fn open(&mut self, name: &str) {
+        self.write_separator();
+        self.output.push('(');
+        self.output.push_str(name);
+    }
+
+    /// Closes the current form, optionally writing the span of the node.
+    fn close(&mut self, span: Option<Span>) {
+        if self.include_spans {
+            if let Some(span) = span {
+                self.output.push_str(" :span ");
+                self.output.push_str(&span.to_string());
+            }
+        }
+
+        self.output.push(')');
+    }
+
+    /// Writes a resolved identifier.
+    fn write_symbol(&mut self, identifier: IdentifierAst) {
+        let name = self.interner.resolve(identifier.symbol).unwrap_or("?");
+        self.write_separator();
+        self.output.push_str(name);
+    }
+
+    /// Writes a loop label with the leading `'`, so that it is not confused
+    /// with an identifier.
+    fn write_label(&mut self, label: Option<IdentifierAst>) {
+        if let Some(label) = label {
+            let name = self.interner.resolve(label.symbol).unwrap_or("?");
+            self.write_separator();
+            self.output.push('\'');
+            self.output.push_str(name);
+        }
+    }
+
+    /// Writes a `break` or `continue` statement.
+    fn write_jump(&mut self, name: &str, span: Span, label: Option<IdentifierAst>) {
+        self.open(name);
+        self.write_label(label);
+        self.close(Some(span));
+    }
+}
+
+impl<'ast> Visitor<'ast> for SExprSerializer<'_> {
+    fn visit_identifier(&mut self, identifier: IdentifierAst) {
+        self.write_symbol(identifier);
+    }
+
+    fn visit_path(&mut self, path: &'ast Path) {
+        let path = path
+            .identifiers
+            .iter()
+            .map(|identifier| self.interner.resolve(identifier.symbol).unwrap_or("?"))
+            .collect::<Vec<_>>()
+            .join(".");
+
+        self.write_atom(path);
+    }
+
+    fn visit_attribute(&mut self, attribute: &'ast Attribute) {
+        self.open("attribute");
+        walk_attribute(self, attribute);
+        self.close(Some(attribute.span));
+    }
+
+    fn visit_import_path(&mut self, path: &'ast ImportPath) {
+        self.visit_path(&path.left);
+
+        if let Some(r#as) = path.r#as {
+            self.write_atom("as");
+            self.visit_identifier(r#as);
+        }
+    }
+
+    fn visit_module(&mut self, module: &'ast Module) {
+        self.open("module");
+        walk_module(self, module);
+        self.close(None);
+    }
+
+    fn visit_item(&mut self, item: &'ast Item) {
+        let name = match item {
+            Item::Function(..) | Item::Impl(..) | Item::TypeAlias(..) => {
+                walk_item(self, item);
+                return;
+            }
+            Item::Enum { .. } => "enum",
+            Item::Import { .. } => "import",
+            Item::Struct { .. } => "struct",
+            Item::Trait { .. } => "trait",
+            Item::TupleLikeStruct { .. } => "tuple_struct",
+            Item::Const { .. } => "const",
+            Item::Static { .. } => "static",
+        };
+
+        self.open(name);
+        walk_item(self, item);
+        self.close(None);
+    }
+
+    fn visit_type_implementation(&mut self, implementation: &'ast Impl) {
+        self.open("impl");
+        self.visit_generic_parameters(implementation.generic_parameters.as_deref());
+        walk_type_implementation(self, implementation);
+        self.close(None);
+    }
+
+    fn visit_function(&mut self, function: &'ast Function) {
+        self.open("function");
+
+        self.visit_attributes(&function.attributes);
+        self.visit_visibility(function.visibility);
+        self.visit_identifier(function.name);
+        self.visit_generic_parameters(function.generic_parameters.as_deref());
+        self.visit_function_parameters(&function.parameters);
+
+        if let Some(return_type) = &function.return_type {
+            self.visit_type(return_type);
+        }
+
+        self.visit_where_clause(function.where_clause.as_deref());
+
+        if let Some(body) = &function.body {
+            self.visit_statements_block(body);
+        }
+
+        self.close(None);
+    }
+
+    fn visit_visibility(&mut self, visibility: Visibility) {
+        if visibility.span_of_pub().is_some() {
+            self.write_atom("pub");
+        }
+    }
+
+    fn visit_where_clause(&mut self, items: Option<&'ast [WhereClauseItem]>) {
+        if items.is_some() {
+            self.open("where");
+            walk_where_clause(self, items);
+            self.close(None);
+        }
+    }
+
+    fn visit_where_clause_item(&mut self, item: &'ast WhereClauseItem) {
+        match item {
+            WhereClauseItem::Eq { .. } => self.open("eq"),
+            WhereClauseItem::Satisfies { .. } => self.open("satisfies"),
+        }
+
+        walk_where_clause_item(self, item);
+        self.close(None);
+    }
+
+    fn visit_enum_item(&mut self, item: &'ast EnumItem) {
+        self.open("variant");
+        walk_enum_item(self, item);
+        self.close(None);
+    }
+
+    fn visit_struct_field(&mut self, field: &'ast StructField) {
+        self.open("field");
+        walk_struct_field(self, field);
+        self.close(None);
+    }
+
+    fn visit_generic_parameters(&mut self, parameters: Option<&'ast [GenericParameter]>) {
+        if parameters.is_some() {
+            self.open("generic_parameters");
+            walk_generic_parameters(self, parameters);
+            self.close(None);
+        }
+    }
+
+    fn visit_generic_parameter(&mut self, parameter: &'ast GenericParameter) {
+        self.open("generic_parameter");
+        walk_generic_parameter(self, parameter);
+        self.close(None);
+    }
+
+    fn visit_type_alias(&mut self, alias: &'ast TypeAlias) {
+        self.open("type_alias");
+        walk_type_alias(self, alias);
+        self.close(None);
+    }
+
+    fn visit_tuple_field(&mut self, field: &'ast TupleField) {
+        self.open("field");
+        walk_tuple_field(self, field);
+        self.close(None);
+    }
+
+    fn visit_type(&mut self, ty: &'ast Type) {
+        let name = match ty {
+            Type::Path(path) => {
+                self.visit_type_path(path);
+                return;
+            }
+            Type::Function { .. } => "function_type",
+            Type::Parenthesized { .. } => "parenthesized_type",
+            Type::TraitObject { .. } => "trait_object_type",
+            Type::Reference { mutable: false, .. } => "reference_type",
+            Type::Reference { mutable: true, .. } => "mutable_reference_type",
+            Type::Array { .. } => "array_type",
+            Type::Tuple { .. } => "tuple_type",
+            Type::WithQualifiedPath { .. } => "qualified_path_type",
+        };
+
+        self.open(name);
+        walk_type(self, ty);
+        self.close(Some(ty.span()));
+    }
+
+    fn visit_type_path(&mut self, path: &'ast TypePath) {
+        self.open("type");
+        walk_type_path(self, path);
+        self.close(Some(path.span));
+    }
+
+    fn visit_generic_arguments(&mut self, arguments: &'ast [GenericArgument]) {
+        self.open("generic_arguments");
+        walk_generic_arguments(self, arguments);
+        self.close(None);
+    }
+
+    fn visit_generic_argument(&mut self, argument: &'ast GenericArgument) {
+        match argument {
+            GenericArgument::Type(..) => walk_generic_argument(self, argument),
+            GenericArgument::AssociatedType { .. } => {
+                self.open("associated_type");
+                walk_generic_argument(self, argument);
+                self.close(None);
+            }
+        }
+    }
+
+    fn visit_trait_bounds(&mut self, bounds: &'ast [TypePath]) {
+        self.open("bounds");
+        walk_trait_bounds(self, bounds);
+        self.close(None);
+    }
+
+    fn visit_if_block(&mut self, block: &'ast (Expression, StatementsBlock)) {
+        self.open("branch");
+        walk_if_block(self, block);
+        self.close(None);
+    }
+
+    fn visit_statements_block(&mut self, block: &'ast StatementsBlock) {
+        self.open("block");
+        walk_statements_block(self, block);
+        self.close(None);
+    }
+
+    fn visit_statement(&mut self, statement: &'ast Statement) {
+        let (name, span) = match statement {
+            Statement::Break { span, label } => {
+                self.write_jump("break", *span, *label);
+                return;
+            }
+            Statement::Continue { span, label } => {
+                self.write_jump("continue", *span, *label);
+                return;
+            }
+            Statement::Defer { .. } => ("defer", None),
+            Statement::Expression {
+                has_semicolon: false,
+                ..
+            } => {
+                walk_statement(self, statement);
+                return;
+            }
+            Statement::Expression { .. } => ("statement", None),
+            Statement::Let { .. } => ("let", None),
+            Statement::Return { .. } => ("return", None),
+        };
+
+        self.open(name);
+        walk_statement(self, statement);
+        self.close(span);
+    }
+
+    fn visit_expression(&mut self, expression: &'ast Expression) {
+        let name = match expression {
+            Expression::Identifier(identifier) => {
+                self.visit_identifier(*identifier);
+                return;
+            }
+            Expression::Literal(literal) => {
+                self.visit_literal(literal);
+                return;
+            }
+            Expression::Binary {
+                left,
+                operator,
+                right,
+                span,
+            } => {
+                self.open("binary");
+                self.visit_binary_operator(*operator);
+                self.visit_expression(left);
+                self.visit_expression(right);
+                self.close(Some(*span));
+                return;
+            }
+            Expression::Prefix {
+                inner,
+                operator,
+                span,
+            } => {
+                self.open("prefix");
+                self.visit_prefix_operator(*operator);
+                self.visit_expression(inner);
+                self.close(Some(*span));
+                return;
+            }
+            Expression::Postfix {
+                inner,
+                operator,
+                span,
+            } => {
+                self.open("postfix");
+                self.visit_postfix_operator(*operator);
+                self.visit_expression(inner);
+                self.close(Some(*span));
+                return;
+            }
+            Expression::Range {
+                span,
+                start,
+                end,
+                inclusive,
+            } => {
+                self.open(if *inclusive {
+                    "inclusive_range"
+                } else {
+                    "range"
+                });
+
+                // Missing bounds are written as `_`, so that `(range _ b)` and
+                // `(range a _)` can be told apart.
+                for bound in [start, end] {
+                    match bound {
+                        Some(bound) => self.visit_expression(bound),
+                        None => self.write_atom("_"),
+                    }
+                }
+
+                self.close(Some(*span));
+                return;
+            }
+            Expression::While {
+                span,
+                label,
+                condition,
+                body,
+            } => {
+                self.open("while");
+                self.write_label(*label);
+                self.visit_expression(condition);
+                self.visit_statements_block(body);
+                self.close(Some(*span));
+                return;
+            }
+            Expression::TupleFieldAccess { span, left, index } => {
+                self.open("tuple_field_access");
+                self.visit_expression(left);
+                self.write_atom(index.to_string());
+                self.close(Some(*span));
+                return;
+            }
+            Expression::As { .. } => "as",
+            Expression::Call { .. } => "call",
+            Expression::FieldAccess { .. } => "field_access",
+            Expression::Index { .. } => "index",
+            Expression::For { .. } => "for",
+            Expression::GenericArguments { .. } => "with_generic_arguments",
+            Expression::If { .. } => "if",
+            Expression::Lambda { .. } => "lambda",
+            Expression::List { .. } => "list",
+            Expression::Match { .. } => "match",
+            Expression::Parenthesized { .. } => "parenthesized",
+            Expression::StatementsBlock { .. } => "block_expression",
+            Expression::Struct { .. } => "struct_expression",
+            Expression::Tuple { .. } => "tuple",
+        };
+
+        self.open(name);
+        walk_expression(self, expression);
+        self.close(Some(expression.span()));
+    }
+
+    fn visit_lambda_function_parameters(&mut self, parameters: &'ast [LambdaFunctionParameter]) {
+        self.open("parameters");
+        walk_lambda_function_parameters(self, parameters);
+        self.close(None);
+    }
+
+    fn visit_lambda_function_parameter(&mut self, parameter: &'ast LambdaFunctionParameter) {
+        self.open("parameter");
+        walk_lambda_function_parameter(self, parameter);
+        self.close(None);
+    }
+
+    fn visit_function_parameters(&mut self, parameters: &'ast [FunctionParameter]) {
+        self.open("parameters");
+        walk_function_parameters(self, parameters);
+        self.close(None);
+    }
+
+    fn visit_self_function_parameter(&mut self, parameter: &'ast SelfParameter) {
+        self.open("self");
+        walk_self_function_parameter(self, parameter);
+        self.close(Some(parameter.self_span));
+    }
+
+    fn visit_just_function_parameter(&mut self, parameter: &'ast JustFunctionParameter) {
+        self.open("parameter");
+        walk_just_function_parameter(self, parameter);
+        self.close(None);
+    }
+
+    fn visit_match_expression_item(&mut self, item: &'ast MatchExpressionItem) {
+        self.open("arm");
+        walk_match_expression_item(self, item);
+        self.close(None);
+    }
+
+    fn visit_struct_expression_item(&mut self, item: &'ast StructExpressionItem) {
+        self.open("field");
+        walk_struct_expression_item(self, item);
+        self.close(None);
+    }
+
+    fn visit_binary_operator(&mut self, operator: BinaryOperator) {
+        self.write_operator(operator.raw);
+    }
+
+    fn visit_postfix_operator(&mut self, operator: PostfixOperator) {
+        self.write_operator(operator.raw);
+    }
+
+    fn visit_prefix_operator(&mut self, operator: PrefixOperator) {
+        self.write_operator(operator.raw);
+    }
+
+    fn visit_pattern(&mut self, pattern: &'ast Pattern) {
+        let name = match pattern {
+            Pattern::Literal(literal) => {
+                self.visit_literal(literal);
+                return;
+            }
+            Pattern::Identifier { pattern: None, .. } | Pattern::Path { .. } => {
+                walk_pattern(self, pattern);
+                return;
+            }
+            Pattern::Rest { .. } => {
+                self.write_atom("..");
+                return;
+            }
+            Pattern::Wildcard { .. } => {
+                self.write_atom("_");
+                return;
+            }
+            Pattern::NegativeLiteral { .. } => "negative",
+            Pattern::Identifier { .. } => "binding",
+            Pattern::Grouped { .. } => "grouped_pattern",
+            Pattern::List { .. } => "list_pattern",
+            Pattern::Or { .. } => "or_pattern",
+            Pattern::Struct { .. } => "struct_pattern",
+            Pattern::Tuple { .. } => "tuple_pattern",
+            Pattern::TupleLike { .. } => "tuple_like_pattern",
+        };
+
+        self.open(name);
+        walk_pattern(self, pattern);
+        self.close(Some(pattern.span()));
+    }
+
+    fn visit_literal(&mut self, literal: &'ast Literal) {
+        match literal {
+            Literal::Boolean { value, .. } => {
+                self.open("boolean");
+                self.write_atom(value.to_string());
+            }
+            Literal::Character { value, .. } => {
+                self.open("character");
+                self.write_atom(format!("{value:?}"));
+            }
+            Literal::Float { value, .. } => {
+                self.open("float");
+                self.write_atom(format!("{value:?}"));
+            }
+            Literal::Integer { value, .. } => {
+                self.open("integer");
+                self.write_atom(value.to_string());
+            }
+            Literal::String { value, .. } => {
+                self.open("string");
+                self.write_atom(format!("{value:?}"));
+            }
+        }
+
+        self.close(Some(literal.span()));
+    }
+
+    fn visit_struct_field_pattern(&mut self, pattern: &'ast StructFieldPattern) {
+        match pattern {
+            StructFieldPattern::NotRest { span, .. } => {
+                self.open("field");
+                walk_struct_field_pattern(self, pattern);
+                self.close(Some(*span));
+            }
+            StructFieldPattern::Rest { .. } => self.write_atom(".."),
+        }
+    }
+}
+
+/// Serialize a module AST into a compact S-expression string.
+///
+/// ```
+/// # use ry_ast::{serialize::serialize_ast_sexpr, IdentifierAst, ImportPath, Item, Module, Path};
+/// # use ry_filesystem::span::Span;
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let foo = IdentifierAst {
+///     span: Span { start: 7, end: 10 },
+///     symbol: interner.get_or_intern("foo"),
+/// };
+///
+/// let module = Module {
+///     items: vec![Item::Import {
+///         attributes: vec![],
+///         path: ImportPath {
+///             left: Path {
+///                 span: foo.span,
+///                 identifiers: vec![foo],
+///             },
+///             r#as: None,
+///         },
+///     }],
+///     docstring: None,
+/// };
+///
+/// assert_eq!(serialize_ast_sexpr(&module, &interner), "(module (import foo))");
+/// ```
+#[must_use]
+pub fn serialize_ast_sexpr(module: &Module, interner: &Interner) -> String {
+    let mut serializer = SExprSerializer::new(interner);
+    serializer.visit_module(module);
+    serializer.take_output()
+}
+
+/// Returns a human-readable representation of a type, that reconstructs its
+/// surface syntax. Used when emitting diagnostics about types.
+///
+/// ```
+/// # use ry_ast::{serialize::display_type, Path, IdentifierAst, Type, TypePath, TypePathSegment};
+/// # use ry_filesystem::span::Span;
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let span = Span { start: 0, end: 0 };
+/// let uint32 = Type::Path(TypePath {
+///     span,
+///     segments: vec![TypePathSegment {
+///         span,
+///         path: Path {
+///             span,
+///             identifiers: vec![IdentifierAst {
+///                 span,
+///                 symbol: interner.get_or_intern("uint32"),
+///             }],
+///         },
+///         generic_arguments: None,
+///     }],
+/// });
+///
+/// let tuple = Type::Tuple {
+///     span,
+///     element_types: vec![uint32.clone(), uint32],
+/// };
+///
+/// assert_eq!(display_type(&tuple, &interner), "(uint32, uint32)");
+/// ```
+#[must_use]
+pub fn display_type(ty: &Type, interner: &Interner) -> String {
+    unparse_type(ty, interner)
+}
+
+/// Serialize a module AST into a JSON string.
+///
+/// Every enum node is represented as an object with a single key - name
+/// of the node kind, spans are represented as `{"start": .., "end": ..}`
+/// and identifier symbols are resolved using the interner.
+///
+/// ```
+/// # use ry_ast::{serialize::serialize_ast_json, IdentifierAst, ImportPath, Item, Module, Path};
+/// # use ry_filesystem::span::Span;
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let foo = IdentifierAst {
+///     span: Span { start: 7, end: 10 },
+///     symbol: interner.get_or_intern("foo"),
+/// };
+///
+/// let module = Module {
+///     items: vec![Item::Import {
+///         attributes: vec![],
+///         path: ImportPath {
+///             left: Path {
+///                 span: foo.span,
+///                 identifiers: vec![foo],
+///             },
+///             r#as: None,
+///         },
+///     }],
+///     docstring: None,
+/// };
+///
+/// let json = serialize_ast_json(&module, &interner);
+///
+/// assert!(json.contains(r#""Import""#));
+/// assert!(json.contains(r#""symbol": "foo""#));
+/// ```
+///
+/// # Panics
+/// The function never panics in practice, because AST doesn't contain
+/// values that cannot be represented in JSON.
+#[cfg(feature = "serde")]
+#[must_use]
+pub fn serialize_ast_json(module: &Module, interner: &Interner) -> String {
+    let mut value = serde_json::to_value(module).expect("AST is always serializable into JSON");
+    resolve_symbols(&mut value, interner);
+
+    serde_json::to_string_pretty(&value).expect("JSON value is always serializable")
+}
+
+/// Replaces symbols (values of `symbol` keys) in the serialized AST
+/// with corresponding strings.
+#[cfg(feature = "serde")]
+fn resolve_symbols(value: &mut serde_json::Value, interner: &Interner) {
+    match value {
+        serde_json::Value::Object(object) => {
+            if let Some(symbol) = object.get_mut("symbol") {
+                if let Some(resolved) = symbol
+                    .as_u64()
+                    .and_then(|symbol| usize::try_from(symbol).ok())
+                    .and_then(|symbol| interner.resolve(symbol))
+                {
+                    *symbol = serde_json::Value::String(resolved.to_owned());
+                }
+            }
+
+            for value in object.values_mut() {
+                resolve_symbols(value, interner);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                resolve_symbols(value, interner);
+            }
+        }
+        _ => {}
+    }
+}
@@ -2,7 +2,7 @@
 use std::fmt::Display;
 
 use phf::phf_map;
-use ry_filesystem::span::Span;
+use ry_filesystem::span::{Span, Spanned};
 
 use crate::precedence::Precedence;
 
@@ -11,7 +11,6 @@ use crate::precedence::Precedence;
 pub enum RawLexError {
     DigitDoesNotCorrespondToBase,
     EmptyCharLiteral,
-    EmptyEscapeSequence,
     EmptyWrappedIdentifier,
     ExpectedCloseBracketInByteEscapeSequence,
     ExpectedCloseBracketInUnicodeEscapeSequence,
@@ -19,19 +18,28 @@ pub enum RawLexError {
     ExpectedDigitInUnicodeEscapeSequence,
     ExpectedOpenBracketInByteEscapeSequence,
     ExpectedOpenBracketInUnicodeEscapeSequence,
-    ExponentHasNoDigits,
     ExponentRequiresDecimalMantissa,
-    HasNoDigits,
+    IntegerLiteralOverflow,
     InvalidByteEscapeSequence,
-    InvalidDigit,
+    InvalidDigitForRadix,
+    InvalidDigitSeparator,
     InvalidRadixPoint,
     InvalidUnicodeEscapeSequence,
+    InvalidWrappedIdentifier,
+    MissingDigitsAfterRadixPrefix,
+    MissingExponentDigits,
     MoreThanOneCharInCharLiteral,
+    NonAsciiByteInByteLiteral,
     NumberParseError,
-    UnderscoreMustSeparateSuccessiveDigits,
+    SurrogateInUnicodeEscape,
+    UnescapedControlCharacterInString,
+    UnescapedTabInCharLiteral,
     UnexpectedChar,
     UnknownEscapeSequence,
+    UnterminatedBlockComment,
     UnterminatedCharLiteral,
+    UnterminatedInterpolation,
+    UnterminatedRawStringLiteral,
     UnterminatedStringLiteral,
     UnterminatedWrappedIdentifier,
 }
@@ -40,7 +48,6 @@ impl AsRef<str> for RawLexError {
     fn as_ref(&self) -> &str {
         match self {
             Self::EmptyCharLiteral => "empty character literal",
-            Self::EmptyEscapeSequence => "empty escape sequence",
             Self::EmptyWrappedIdentifier => "empty wrapped identifier literal",
             Self::ExpectedCloseBracketInByteEscapeSequence => {
                 "expected `}` in byte escape sequence"
@@ -56,22 +63,33 @@ impl AsRef<str> for RawLexError {
             Self::ExpectedOpenBracketInUnicodeEscapeSequence => {
                 "expected `{` in Unicode escape sequence"
             }
-            Self::ExponentHasNoDigits => "exponent has no digits",
             Self::ExponentRequiresDecimalMantissa => "exponent requires decimal mantissa",
             Self::DigitDoesNotCorrespondToBase => "digit doesn't correspond to the base",
-            Self::HasNoDigits => "has no digits",
+            Self::IntegerLiteralOverflow => "integer literal is too large to fit into `u64`",
             Self::InvalidByteEscapeSequence => "invalid byte escape sequence",
-            Self::InvalidDigit => "invalid digit",
+            Self::InvalidDigitForRadix => "invalid digit for the radix",
+            Self::InvalidDigitSeparator => "`_` must separate successive digits",
             Self::InvalidRadixPoint => "invalid radix point",
             Self::InvalidUnicodeEscapeSequence => "invalid Unicode escape sequence",
+            Self::InvalidWrappedIdentifier => "wrapped identifier is not a valid identifier",
+            Self::MissingDigitsAfterRadixPrefix => "missing digits after the radix prefix",
+            Self::MissingExponentDigits => "missing digits in the exponent",
             Self::MoreThanOneCharInCharLiteral => {
                 "more than one character inside character literal"
             }
-            Self::UnderscoreMustSeparateSuccessiveDigits => "`_` must separate successive digits",
+            Self::NonAsciiByteInByteLiteral => "non-ASCII character in byte literal",
             Self::NumberParseError => "number parsing error (overflow is possible)",
+            Self::SurrogateInUnicodeEscape => "surrogate code point in Unicode escape sequence",
+            Self::UnescapedControlCharacterInString => {
+                "unescaped control character in string literal"
+            }
+            Self::UnescapedTabInCharLiteral => "unescaped tab in character literal",
             Self::UnexpectedChar => "unexpected character",
             Self::UnknownEscapeSequence => "unknown escape sequence",
+            Self::UnterminatedBlockComment => "unterminated block comment",
             Self::UnterminatedCharLiteral => "unterminated character literal",
+            Self::UnterminatedInterpolation => "unterminated string interpolation",
+            Self::UnterminatedRawStringLiteral => "unterminated raw string literal",
             Self::UnterminatedStringLiteral => "unterminated string literal",
             Self::UnterminatedWrappedIdentifier => "unterminated wrapper identifier",
         }
@@ -106,6 +124,7 @@ pub enum NumberKind {
     Invalid,
     Int,
     Float,
+    Imaginary,
 }
 
 /// This enum represents a set of keywords used in the Ry programming language.
@@ -119,6 +138,7 @@ pub enum Keyword {
     For,
     Fun,
     If,
+    In,
     Impl,
     Pub,
     Return,
@@ -133,6 +153,9 @@ pub enum Keyword {
     Break,
     Continue,
     Dyn,
+    Const,
+    Static,
+    Mut,
 }
 
 impl AsRef<str> for Keyword {
@@ -152,6 +175,7 @@ impl AsRef<str> for Keyword {
             Self::While => "`while`",
             Self::As => "`as`",
             Self::For => "`for`",
+            Self::In => "`in`",
             Self::Where => "`where`",
             Self::Let => "`let`",
             Self::Match => "`match`",
@@ -159,6 +183,9 @@ impl AsRef<str> for Keyword {
             Self::Break => "`break`",
             Self::Continue => "`continue`",
             Self::Dyn => "`dyn`",
+            Self::Const => "`const`",
+            Self::Static => "`static`",
+            Self::Mut => "`mut`",
         }
     }
 }
@@ -173,9 +200,12 @@ impl Display for Keyword {
 /// Represents a punctuator.
 #[derive(Debug, Clone, PartialEq, Copy, Eq, Hash)]
 pub enum Punctuator {
-    /// Arrow (=>).
+    /// Arrow (->).
     Arrow,
 
+    /// Fat arrow (=>).
+    FatArrow,
+
     /// Ampersand (&).
     And,
 
@@ -215,6 +245,9 @@ pub enum Punctuator {
     /// Colon (:).
     Colon,
 
+    /// Double colon (::).
+    ColonColon,
+
     /// Comma (,).
     Comma,
 
@@ -224,6 +257,9 @@ pub enum Punctuator {
     /// Dot Dot (..).
     DotDot,
 
+    /// Dot Dot Equal (..=).
+    DotDotEq,
+
     /// Equal (==).
     Eq,
 
@@ -330,7 +366,8 @@ impl AsRef<str> for Punctuator {
             Self::LessThanOrEq => "`<=`",
             Self::Assign => "`=`",
             Self::Eq => "`==`",
-            Self::Arrow => "`=>`",
+            Self::Arrow => "`->`",
+            Self::FatArrow => "`=>`",
             Self::NotEq => "`!=`",
             Self::RightShift => "`>>`",
             Self::LeftShift => "`<<`",
@@ -356,8 +393,10 @@ impl AsRef<str> for Punctuator {
             Self::Comma => "`,`",
             Self::Dot => "`.`",
             Self::DotDot => "`..`",
+            Self::DotDotEq => "`..=`",
             Self::Semicolon => "`;`",
             Self::Colon => "`:`",
+            Self::ColonColon => "`::`",
             Self::PlusPlus => "`++`",
             Self::MinusMinus => "`--`",
             Self::AsteriskAsterisk => "`**`",
@@ -379,6 +418,10 @@ impl Display for Punctuator {
 /// Represents token without a specific location in source text.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
 pub enum RawToken {
+    /// Byte literal (`b'x'`).
+    ByteLiteral,
+    /// Byte string literal (`b"..."`).
+    ByteStringLiteral,
     /// True boolean literal (`true`).
     TrueBoolLiteral,
     /// False boolean literal (`false`).
@@ -398,16 +441,39 @@ pub enum RawToken {
     FloatLiteral,
     /// Identifier.
     Identifier,
+    /// Imaginary number literal (`3i`, `2.5i`).
+    ImaginaryLiteral,
     /// Integer literal.
     IntegerLiteral,
     /// Error token.
     Error(RawLexError),
     /// Keyword.
     Keyword(Keyword),
+    /// Loop label (`'outer`).
+    Label,
     /// Punctuator.
     Punctuator(Punctuator),
+    /// Raw string literal (`r"..."`, `r#"..."#`).
+    RawStringLiteral,
     /// String literal.
     StringLiteral,
+    /// Part of an interpolated string literal, that precedes `${`.
+    ///
+    /// String `"hello ${name}!"` is scanned as the following sequence:
+    ///
+    /// 1. [`StringFragment`] (`"hello ${`), with contents `hello `;
+    /// 2. tokens of the interpolated expression ([`Identifier`] `name`);
+    /// 3. [`StringLiteral`] (`}!"`), with contents `!`.
+    ///
+    /// If the string contains more than one interpolation, the part between
+    /// them (`}...${`) is again scanned as [`StringFragment`]. So every
+    /// interpolated string is a sequence of one or more fragments, each followed
+    /// by an expression, which is terminated with [`StringLiteral`].
+    ///
+    /// [`StringFragment`]: RawToken::StringFragment
+    /// [`StringLiteral`]: RawToken::StringLiteral
+    /// [`Identifier`]: RawToken::Identifier
+    StringFragment,
 }
 
 impl AsRef<Self> for RawToken {
@@ -419,23 +485,32 @@ impl AsRef<Self> for RawToken {
 impl AsRef<str> for RawToken {
     fn as_ref(&self) -> &str {
         match self {
-            Self::Identifier => "identifier",
-            Self::StringLiteral => "string literal",
-            Self::IntegerLiteral => "integer literal",
-            Self::FloatLiteral => "float literal",
-            Self::CharLiteral => "character literal",
+            Self::Identifier => "<identifier>",
+            Self::StringLiteral => "<string literal>",
+            Self::StringFragment => "<string fragment>",
+            Self::RawStringLiteral => "<raw string literal>",
+            Self::IntegerLiteral => "<integer literal>",
+            Self::FloatLiteral => "<float literal>",
+            Self::ImaginaryLiteral => "<imaginary literal>",
+            Self::CharLiteral => "<character literal>",
+            Self::ByteLiteral => "<byte literal>",
+            Self::ByteStringLiteral => "<byte string literal>",
             Self::TrueBoolLiteral => "`true`",
             Self::FalseBoolLiteral => "`false`",
             Self::Keyword(keyword) => keyword.as_ref(),
+            Self::Label => "<label>",
             Self::Punctuator(punctuator) => punctuator.as_ref(),
-            Self::GlobalDocComment | Self::LocalDocComment => "doc comment",
-            Self::Comment => "comment",
-            Self::EndOfFile => "end of file",
-            Self::Error(..) => "error token",
+            Self::GlobalDocComment | Self::LocalDocComment => "<doc comment>",
+            Self::Comment => "<comment>",
+            Self::EndOfFile => "<eof>",
+            Self::Error(..) => "<error>",
         }
     }
 }
 
+/// Keywords, punctuators and boolean literals are displayed with their source
+/// spelling in backticks (`` `fun` ``, `` `**` ``), other tokens with a
+/// description in angle brackets (`<identifier>`, `<eof>`).
 impl Display for RawToken {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(self.as_ref())?;
@@ -463,6 +538,28 @@ impl RawToken {
     }
 }
 
+/// Category of a token, e.g. for syntax highlighting, where every category
+/// is mapped to its own color.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum TokenCategory {
+    /// Keywords (`fun`, `while`, ...).
+    Keyword,
+    /// Operators (`+`, `&&`, `..=`, ...).
+    Operator,
+    /// Literals, including boolean ones and parts of interpolated strings.
+    Literal,
+    /// Delimiters and separators (`(`, `}`, `,`, `::`, `=>`, ...).
+    Punctuation,
+    /// Comments, including doc comments.
+    Comment,
+    /// Identifiers and loop labels.
+    Identifier,
+    /// Error tokens.
+    Error,
+    /// End of file, which has nothing to highlight.
+    EndOfFile,
+}
+
 /// Represents a token with a specified location in source text.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct Token {
@@ -470,6 +567,29 @@ pub struct Token {
     pub raw: RawToken,
 }
 
+impl Token {
+    /// Returns `true` if the token is of the given kind, ignoring its span.
+    #[inline]
+    #[must_use]
+    pub fn has_raw(&self, raw: RawToken) -> bool {
+        self.raw == raw
+    }
+
+    /// Returns `true` if both tokens are of the same kind, ignoring their spans.
+    #[inline]
+    #[must_use]
+    pub fn raw_eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Spanned for Token {
+    #[inline]
+    fn span(&self) -> Span {
+        self.span
+    }
+}
+
 /// Macro used to easily initialize tokens.
 ///
 /// # Example
@@ -490,11 +610,13 @@ pub struct Token {
 /// ```
 #[macro_export]
 macro_rules! Token {
+    [::] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::ColonColon)};
     [:] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Colon)};
     [@] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::AtSign)};
     [++] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::PlusPlus)};
     [+=] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::PlusEq)};
     [+] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Plus)};
+    [->] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Arrow)};
     [--] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::MinusMinus)};
     [-=] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::MinusEq)};
     [-] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Minus)};
@@ -532,11 +654,12 @@ macro_rules! Token {
     [,] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Comma)};
     [.] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Dot)};
     [..] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::DotDot)};
+    [..=] =>                {$crate::token::RawToken::Punctuator($crate::token::Punctuator::DotDotEq)};
     [;] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Semicolon)};
     [%] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Percent)};
     [%=] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::PercentEq)};
     [#] =>                  {$crate::token::RawToken::Punctuator($crate::token::Punctuator::HashTag)};
-    [=>] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::Arrow)};
+    [=>] =>                 {$crate::token::RawToken::Punctuator($crate::token::Punctuator::FatArrow)};
     [true] =>               {$crate::token::RawToken::TrueBoolLiteral};
     [false] =>              {$crate::token::RawToken::FalseBoolLiteral};
     [import] =>             {$crate::token::RawToken::Keyword($crate::token::Keyword::Import)};
@@ -555,11 +678,15 @@ macro_rules! Token {
     [as] =>                 {$crate::token::RawToken::Keyword($crate::token::Keyword::As)};
     [type] =>               {$crate::token::RawToken::Keyword($crate::token::Keyword::Type)};
     [for] =>                {$crate::token::RawToken::Keyword($crate::token::Keyword::For)};
+    [in] =>                 {$crate::token::RawToken::Keyword($crate::token::Keyword::In)};
     [where] =>              {$crate::token::RawToken::Keyword($crate::token::Keyword::Where)};
     [match] =>              {$crate::token::RawToken::Keyword($crate::token::Keyword::Match)};
     [break] =>              {$crate::token::RawToken::Keyword($crate::token::Keyword::Break)};
     [continue] =>           {$crate::token::RawToken::Keyword($crate::token::Keyword::Continue)};
     [dyn] =>                {$crate::token::RawToken::Keyword($crate::token::Keyword::Dyn)};
+    [const] =>              {$crate::token::RawToken::Keyword($crate::token::Keyword::Const)};
+    [static] =>             {$crate::token::RawToken::Keyword($crate::token::Keyword::Static)};
+    [mut] =>                {$crate::token::RawToken::Keyword($crate::token::Keyword::Mut)};
 }
 
 /// List of reserved Ry names: keywords, boolean literals & etc..
@@ -582,14 +709,76 @@ pub static RESERVED: phf::Map<&'static str, RawToken> = phf_map! {
     "as" => Token![as],
     "type" => Token![type],
     "for" => Token![for],
+    "in" => Token![in],
     "where" => Token![where],
     "match" => Token![match],
     "break" => Token![break],
     "continue" => Token![continue],
     "dyn" => Token![dyn],
+    "const" => Token![const],
+    "static" => Token![static],
+    "mut" => Token![mut],
 };
 
 impl Punctuator {
+    /// Returns the category of the punctuator: either
+    /// [`TokenCategory::Operator`] or [`TokenCategory::Punctuation`].
+    #[must_use]
+    pub const fn category(&self) -> TokenCategory {
+        match self {
+            Self::Arrow
+            | Self::FatArrow
+            | Self::AtSign
+            | Self::CloseBrace
+            | Self::CloseBracket
+            | Self::CloseParent
+            | Self::Colon
+            | Self::ColonColon
+            | Self::Comma
+            | Self::Dot
+            | Self::OpenBrace
+            | Self::OpenBracket
+            | Self::OpenParent
+            | Self::Semicolon
+            | Self::HashTag => TokenCategory::Punctuation,
+            Self::And
+            | Self::AndEq
+            | Self::AndAnd
+            | Self::Assign
+            | Self::Asterisk
+            | Self::AsteriskAsterisk
+            | Self::AsteriskEq
+            | Self::Bang
+            | Self::DotDot
+            | Self::DotDotEq
+            | Self::Eq
+            | Self::GreaterThan
+            | Self::GreaterThanOrEq
+            | Self::LeftShift
+            | Self::LessThan
+            | Self::LessThanOrEq
+            | Self::Minus
+            | Self::MinusEq
+            | Self::MinusMinus
+            | Self::Not
+            | Self::NotEq
+            | Self::Or
+            | Self::OrEq
+            | Self::OrOr
+            | Self::Percent
+            | Self::PercentEq
+            | Self::Plus
+            | Self::PlusEq
+            | Self::PlusPlus
+            | Self::QuestionMark
+            | Self::RightShift
+            | Self::Slash
+            | Self::SlashEq
+            | Self::Xor
+            | Self::XorEq => TokenCategory::Operator,
+        }
+    }
+
     #[inline]
     #[must_use]
     pub const fn to_precedence(&self) -> Precedence {
@@ -606,7 +795,10 @@ impl Punctuator {
             | Self::AsteriskEq
             | Self::SlashEq
             | Self::OrEq
+            | Self::AndEq
+            | Self::PercentEq
             | Self::XorEq => Precedence::Assign,
+            Self::DotDot | Self::DotDotEq => Precedence::Range,
             Self::LessThan | Self::LessThanOrEq | Self::GreaterThan | Self::GreaterThanOrEq => {
                 Precedence::Comparison
             }
@@ -687,4 +879,38 @@ impl RawToken {
     pub const fn postfix_operator(&self) -> bool {
         matches!(self, Token![?] | Token![++] | Token![--])
     }
+
+    /// Returns the category of the token.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_ast::{token::TokenCategory, Token};
+    /// assert_eq!(Token![while].category(), TokenCategory::Keyword);
+    /// assert_eq!(Token![&&].category(), TokenCategory::Operator);
+    /// assert_eq!(Token![,].category(), TokenCategory::Punctuation);
+    /// ```
+    #[must_use]
+    pub const fn category(&self) -> TokenCategory {
+        match self {
+            Self::Keyword(..) => TokenCategory::Keyword,
+            Self::Punctuator(punctuator) => punctuator.category(),
+            Self::ByteLiteral
+            | Self::ByteStringLiteral
+            | Self::TrueBoolLiteral
+            | Self::FalseBoolLiteral
+            | Self::CharLiteral
+            | Self::FloatLiteral
+            | Self::ImaginaryLiteral
+            | Self::IntegerLiteral
+            | Self::RawStringLiteral
+            | Self::StringLiteral
+            | Self::StringFragment => TokenCategory::Literal,
+            Self::Comment | Self::GlobalDocComment | Self::LocalDocComment => {
+                TokenCategory::Comment
+            }
+            Self::Identifier | Self::Label => TokenCategory::Identifier,
+            Self::Error(..) => TokenCategory::Error,
+            Self::EndOfFile => TokenCategory::EndOfFile,
+        }
+    }
 }
@@ -0,0 +1,1217 @@
+//! Defines [`unparse()`] to convert AST back into Ry source code.
+//!
+//! The output is formatted with 4 spaces of indentation, statement blocks
+//! consisting of a single simple statement are printed on the same line, and
+//! parentheses are only inserted into expressions when operator precedence
+//! requires them (see [`Precedence`]).
+
+use ry_interner::Interner;
+
+use crate::{
+    precedence::{Associativity, Precedence},
+    token::RawToken,
+    Attribute, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
+    GenericParameter, IdentifierAst, Impl, ImportPath, Item, Literal, MatchExpressionItem, Module,
+    Path, Pattern, Statement, StatementsBlock, StructExpressionItem, StructField,
+    StructFieldPattern, TraitItem, TupleField, Type, TypeAlias, TypePath, TypePathSegment,
+    Visibility, WhereClause, WhereClauseItem,
+};
+
+/// Convert a module AST back into Ry source code.
+///
+/// ```
+/// # use ry_ast::{unparse::unparse, IdentifierAst, ImportPath, Item, Module, Path};
+/// # use ry_filesystem::span::Span;
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let foo = IdentifierAst {
+///     span: Span { start: 7, end: 10 },
+///     symbol: interner.get_or_intern("foo"),
+/// };
+///
+/// let module = Module {
+///     items: vec![Item::Import {
+///         attributes: vec![],
+///         path: ImportPath {
+///             left: Path {
+///                 span: foo.span,
+///                 identifiers: vec![foo],
+///             },
+///             r#as: None,
+///         },
+///     }],
+///     docstring: None,
+/// };
+///
+/// assert_eq!(unparse(&module, &interner), "import foo;\n");
+/// ```
+#[must_use]
+pub fn unparse(module: &Module, interner: &Interner) -> String {
+    let mut unparser = Unparser::new(interner);
+    unparser.unparse_module(module);
+    unparser.output
+}
+
+/// Converts a type back into source code.
+pub(crate) fn unparse_type(ty: &Type, interner: &Interner) -> String {
+    let mut unparser = Unparser::new(interner);
+    unparser.unparse_type(ty);
+    unparser.output
+}
+
+/// Returns precedence of an expression, or [`None`], if expression is
+/// primary or postfix-like, so it never has to be parenthesized, when being
+/// an operand of another operator.
+fn precedence_of_expression(expression: &Expression) -> Option<Precedence> {
+    match expression {
+        Expression::Binary { operator, .. } => Some(RawToken::from(operator.raw).to_precedence()),
+        Expression::As { .. } => Some(Precedence::As),
+        Expression::Prefix { .. } => Some(Precedence::Unary),
+        Expression::Range { .. } => Some(Precedence::Range),
+        _ => None,
+    }
+}
+
+/// Escapes a character, so that it can be put into a string or a character literal.
+fn escape_char(c: char, quote: char, output: &mut String) {
+    match c {
+        '\\' => output.push_str("\\\\"),
+        '\n' => output.push_str("\\n"),
+        '\r' => output.push_str("\\r"),
+        '\t' => output.push_str("\\t"),
+        '\u{0008}' => output.push_str("\\b"),
+        '\u{000C}' => output.push_str("\\f"),
+        '$' if quote == '"' => output.push_str("\\$"),
+        _ if c == quote => {
+            output.push('\\');
+            output.push(c);
+        }
+        _ if c.is_control() => output.extend(c.escape_unicode()),
+        _ => output.push(c),
+    }
+}
+
+struct Unparser<'interner> {
+    interner: &'interner Interner,
+    indentation: usize,
+    output: String,
+}
+
+impl<'interner> Unparser<'interner> {
+    const fn new(interner: &'interner Interner) -> Self {
+        Self {
+            interner,
+            indentation: 0,
+            output: String::new(),
+        }
+    }
+
+    fn write(&mut self, s: &str) {
+        self.output.push_str(s);
+    }
+
+    fn write_indentation(&mut self) {
+        for _ in 0..self.indentation {
+            self.output.push_str("    ");
+        }
+    }
+
+    fn write_newline(&mut self) {
+        self.output.push('\n');
+    }
+
+    fn write_separated<T>(&mut self, elements: &[T], separator: &str, f: impl Fn(&mut Self, &T)) {
+        for (idx, element) in elements.iter().enumerate() {
+            if idx != 0 {
+                self.write(separator);
+            }
+
+            f(self, element);
+        }
+    }
+
+    fn write_docstring(&mut self, docstring: Option<&str>, prefix: &str) {
+        if let Some(docstring) = docstring {
+            for line in docstring.split('\n') {
+                self.write_indentation();
+                self.write(prefix);
+
+                if !line.is_empty() {
+                    self.write(" ");
+                    self.write(line);
+                }
+
+                self.write_newline();
+            }
+        }
+    }
+
+    fn write_attributes(&mut self, attributes: &[Attribute]) {
+        for attribute in attributes {
+            self.write_indentation();
+            self.write("#[");
+            self.unparse_path(&attribute.path);
+
+            if !attribute.arguments.is_empty() {
+                self.write("(");
+                self.write_separated(&attribute.arguments, ", ", Self::unparse_expression);
+                self.write(")");
+            }
+
+            self.write("]");
+            self.write_newline();
+        }
+    }
+
+    fn unparse_identifier(&mut self, identifier: IdentifierAst) {
+        let name = self.interner.resolve(identifier.symbol).unwrap_or("?");
+        self.output.push_str(name);
+    }
+
+    fn unparse_path(&mut self, path: &Path) {
+        self.write_separated(&path.identifiers, ".", |this, identifier| {
+            this.unparse_identifier(*identifier);
+        });
+    }
+
+    fn unparse_visibility(&mut self, visibility: Visibility) {
+        if visibility.span_of_pub().is_some() {
+            self.write("pub ");
+        }
+    }
+
+    fn unparse_module(&mut self, module: &Module) {
+        self.write_docstring(module.docstring.as_deref(), "//!");
+
+        for (idx, item) in module.items.iter().enumerate() {
+            if idx != 0 || module.docstring.is_some() {
+                self.write_newline();
+            }
+
+            self.unparse_item(item);
+        }
+    }
+
+    fn unparse_item(&mut self, item: &Item) {
+        match item {
+            Item::Enum {
+                visibility,
+                name,
+                generic_parameters,
+                where_clause,
+                items,
+                attributes,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_attributes(attributes);
+                self.write_indentation();
+                self.unparse_visibility(*visibility);
+                self.write("enum ");
+                self.unparse_identifier(*name);
+                self.unparse_generic_parameters(generic_parameters.as_deref());
+                self.write(" {");
+                self.write_newline();
+
+                self.indentation += 1;
+                for item in items {
+                    self.unparse_enum_item(item);
+                }
+                self.indentation -= 1;
+
+                self.write_indentation();
+                self.write("}");
+                self.unparse_where_clause(where_clause.as_ref());
+                self.write_newline();
+            }
+            Item::Function(function) => self.unparse_function(function),
+            Item::Import { attributes, path } => {
+                self.write_attributes(attributes);
+                self.unparse_import(path);
+            }
+            Item::Trait {
+                visibility,
+                name,
+                generic_parameters,
+                where_clause,
+                items,
+                attributes,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_attributes(attributes);
+                self.write_indentation();
+                self.unparse_visibility(*visibility);
+                self.write("trait ");
+                self.unparse_identifier(*name);
+                self.unparse_generic_parameters(generic_parameters.as_deref());
+                self.unparse_where_clause(where_clause.as_ref());
+                self.unparse_trait_items(items);
+            }
+            Item::Impl(implementation) => self.unparse_impl(implementation),
+            Item::Struct {
+                visibility,
+                name,
+                generic_parameters,
+                where_clause,
+                fields,
+                attributes,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_attributes(attributes);
+                self.write_indentation();
+                self.unparse_visibility(*visibility);
+                self.write("struct ");
+                self.unparse_identifier(*name);
+                self.unparse_generic_parameters(generic_parameters.as_deref());
+                self.unparse_where_clause(where_clause.as_ref());
+                self.unparse_struct_fields(fields);
+                self.write_newline();
+            }
+            Item::TupleLikeStruct {
+                visibility,
+                name,
+                generic_parameters,
+                where_clause,
+                fields,
+                attributes,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_attributes(attributes);
+                self.write_indentation();
+                self.unparse_visibility(*visibility);
+                self.write("struct ");
+                self.unparse_identifier(*name);
+                self.unparse_generic_parameters(generic_parameters.as_deref());
+                self.unparse_where_clause(where_clause.as_ref());
+                self.unparse_tuple_fields(fields);
+                self.write(";");
+                self.write_newline();
+            }
+            Item::TypeAlias(alias) => self.unparse_type_alias(alias),
+            Item::Const {
+                visibility,
+                name,
+                ty,
+                value,
+                attributes,
+                docstring,
+            }
+            | Item::Static {
+                visibility,
+                name,
+                ty,
+                value,
+                attributes,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_attributes(attributes);
+                self.write_indentation();
+                self.unparse_visibility(*visibility);
+                self.write(if matches!(item, Item::Const { .. }) {
+                    "const "
+                } else {
+                    "static "
+                });
+                self.unparse_identifier(*name);
+                self.write(": ");
+                self.unparse_type(ty);
+                self.write(" = ");
+                self.unparse_expression(value);
+                self.write(";");
+                self.write_newline();
+            }
+        }
+    }
+
+    fn unparse_import(&mut self, path: &ImportPath) {
+        self.write_indentation();
+        self.write("import ");
+        self.unparse_path(&path.left);
+
+        if let Some(r#as) = path.r#as {
+            self.write(" as ");
+            self.unparse_identifier(r#as);
+        }
+
+        self.write(";");
+        self.write_newline();
+    }
+
+    fn unparse_enum_item(&mut self, item: &EnumItem) {
+        match item {
+            EnumItem::Just {
+                name,
+                discriminant,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_indentation();
+                self.unparse_identifier(*name);
+
+                if let Some(discriminant) = discriminant {
+                    self.write(" = ");
+                    self.unparse_expression(discriminant);
+                }
+            }
+            EnumItem::Tuple {
+                name,
+                fields,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_indentation();
+                self.unparse_identifier(*name);
+                self.unparse_tuple_fields(fields);
+            }
+            EnumItem::Struct {
+                name,
+                fields,
+                docstring,
+            } => {
+                self.write_docstring(docstring.as_deref(), "///");
+                self.write_indentation();
+                self.unparse_identifier(*name);
+                self.unparse_struct_fields(fields);
+            }
+        }
+
+        self.write(",");
+        self.write_newline();
+    }
+
+    fn unparse_struct_fields(&mut self, fields: &[StructField]) {
+        if fields.is_empty() {
+            self.write(" {}");
+            return;
+        }
+
+        self.write(" {");
+        self.write_newline();
+        self.indentation += 1;
+
+        for field in fields {
+            self.write_docstring(field.docstring.as_deref(), "///");
+            self.write_indentation();
+            self.unparse_visibility(field.visibility);
+            self.unparse_identifier(field.name);
+            self.write(": ");
+            self.unparse_type(&field.ty);
+            self.write(",");
+            self.write_newline();
+        }
+
+        self.indentation -= 1;
+        self.write_indentation();
+        self.write("}");
+    }
+
+    fn unparse_tuple_fields(&mut self, fields: &[TupleField]) {
+        self.write("(");
+        self.write_separated(fields, ", ", |this, field| {
+            this.unparse_visibility(field.visibility);
+            this.unparse_type(&field.ty);
+        });
+        self.write(")");
+    }
+
+    fn unparse_trait_items(&mut self, items: &[TraitItem]) {
+        if items.is_empty() {
+            self.write(" {}");
+            self.write_newline();
+            return;
+        }
+
+        self.write(" {");
+        self.write_newline();
+        self.indentation += 1;
+
+        for (idx, item) in items.iter().enumerate() {
+            if idx != 0 {
+                self.write_newline();
+            }
+
+            match item {
+                TraitItem::AssociatedFunction(function) => self.unparse_function(function),
+                TraitItem::TypeAlias(alias) => self.unparse_type_alias(alias),
+            }
+        }
+
+        self.indentation -= 1;
+        self.write_indentation();
+        self.write("}");
+        self.write_newline();
+    }
+
+    fn unparse_impl(&mut self, implementation: &Impl) {
+        self.write_docstring(implementation.docstring.as_deref(), "///");
+        self.write_attributes(&implementation.attributes);
+        self.write_indentation();
+        self.write("impl");
+        self.unparse_generic_parameters(implementation.generic_parameters.as_deref());
+        self.write(" ");
+
+        if let Some(r#trait) = &implementation.r#trait {
+            self.unparse_type(r#trait);
+            self.write(" for ");
+        }
+
+        self.unparse_type(&implementation.ty);
+        self.unparse_where_clause(implementation.where_clause.as_ref());
+        self.unparse_trait_items(&implementation.items);
+    }
+
+    fn unparse_type_alias(&mut self, alias: &TypeAlias) {
+        self.write_docstring(alias.docstring.as_deref(), "///");
+        self.write_attributes(&alias.attributes);
+        self.write_indentation();
+        self.unparse_visibility(alias.visibility);
+        self.write("type ");
+        self.unparse_identifier(alias.name);
+        self.unparse_generic_parameters(alias.generic_parameters.as_deref());
+
+        if let Some(bounds) = &alias.bounds {
+            self.write(": ");
+            self.unparse_trait_bounds(bounds);
+        }
+
+        if let Some(value) = &alias.value {
+            self.write(" = ");
+            self.unparse_type(value);
+        }
+
+        self.unparse_where_clause(alias.where_clause.as_ref());
+
+        self.write(";");
+        self.write_newline();
+    }
+
+    fn unparse_function(&mut self, function: &Function) {
+        self.write_docstring(function.docstring.as_deref(), "///");
+        self.write_attributes(&function.attributes);
+        self.write_indentation();
+        self.unparse_visibility(function.visibility);
+        self.write("fun ");
+        self.unparse_identifier(function.name);
+        self.unparse_generic_parameters(function.generic_parameters.as_deref());
+
+        self.write("(");
+        self.write_separated(
+            &function.parameters,
+            ", ",
+            |this, parameter| match parameter {
+                FunctionParameter::Just(parameter) => {
+                    this.unparse_identifier(parameter.name);
+                    this.write(": ");
+                    this.unparse_type(&parameter.ty);
+
+                    if let Some(default_value) = &parameter.default_value {
+                        this.write(" = ");
+                        this.unparse_expression(default_value);
+                    }
+                }
+                FunctionParameter::Self_(parameter) => {
+                    this.write("self");
+
+                    if let Some(ty) = &parameter.ty {
+                        this.write(": ");
+                        this.unparse_type(ty);
+                    }
+                }
+            },
+        );
+        self.write(")");
+
+        if let Some(return_type) = &function.return_type {
+            self.write(": ");
+            self.unparse_type(return_type);
+        }
+
+        self.unparse_where_clause(function.where_clause.as_ref());
+
+        if let Some(body) = &function.body {
+            self.write(" ");
+            self.unparse_statements_block(body);
+        } else {
+            self.write(";");
+        }
+
+        self.write_newline();
+    }
+
+    fn unparse_generic_parameters(&mut self, parameters: Option<&[GenericParameter]>) {
+        if let Some(parameters) = parameters {
+            self.write("[");
+            self.write_separated(parameters, ", ", |this, parameter| {
+                this.unparse_identifier(parameter.name);
+
+                if let Some(bounds) = &parameter.bounds {
+                    this.write(": ");
+                    this.unparse_trait_bounds(bounds);
+                }
+
+                if let Some(default_value) = &parameter.default_value {
+                    this.write(" = ");
+                    this.unparse_type(default_value);
+                }
+            });
+            self.write("]");
+        }
+    }
+
+    fn unparse_where_clause(&mut self, where_clause: Option<&WhereClause>) {
+        if let Some(where_clause) = where_clause {
+            self.write(" where ");
+            self.write_separated(where_clause, ", ", |this, item| match item {
+                WhereClauseItem::Eq { left, right } => {
+                    this.unparse_type(left);
+                    this.write(" = ");
+                    this.unparse_type(right);
+                }
+                WhereClauseItem::Satisfies { ty, bounds } => {
+                    this.unparse_type(ty);
+                    this.write(": ");
+                    this.unparse_trait_bounds(bounds);
+                }
+            });
+        }
+    }
+
+    fn unparse_trait_bounds(&mut self, bounds: &[TypePath]) {
+        self.write_separated(bounds, " + ", Self::unparse_type_path);
+    }
+
+    fn unparse_type(&mut self, ty: &Type) {
+        match ty {
+            Type::Path(path) => self.unparse_type_path(path),
+            Type::Tuple { element_types, .. } => {
+                self.write("(");
+                self.write_separated(element_types, ", ", Self::unparse_type);
+
+                if element_types.len() == 1 {
+                    self.write(",");
+                }
+
+                self.write(")");
+            }
+            Type::Function {
+                parameter_types,
+                return_type,
+                ..
+            } => {
+                self.write("(");
+                self.write_separated(parameter_types, ", ", Self::unparse_type);
+                self.write("): ");
+                self.unparse_type(return_type);
+            }
+            Type::Parenthesized { inner, .. } => {
+                self.write("(");
+                self.unparse_type(inner);
+                self.write(")");
+            }
+            Type::TraitObject { bounds, .. } => {
+                self.write("dyn ");
+                self.unparse_trait_bounds(bounds);
+            }
+            Type::Reference { mutable, inner, .. } => {
+                self.write(if *mutable { "&mut " } else { "&" });
+                self.unparse_type(inner);
+            }
+            Type::Array { element, size, .. } => {
+                self.write("[");
+                self.unparse_type(element);
+
+                if let Some(size) = size {
+                    self.write("; ");
+                    self.unparse_expression(size);
+                }
+
+                self.write("]");
+            }
+            Type::WithQualifiedPath {
+                left,
+                right,
+                segments,
+                ..
+            } => {
+                self.write("[");
+                self.unparse_type(left);
+                self.write(" as ");
+                self.unparse_type_path(right);
+                self.write("].");
+                self.write_separated(segments, ".", Self::unparse_type_path_segment);
+            }
+        }
+    }
+
+    fn unparse_type_path(&mut self, path: &TypePath) {
+        self.write_separated(&path.segments, ".", Self::unparse_type_path_segment);
+    }
+
+    fn unparse_type_path_segment(&mut self, segment: &TypePathSegment) {
+        self.unparse_path(&segment.path);
+
+        if let Some(generic_arguments) = &segment.generic_arguments {
+            self.unparse_generic_arguments(generic_arguments);
+        }
+    }
+
+    fn unparse_generic_arguments(&mut self, arguments: &[GenericArgument]) {
+        self.write("[");
+        self.write_separated(arguments, ", ", |this, argument| match argument {
+            GenericArgument::Type(ty) => this.unparse_type(ty),
+            GenericArgument::AssociatedType { name, value } => {
+                this.unparse_identifier(*name);
+                this.write(" = ");
+                this.unparse_type(value);
+            }
+        });
+        self.write("]");
+    }
+
+    fn unparse_statements_block(&mut self, block: &StatementsBlock) {
+        match block.as_slice() {
+            [] => self.write("{}"),
+            [statement] if Self::is_simple_statement(statement) => {
+                self.write("{ ");
+                self.unparse_statement(statement, true);
+                self.write(" }");
+            }
+            _ => {
+                self.write("{");
+                self.write_newline();
+                self.indentation += 1;
+
+                for (idx, statement) in block.iter().enumerate() {
+                    self.write_indentation();
+                    self.unparse_statement(statement, idx == block.len() - 1);
+                    self.write_newline();
+                }
+
+                self.indentation -= 1;
+                self.write_indentation();
+                self.write("}");
+            }
+        }
+    }
+
+    /// Returns `true` if the statement can be printed on the same line as
+    /// braces of the block it is located in.
+    fn is_simple_statement(statement: &Statement) -> bool {
+        match statement {
+            Statement::Break { .. }
+            | Statement::Continue { .. }
+            | Statement::Return {
+                expression: None, ..
+            } => true,
+            Statement::Defer { call: expression }
+            | Statement::Expression { expression, .. }
+            | Statement::Return {
+                expression: Some(expression),
+                ..
+            }
+            | Statement::Let {
+                value: expression, ..
+            } => Self::is_simple_expression(expression),
+        }
+    }
+
+    /// Returns `true` if the expression doesn't contain statement blocks.
+    fn is_simple_expression(expression: &Expression) -> bool {
+        match expression {
+            Expression::Identifier(..) | Expression::Literal(..) => true,
+            Expression::As { left, .. }
+            | Expression::FieldAccess { left, .. }
+            | Expression::TupleFieldAccess { left, .. }
+            | Expression::GenericArguments { left, .. } => Self::is_simple_expression(left),
+            Expression::Index { left, index, .. } => {
+                Self::is_simple_expression(left) && Self::is_simple_expression(index)
+            }
+            Expression::Parenthesized { inner, .. }
+            | Expression::Prefix { inner, .. }
+            | Expression::Postfix { inner, .. } => Self::is_simple_expression(inner),
+            Expression::Binary { left, right, .. } => {
+                Self::is_simple_expression(left) && Self::is_simple_expression(right)
+            }
+            Expression::Call {
+                left, arguments, ..
+            } => {
+                Self::is_simple_expression(left) && arguments.iter().all(Self::is_simple_expression)
+            }
+            Expression::List { elements, .. } | Expression::Tuple { elements, .. } => {
+                elements.iter().all(Self::is_simple_expression)
+            }
+            Expression::Range { start, end, .. } => [start, end]
+                .into_iter()
+                .flatten()
+                .all(|bound| Self::is_simple_expression(bound)),
+            Expression::Struct { left, fields, .. } => {
+                Self::is_simple_expression(left)
+                    && fields
+                        .iter()
+                        .filter_map(|field| field.value.as_ref())
+                        .all(Self::is_simple_expression)
+            }
+            Expression::If { .. }
+            | Expression::Lambda { .. }
+            | Expression::Match { .. }
+            | Expression::StatementsBlock { .. }
+            | Expression::While { .. }
+            | Expression::For { .. } => false,
+        }
+    }
+
+    fn unparse_statement(&mut self, statement: &Statement, last: bool) {
+        match statement {
+            Statement::Break { label, .. } => {
+                self.write("break");
+                self.unparse_label(*label);
+                self.write(";");
+            }
+            Statement::Continue { label, .. } => {
+                self.write("continue");
+                self.unparse_label(*label);
+                self.write(";");
+            }
+            Statement::Defer { call } => {
+                self.write("defer ");
+                self.unparse_expression(call);
+                self.write(";");
+            }
+            Statement::Expression {
+                expression,
+                has_semicolon,
+            } => {
+                self.unparse_expression(expression);
+
+                // Expressions with blocks must be separated from the next
+                // statement with `;`, which is then skipped by the parser.
+                if *has_semicolon || (!last && expression.with_block()) {
+                    self.write(";");
+                }
+            }
+            Statement::Let { pattern, value, ty } => {
+                self.write("let ");
+                self.unparse_pattern(pattern);
+
+                if let Some(ty) = ty {
+                    self.write(": ");
+                    self.unparse_type(ty);
+                }
+
+                self.write(" = ");
+                self.unparse_expression(value);
+                self.write(";");
+            }
+            Statement::Return { expression, .. } => {
+                self.write("return");
+
+                if let Some(expression) = expression {
+                    self.write(" ");
+                    self.unparse_expression(expression);
+                }
+
+                self.write(";");
+            }
+        }
+    }
+
+    /// Writes an operand of an operator, wrapping it in parentheses if needed.
+    fn unparse_operand(&mut self, operand: &Expression, parenthesize: bool) {
+        if parenthesize {
+            self.write("(");
+            self.unparse_expression(operand);
+            self.write(")");
+        } else {
+            self.unparse_expression(operand);
+        }
+    }
+
+    /// Writes a left operand of a postfix-like operator (call, field access,
+    /// cast, etc.) with a given precedence.
+    fn unparse_left_operand(&mut self, left: &Expression, precedence: Precedence) {
+        self.unparse_operand(
+            left,
+            precedence_of_expression(left).is_some_and(|left| left < precedence),
+        );
+    }
+
+    /// Writes a condition of `if`, `while`, an iterable of `for` or a
+    /// scrutinee of `match`, in which struct expressions must be
+    /// parenthesized.
+    fn unparse_condition(&mut self, condition: &Expression) {
+        self.unparse_operand(condition, matches!(condition, Expression::Struct { .. }));
+    }
+
+    fn unparse_expression(&mut self, expression: &Expression) {
+        match expression {
+            Expression::As { left, right, .. } => {
+                self.unparse_left_operand(left, Precedence::As);
+                self.write(" as ");
+                self.unparse_type(right);
+            }
+            Expression::Binary {
+                left,
+                operator,
+                right,
+                ..
+            } => {
+                let precedence = RawToken::from(operator.raw).to_precedence();
+                let right_associative = precedence.associativity() == Associativity::Right;
+
+                // Operand of the same precedence has to be parenthesized on
+                // the side the operator doesn't associate to.
+                self.unparse_operand(
+                    left,
+                    precedence_of_expression(left).is_some_and(|left| {
+                        left < precedence || (right_associative && left == precedence)
+                    }),
+                );
+                self.write(" ");
+                self.write(String::from(operator.raw).trim_matches('`'));
+                self.write(" ");
+                self.unparse_operand(
+                    right,
+                    precedence_of_expression(right).is_some_and(|right| {
+                        right < precedence || (!right_associative && right == precedence)
+                    }),
+                );
+            }
+            Expression::Range {
+                start,
+                end,
+                inclusive,
+                ..
+            } => {
+                if let Some(start) = start {
+                    self.unparse_left_operand(start, Precedence::Range);
+                }
+
+                self.write(if *inclusive { "..=" } else { ".." });
+
+                if let Some(end) = end {
+                    self.unparse_operand(
+                        end,
+                        precedence_of_expression(end).is_some_and(|end| end <= Precedence::Range),
+                    );
+                }
+            }
+            Expression::Call {
+                left, arguments, ..
+            } => {
+                self.unparse_left_operand(left, Precedence::Call);
+                self.write("(");
+                self.write_separated(arguments, ", ", Self::unparse_expression);
+                self.write(")");
+            }
+            Expression::FieldAccess { left, right, .. } => {
+                self.unparse_left_operand(left, Precedence::Property);
+                self.write(".");
+                self.unparse_identifier(*right);
+            }
+            Expression::TupleFieldAccess { left, index, .. } => {
+                self.unparse_left_operand(left, Precedence::Property);
+                self.write(".");
+                self.write(&index.to_string());
+            }
+            Expression::GenericArguments {
+                left,
+                generic_arguments,
+                ..
+            } => {
+                self.unparse_left_operand(left, Precedence::GenericArgument);
+                self.unparse_generic_arguments(generic_arguments);
+            }
+            Expression::Index { left, index, .. } => {
+                self.unparse_left_operand(left, Precedence::GenericArgument);
+                self.write("[");
+                self.unparse_expression(index);
+                self.write("]");
+            }
+            Expression::Identifier(identifier) => self.unparse_identifier(*identifier),
+            Expression::If {
+                if_blocks, r#else, ..
+            } => {
+                for (idx, (condition, block)) in if_blocks.iter().enumerate() {
+                    if idx != 0 {
+                        self.write(" else ");
+                    }
+
+                    self.write("if ");
+                    self.unparse_condition(condition);
+                    self.write(" ");
+                    self.unparse_statements_block(block);
+                }
+
+                if let Some(r#else) = r#else {
+                    self.write(" else ");
+                    self.unparse_statements_block(r#else);
+                }
+            }
+            Expression::Lambda {
+                parameters,
+                return_type,
+                block,
+                ..
+            } => {
+                self.write("|");
+                self.write_separated(parameters, ", ", |this, parameter| {
+                    this.unparse_identifier(parameter.name);
+
+                    if let Some(ty) = &parameter.ty {
+                        this.write(": ");
+                        this.unparse_type(ty);
+                    }
+                });
+                self.write("|");
+
+                if let Some(return_type) = return_type {
+                    self.write(": ");
+                    self.unparse_type(return_type);
+                }
+
+                self.write(" ");
+                self.unparse_statements_block(block);
+            }
+            Expression::List { elements, .. } => {
+                self.write("[");
+                self.write_separated(elements, ", ", Self::unparse_expression);
+                self.write("]");
+            }
+            Expression::Literal(literal) => self.unparse_literal(literal),
+            Expression::Match {
+                expression, block, ..
+            } => {
+                self.write("match ");
+                self.unparse_condition(expression);
+                self.unparse_match_block(block);
+            }
+            Expression::Parenthesized { inner, .. } => {
+                self.write("(");
+                self.unparse_expression(inner);
+                self.write(")");
+            }
+            Expression::Postfix {
+                inner, operator, ..
+            } => {
+                self.unparse_left_operand(inner, Precedence::Unary);
+                self.write(String::from(operator.raw).trim_matches('`'));
+            }
+            Expression::Prefix {
+                inner, operator, ..
+            } => {
+                self.write(String::from(operator.raw).trim_matches('`'));
+                self.unparse_operand(
+                    inner,
+                    matches!(**inner, Expression::Postfix { .. })
+                        || precedence_of_expression(inner)
+                            .is_some_and(|inner| inner <= Precedence::Unary),
+                );
+            }
+            Expression::StatementsBlock { block, .. } => self.unparse_statements_block(block),
+            Expression::Struct { left, fields, .. } => {
+                self.unparse_left_operand(left, Precedence::Struct);
+                self.unparse_struct_expression_fields(fields);
+            }
+            Expression::Tuple { elements, .. } => {
+                self.write("(");
+                self.write_separated(elements, ", ", Self::unparse_expression);
+
+                if elements.len() == 1 {
+                    self.write(",");
+                }
+
+                self.write(")");
+            }
+            Expression::While {
+                label,
+                condition,
+                body,
+                ..
+            } => {
+                if let Some(label) = label {
+                    self.write("'");
+                    self.unparse_identifier(*label);
+                    self.write(": ");
+                }
+
+                self.write("while ");
+                self.unparse_condition(condition);
+                self.write(" ");
+                self.unparse_statements_block(body);
+            }
+            Expression::For {
+                pattern,
+                iterable,
+                body,
+                ..
+            } => {
+                self.write("for ");
+                self.unparse_pattern(pattern);
+                self.write(" in ");
+                self.unparse_condition(iterable);
+                self.write(" ");
+                self.unparse_statements_block(body);
+            }
+        }
+    }
+
+    /// Writes ` 'label` after `break` or `continue`.
+    fn unparse_label(&mut self, label: Option<IdentifierAst>) {
+        if let Some(label) = label {
+            self.write(" '");
+            self.unparse_identifier(label);
+        }
+    }
+
+    fn unparse_match_block(&mut self, block: &[MatchExpressionItem]) {
+        if block.is_empty() {
+            self.write(" {}");
+            return;
+        }
+
+        self.write(" {");
+        self.write_newline();
+        self.indentation += 1;
+
+        for item in block {
+            self.write_indentation();
+            self.unparse_pattern(&item.left);
+            self.write(" => ");
+            self.unparse_expression(&item.right);
+            self.write(",");
+            self.write_newline();
+        }
+
+        self.indentation -= 1;
+        self.write_indentation();
+        self.write("}");
+    }
+
+    fn unparse_struct_expression_fields(&mut self, fields: &[StructExpressionItem]) {
+        if fields.is_empty() {
+            self.write(" {}");
+            return;
+        }
+
+        self.write(" { ");
+        self.write_separated(fields, ", ", |this, field| {
+            this.unparse_identifier(field.name);
+
+            if let Some(value) = &field.value {
+                this.write(": ");
+                this.unparse_expression(value);
+            }
+        });
+        self.write(" }");
+    }
+
+    fn unparse_literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Boolean { value, .. } => self.write(if *value { "true" } else { "false" }),
+            Literal::Character { value, .. } => {
+                self.output.push('\'');
+                escape_char(*value, '\'', &mut self.output);
+                self.output.push('\'');
+            }
+            Literal::Float { value, .. } => self.write(&format!("{value:?}")),
+            Literal::Integer { value, .. } => self.write(&value.to_string()),
+            Literal::String { value, .. } => {
+                self.output.push('"');
+                for c in value.chars() {
+                    escape_char(c, '"', &mut self.output);
+                }
+                self.output.push('"');
+            }
+        }
+    }
+
+    fn unparse_pattern(&mut self, pattern: &Pattern) {
+        match pattern {
+            Pattern::Grouped { inner, .. } => {
+                self.write("(");
+                self.unparse_pattern(inner);
+                self.write(")");
+            }
+            Pattern::Identifier {
+                identifier,
+                pattern,
+                ..
+            } => {
+                self.unparse_identifier(*identifier);
+
+                if let Some(pattern) = pattern {
+                    self.write(" @ ");
+                    self.unparse_pattern(pattern);
+                }
+            }
+            Pattern::List { inner_patterns, .. } => {
+                self.write("[");
+                self.write_separated(inner_patterns, ", ", Self::unparse_pattern);
+                self.write("]");
+            }
+            Pattern::Literal(literal) => self.unparse_literal(literal),
+            Pattern::NegativeLiteral { literal, .. } => {
+                self.write("-");
+                self.unparse_literal(literal);
+            }
+            Pattern::Or { left, right, .. } => {
+                self.unparse_pattern(left);
+                self.write(" | ");
+                self.unparse_pattern(right);
+            }
+            Pattern::Path { path, .. } => self.unparse_path(path),
+            Pattern::Rest { .. } => self.write(".."),
+            Pattern::Wildcard { .. } => self.write("_"),
+            Pattern::Struct { path, fields, .. } => {
+                self.unparse_path(path);
+
+                if fields.is_empty() {
+                    self.write(" {}");
+                    return;
+                }
+
+                self.write(" { ");
+                self.write_separated(fields, ", ", |this, field| match field {
+                    StructFieldPattern::NotRest {
+                        field_name,
+                        value_pattern,
+                        ..
+                    } => {
+                        this.unparse_identifier(*field_name);
+
+                        if let Some(value_pattern) = value_pattern {
+                            this.write(": ");
+                            this.unparse_pattern(value_pattern);
+                        }
+                    }
+                    StructFieldPattern::Rest { .. } => this.write(".."),
+                });
+                self.write(" }");
+            }
+            Pattern::Tuple { elements, .. } => {
+                self.write("(");
+                self.write_separated(elements, ", ", Self::unparse_pattern);
+
+                if elements.len() == 1 {
+                    self.write(",");
+                }
+
+                self.write(")");
+            }
+            Pattern::TupleLike {
+                path,
+                inner_patterns,
+                ..
+            } => {
+                self.unparse_path(path);
+                self.write("(");
+                self.write_separated(inner_patterns, ", ", Self::unparse_pattern);
+                self.write(")");
+            }
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    BinaryOperator, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
+    Attribute, BinaryOperator, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
     GenericParameter, IdentifierAst, Impl, ImportPath, Item, JustFunctionParameter,
     LambdaFunctionParameter, Literal, MatchExpressionItem, Module, Path, Pattern, PostfixOperator,
     PrefixOperator, SelfParameter, Statement, StatementsBlock, StructExpressionItem, StructField,
@@ -28,6 +28,14 @@ pub trait Visitor<'ast>: Sized {
 
     fn visit_local_docstring(&mut self, _docstring: Option<&'ast str>) {}
 
+    fn visit_attributes(&mut self, attributes: &'ast [Attribute]) {
+        walk_attributes(self, attributes);
+    }
+
+    fn visit_attribute(&mut self, attribute: &'ast Attribute) {
+        walk_attribute(self, attribute);
+    }
+
     fn visit_type_implementation(&mut self, implementation: &'ast Impl) {
         walk_type_implementation(self, implementation);
     }
@@ -225,6 +233,21 @@ where
     }
 }
 
+pub fn walk_attributes<'ast, V>(visitor: &mut V, attributes: &'ast [Attribute])
+where
+    V: Visitor<'ast>,
+{
+    walk_list!(visitor, visit_attribute, attributes);
+}
+
+pub fn walk_attribute<'ast, V>(visitor: &mut V, attribute: &'ast Attribute)
+where
+    V: Visitor<'ast>,
+{
+    visitor.visit_path(&attribute.path);
+    walk_list!(visitor, visit_expression, &attribute.arguments);
+}
+
 pub fn walk_item<'ast, V>(visitor: &mut V, item: &'ast Item)
 where
     V: Visitor<'ast>,
@@ -236,9 +259,11 @@ where
             generic_parameters,
             where_clause,
             items,
+            attributes,
             docstring,
         } => {
             visitor.visit_local_docstring(docstring.as_deref());
+            visitor.visit_attributes(attributes);
             visitor.visit_visibility(*visibility);
             visitor.visit_identifier(*name);
             visitor.visit_generic_parameters(generic_parameters.as_deref());
@@ -248,7 +273,8 @@ where
         Item::Function(function) => {
             visitor.visit_function(function);
         }
-        Item::Import { path } => {
+        Item::Import { attributes, path } => {
+            visitor.visit_attributes(attributes);
             visitor.visit_import_path(path);
         }
         Item::Trait {
@@ -257,9 +283,11 @@ where
             generic_parameters,
             where_clause,
             items,
+            attributes,
             docstring,
         } => {
             visitor.visit_local_docstring(docstring.as_deref());
+            visitor.visit_attributes(attributes);
             visitor.visit_visibility(*visibility);
             visitor.visit_identifier(*name);
             visitor.visit_generic_parameters(generic_parameters.as_deref());
@@ -272,9 +300,11 @@ where
             generic_parameters,
             where_clause,
             fields,
+            attributes,
             docstring,
         } => {
             visitor.visit_local_docstring(docstring.as_deref());
+            visitor.visit_attributes(attributes);
             visitor.visit_visibility(*visibility);
             visitor.visit_identifier(*name);
             visitor.visit_generic_parameters(generic_parameters.as_deref());
@@ -288,8 +318,10 @@ where
             generic_parameters,
             where_clause,
             fields,
+            attributes,
             docstring,
         } => {
+            visitor.visit_attributes(attributes);
             visitor.visit_visibility(*visibility);
             visitor.visit_identifier(*name);
             visitor.visit_generic_parameters(generic_parameters.as_deref());
@@ -298,6 +330,29 @@ where
             visitor.visit_local_docstring(docstring.as_deref());
         }
         Item::TypeAlias(alias) => visitor.visit_type_alias(alias),
+        Item::Const {
+            visibility,
+            name,
+            ty,
+            value,
+            attributes,
+            docstring,
+        }
+        | Item::Static {
+            visibility,
+            name,
+            ty,
+            value,
+            attributes,
+            docstring,
+        } => {
+            visitor.visit_local_docstring(docstring.as_deref());
+            visitor.visit_attributes(attributes);
+            visitor.visit_visibility(*visibility);
+            visitor.visit_identifier(*name);
+            visitor.visit_type(ty);
+            visitor.visit_expression(value);
+        }
     }
 }
 
@@ -306,6 +361,7 @@ where
     V: Visitor<'ast>,
 {
     visitor.visit_local_docstring(implementation.docstring.as_deref());
+    visitor.visit_attributes(&implementation.attributes);
 
     if let Some(r#trait) = &implementation.r#trait {
         visitor.visit_type(r#trait);
@@ -321,6 +377,7 @@ pub fn walk_function<'ast, V>(visitor: &mut V, function: &'ast Function)
 where
     V: Visitor<'ast>,
 {
+    visitor.visit_attributes(&function.attributes);
     visitor.visit_visibility(function.visibility);
     visitor.visit_identifier(function.name);
     visitor.visit_generic_parameters(function.generic_parameters.as_deref());
@@ -373,9 +430,17 @@ where
     V: Visitor<'ast>,
 {
     match item {
-        EnumItem::Just { name, docstring } => {
+        EnumItem::Just {
+            name,
+            discriminant,
+            docstring,
+        } => {
             visitor.visit_local_docstring(docstring.as_deref());
             visitor.visit_identifier(*name);
+
+            if let Some(discriminant) = discriminant {
+                visitor.visit_expression(discriminant);
+            }
         }
         EnumItem::Struct {
             name,
@@ -439,6 +504,7 @@ pub fn walk_type_alias<'ast, V>(visitor: &mut V, alias: &'ast TypeAlias)
 where
     V: Visitor<'ast>,
 {
+    visitor.visit_attributes(&alias.attributes);
     visitor.visit_visibility(alias.visibility);
     visitor.visit_identifier(alias.name);
     visitor.visit_generic_parameters(alias.generic_parameters.as_deref());
@@ -450,6 +516,8 @@ where
     if let Some(value) = &alias.value {
         visitor.visit_type(value);
     }
+
+    visitor.visit_where_clause(alias.where_clause.as_deref());
 }
 
 pub fn walk_tuple_fields<'ast, V>(visitor: &mut V, fields: &'ast [TupleField])
@@ -513,12 +581,19 @@ where
 
             visitor.visit_type(return_type);
         }
-        Type::Parenthesized { inner, .. } => {
+        Type::Parenthesized { inner, .. } | Type::Reference { inner, .. } => {
             visitor.visit_type(inner);
         }
         Type::TraitObject { bounds, .. } => {
             visitor.visit_trait_bounds(bounds);
         }
+        Type::Array { element, size, .. } => {
+            visitor.visit_type(element);
+
+            if let Some(size) = size {
+                visitor.visit_expression(size);
+            }
+        }
         Type::WithQualifiedPath {
             left,
             right,
@@ -614,9 +689,14 @@ where
         Statement::Defer { call } => {
             visitor.visit_expression(call);
         }
-        Statement::Expression { expression, .. } | Statement::Return { expression } => {
+        Statement::Expression { expression, .. } => {
             visitor.visit_expression(expression);
         }
+        Statement::Return { expression, .. } => {
+            if let Some(expression) = expression {
+                visitor.visit_expression(expression);
+            }
+        }
         Statement::Let { pattern, value, ty } => {
             visitor.visit_pattern(pattern);
 
@@ -659,6 +739,11 @@ where
             visitor.visit_expression(left);
             visitor.visit_identifier(*right);
         }
+        Expression::TupleFieldAccess { left, .. } => visitor.visit_expression(left),
+        Expression::Index { left, index, .. } => {
+            visitor.visit_expression(left);
+            visitor.visit_expression(index);
+        }
         Expression::Lambda {
             parameters,
             return_type,
@@ -726,12 +811,31 @@ where
             visitor.visit_expression(left);
             visitor.visit_struct_expression_items(fields);
         }
+        Expression::Range { start, end, .. } => {
+            if let Some(start) = start {
+                visitor.visit_expression(start);
+            }
+
+            if let Some(end) = end {
+                visitor.visit_expression(end);
+            }
+        }
         Expression::While {
             condition, body, ..
         } => {
             visitor.visit_expression(condition);
             visitor.visit_statements_block(body);
         }
+        Expression::For {
+            pattern,
+            iterable,
+            body,
+            ..
+        } => {
+            visitor.visit_pattern(pattern);
+            visitor.visit_expression(iterable);
+            visitor.visit_statements_block(body);
+        }
     }
 }
 
@@ -791,6 +895,10 @@ pub fn walk_just_function_parameter<'ast, V>(
 {
     visitor.visit_identifier(parameter.name);
     visitor.visit_type(&parameter.ty);
+
+    if let Some(default_value) = &parameter.default_value {
+        visitor.visit_expression(default_value);
+    }
 }
 
 pub fn walk_match_expression_item<'ast, V>(visitor: &mut V, item: &'ast MatchExpressionItem)
@@ -848,7 +956,9 @@ where
         Pattern::List { inner_patterns, .. } => {
             walk_list!(visitor, visit_pattern, inner_patterns);
         }
-        Pattern::Literal(literal) => visitor.visit_literal(literal),
+        Pattern::Literal(literal) | Pattern::NegativeLiteral { literal, .. } => {
+            visitor.visit_literal(literal);
+        }
         Pattern::Or { left, right, .. } => {
             visitor.visit_pattern(left);
             visitor.visit_pattern(right);
@@ -856,7 +966,7 @@ where
         Pattern::Path { path, .. } => {
             visitor.visit_path(path);
         }
-        Pattern::Rest { .. } => {}
+        Pattern::Rest { .. } | Pattern::Wildcard { .. } => {}
 
         Pattern::Struct { path, fields, .. } => {
             visitor.visit_path(path);
@@ -0,0 +1,749 @@
+//! Defines [`VisitorMut`] - a visitor, that is allowed to modify an AST
+//! in place (used for desugaring, renaming, etc.).
+
+use crate::{
+    Attribute, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
+    GenericParameter, IdentifierAst, Impl, ImportPath, Item, LambdaFunctionParameter, Literal,
+    MatchExpressionItem, Module, Path, Pattern, Statement, StatementsBlock, StructExpressionItem,
+    StructField, StructFieldPattern, TraitItem, TupleField, Type, TypeAlias, TypePath,
+    TypePathSegment, WhereClauseItem,
+};
+
+pub trait VisitorMut: Sized {
+    fn visit_identifier_mut(&mut self, _identifier: &mut IdentifierAst) {}
+
+    fn visit_path_mut(&mut self, path: &mut Path) {
+        walk_path_mut(self, path);
+    }
+
+    fn visit_import_path_mut(&mut self, path: &mut ImportPath) {
+        walk_import_path_mut(self, path);
+    }
+
+    fn visit_module_mut(&mut self, module: &mut Module) {
+        walk_module_mut(self, module);
+    }
+
+    fn visit_item_mut(&mut self, item: &mut Item) {
+        walk_item_mut(self, item);
+    }
+
+    fn visit_attribute_mut(&mut self, attribute: &mut Attribute) {
+        walk_attribute_mut(self, attribute);
+    }
+
+    fn visit_type_implementation_mut(&mut self, implementation: &mut Impl) {
+        walk_type_implementation_mut(self, implementation);
+    }
+
+    fn visit_function_mut(&mut self, function: &mut Function) {
+        walk_function_mut(self, function);
+    }
+
+    fn visit_function_parameter_mut(&mut self, parameter: &mut FunctionParameter) {
+        walk_function_parameter_mut(self, parameter);
+    }
+
+    fn visit_where_clause_item_mut(&mut self, item: &mut WhereClauseItem) {
+        walk_where_clause_item_mut(self, item);
+    }
+
+    fn visit_enum_item_mut(&mut self, item: &mut EnumItem) {
+        walk_enum_item_mut(self, item);
+    }
+
+    fn visit_struct_field_mut(&mut self, field: &mut StructField) {
+        walk_struct_field_mut(self, field);
+    }
+
+    fn visit_tuple_field_mut(&mut self, field: &mut TupleField) {
+        walk_tuple_field_mut(self, field);
+    }
+
+    fn visit_generic_parameter_mut(&mut self, parameter: &mut GenericParameter) {
+        walk_generic_parameter_mut(self, parameter);
+    }
+
+    fn visit_trait_item_mut(&mut self, item: &mut TraitItem) {
+        walk_trait_item_mut(self, item);
+    }
+
+    fn visit_type_alias_mut(&mut self, alias: &mut TypeAlias) {
+        walk_type_alias_mut(self, alias);
+    }
+
+    fn visit_type_mut(&mut self, ty: &mut Type) {
+        walk_type_mut(self, ty);
+    }
+
+    fn visit_type_path_mut(&mut self, path: &mut TypePath) {
+        walk_type_path_mut(self, path);
+    }
+
+    fn visit_type_path_segment_mut(&mut self, segment: &mut TypePathSegment) {
+        walk_type_path_segment_mut(self, segment);
+    }
+
+    fn visit_generic_argument_mut(&mut self, argument: &mut GenericArgument) {
+        walk_generic_argument_mut(self, argument);
+    }
+
+    fn visit_statements_block_mut(&mut self, block: &mut StatementsBlock) {
+        walk_statements_block_mut(self, block);
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut Statement) {
+        walk_statement_mut(self, statement);
+    }
+
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+    }
+
+    fn visit_lambda_function_parameter_mut(&mut self, parameter: &mut LambdaFunctionParameter) {
+        walk_lambda_function_parameter_mut(self, parameter);
+    }
+
+    fn visit_match_expression_item_mut(&mut self, item: &mut MatchExpressionItem) {
+        walk_match_expression_item_mut(self, item);
+    }
+
+    fn visit_struct_expression_item_mut(&mut self, item: &mut StructExpressionItem) {
+        walk_struct_expression_item_mut(self, item);
+    }
+
+    fn visit_pattern_mut(&mut self, pattern: &mut Pattern) {
+        walk_pattern_mut(self, pattern);
+    }
+
+    fn visit_struct_field_pattern_mut(&mut self, pattern: &mut StructFieldPattern) {
+        walk_struct_field_pattern_mut(self, pattern);
+    }
+
+    fn visit_literal_mut(&mut self, _literal: &mut Literal) {}
+}
+
+macro_rules! walk_list_mut {
+    ($visitor:expr, $method:ident, $list:expr) => {
+        for elem in $list {
+            $visitor.$method(elem)
+        }
+    };
+}
+
+pub fn walk_path_mut<V>(visitor: &mut V, path: &mut Path)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_identifier_mut, &mut path.identifiers);
+}
+
+pub fn walk_import_path_mut<V>(visitor: &mut V, path: &mut ImportPath)
+where
+    V: VisitorMut,
+{
+    visitor.visit_path_mut(&mut path.left);
+
+    if let Some(r#as) = &mut path.r#as {
+        visitor.visit_identifier_mut(r#as);
+    }
+}
+
+pub fn walk_module_mut<V>(visitor: &mut V, module: &mut Module)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_item_mut, &mut module.items);
+}
+
+fn walk_generic_parameters_mut<V>(visitor: &mut V, parameters: &mut Option<Vec<GenericParameter>>)
+where
+    V: VisitorMut,
+{
+    if let Some(parameters) = parameters {
+        walk_list_mut!(visitor, visit_generic_parameter_mut, parameters);
+    }
+}
+
+fn walk_where_clause_mut<V>(visitor: &mut V, items: &mut Option<Vec<WhereClauseItem>>)
+where
+    V: VisitorMut,
+{
+    if let Some(items) = items {
+        walk_list_mut!(visitor, visit_where_clause_item_mut, items);
+    }
+}
+
+pub fn walk_attribute_mut<V>(visitor: &mut V, attribute: &mut Attribute)
+where
+    V: VisitorMut,
+{
+    visitor.visit_path_mut(&mut attribute.path);
+    walk_list_mut!(visitor, visit_expression_mut, &mut attribute.arguments);
+}
+
+pub fn walk_item_mut<V>(visitor: &mut V, item: &mut Item)
+where
+    V: VisitorMut,
+{
+    match item {
+        Item::Enum {
+            name,
+            generic_parameters,
+            where_clause,
+            items,
+            attributes,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_identifier_mut(name);
+            walk_generic_parameters_mut(visitor, generic_parameters);
+            walk_where_clause_mut(visitor, where_clause);
+            walk_list_mut!(visitor, visit_enum_item_mut, items);
+        }
+        Item::Function(function) => visitor.visit_function_mut(function),
+        Item::Import { attributes, path } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_import_path_mut(path);
+        }
+        Item::Trait {
+            name,
+            generic_parameters,
+            where_clause,
+            items,
+            attributes,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_identifier_mut(name);
+            walk_generic_parameters_mut(visitor, generic_parameters);
+            walk_where_clause_mut(visitor, where_clause);
+            walk_list_mut!(visitor, visit_trait_item_mut, items);
+        }
+        Item::TupleLikeStruct {
+            name,
+            generic_parameters,
+            where_clause,
+            fields,
+            attributes,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_identifier_mut(name);
+            walk_generic_parameters_mut(visitor, generic_parameters);
+            walk_where_clause_mut(visitor, where_clause);
+            walk_list_mut!(visitor, visit_tuple_field_mut, fields);
+        }
+        Item::Impl(implementation) => visitor.visit_type_implementation_mut(implementation),
+        Item::Struct {
+            name,
+            generic_parameters,
+            where_clause,
+            fields,
+            attributes,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_identifier_mut(name);
+            walk_generic_parameters_mut(visitor, generic_parameters);
+            walk_where_clause_mut(visitor, where_clause);
+            walk_list_mut!(visitor, visit_struct_field_mut, fields);
+        }
+        Item::TypeAlias(alias) => visitor.visit_type_alias_mut(alias),
+        Item::Const {
+            name,
+            ty,
+            value,
+            attributes,
+            ..
+        }
+        | Item::Static {
+            name,
+            ty,
+            value,
+            attributes,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_attribute_mut, attributes);
+            visitor.visit_identifier_mut(name);
+            visitor.visit_type_mut(ty);
+            visitor.visit_expression_mut(value);
+        }
+    }
+}
+
+pub fn walk_type_implementation_mut<V>(visitor: &mut V, implementation: &mut Impl)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_attribute_mut, &mut implementation.attributes);
+    walk_generic_parameters_mut(visitor, &mut implementation.generic_parameters);
+
+    if let Some(r#trait) = &mut implementation.r#trait {
+        visitor.visit_type_mut(r#trait);
+    }
+
+    visitor.visit_type_mut(&mut implementation.ty);
+    walk_where_clause_mut(visitor, &mut implementation.where_clause);
+    walk_list_mut!(visitor, visit_trait_item_mut, &mut implementation.items);
+}
+
+pub fn walk_function_mut<V>(visitor: &mut V, function: &mut Function)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_attribute_mut, &mut function.attributes);
+    visitor.visit_identifier_mut(&mut function.name);
+    walk_generic_parameters_mut(visitor, &mut function.generic_parameters);
+    walk_list_mut!(
+        visitor,
+        visit_function_parameter_mut,
+        &mut function.parameters
+    );
+
+    if let Some(return_type) = &mut function.return_type {
+        visitor.visit_type_mut(return_type);
+    }
+
+    walk_where_clause_mut(visitor, &mut function.where_clause);
+
+    if let Some(body) = &mut function.body {
+        visitor.visit_statements_block_mut(body);
+    }
+}
+
+pub fn walk_function_parameter_mut<V>(visitor: &mut V, parameter: &mut FunctionParameter)
+where
+    V: VisitorMut,
+{
+    match parameter {
+        FunctionParameter::Just(parameter) => {
+            visitor.visit_identifier_mut(&mut parameter.name);
+            visitor.visit_type_mut(&mut parameter.ty);
+
+            if let Some(default_value) = &mut parameter.default_value {
+                visitor.visit_expression_mut(default_value);
+            }
+        }
+        FunctionParameter::Self_(parameter) => {
+            if let Some(ty) = &mut parameter.ty {
+                visitor.visit_type_mut(ty);
+            }
+        }
+    }
+}
+
+pub fn walk_where_clause_item_mut<V>(visitor: &mut V, item: &mut WhereClauseItem)
+where
+    V: VisitorMut,
+{
+    match item {
+        WhereClauseItem::Eq { left, right } => {
+            visitor.visit_type_mut(left);
+            visitor.visit_type_mut(right);
+        }
+        WhereClauseItem::Satisfies { ty, bounds } => {
+            visitor.visit_type_mut(ty);
+            walk_list_mut!(visitor, visit_type_path_mut, bounds);
+        }
+    }
+}
+
+pub fn walk_enum_item_mut<V>(visitor: &mut V, item: &mut EnumItem)
+where
+    V: VisitorMut,
+{
+    match item {
+        EnumItem::Just {
+            name, discriminant, ..
+        } => {
+            visitor.visit_identifier_mut(name);
+
+            if let Some(discriminant) = discriminant {
+                visitor.visit_expression_mut(discriminant);
+            }
+        }
+        EnumItem::Struct { name, fields, .. } => {
+            visitor.visit_identifier_mut(name);
+            walk_list_mut!(visitor, visit_struct_field_mut, fields);
+        }
+        EnumItem::Tuple { name, fields, .. } => {
+            visitor.visit_identifier_mut(name);
+            walk_list_mut!(visitor, visit_tuple_field_mut, fields);
+        }
+    }
+}
+
+pub fn walk_struct_field_mut<V>(visitor: &mut V, field: &mut StructField)
+where
+    V: VisitorMut,
+{
+    visitor.visit_identifier_mut(&mut field.name);
+    visitor.visit_type_mut(&mut field.ty);
+}
+
+pub fn walk_tuple_field_mut<V>(visitor: &mut V, field: &mut TupleField)
+where
+    V: VisitorMut,
+{
+    visitor.visit_type_mut(&mut field.ty);
+}
+
+pub fn walk_generic_parameter_mut<V>(visitor: &mut V, parameter: &mut GenericParameter)
+where
+    V: VisitorMut,
+{
+    visitor.visit_identifier_mut(&mut parameter.name);
+
+    if let Some(bounds) = &mut parameter.bounds {
+        walk_list_mut!(visitor, visit_type_path_mut, bounds);
+    }
+
+    if let Some(default_value) = &mut parameter.default_value {
+        visitor.visit_type_mut(default_value);
+    }
+}
+
+pub fn walk_trait_item_mut<V>(visitor: &mut V, item: &mut TraitItem)
+where
+    V: VisitorMut,
+{
+    match item {
+        TraitItem::TypeAlias(alias) => visitor.visit_type_alias_mut(alias),
+        TraitItem::AssociatedFunction(function) => visitor.visit_function_mut(function),
+    }
+}
+
+pub fn walk_type_alias_mut<V>(visitor: &mut V, alias: &mut TypeAlias)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_attribute_mut, &mut alias.attributes);
+    visitor.visit_identifier_mut(&mut alias.name);
+    walk_generic_parameters_mut(visitor, &mut alias.generic_parameters);
+
+    if let Some(bounds) = &mut alias.bounds {
+        walk_list_mut!(visitor, visit_type_path_mut, bounds);
+    }
+
+    if let Some(value) = &mut alias.value {
+        visitor.visit_type_mut(value);
+    }
+
+    walk_where_clause_mut(visitor, &mut alias.where_clause);
+}
+
+pub fn walk_type_mut<V>(visitor: &mut V, ty: &mut Type)
+where
+    V: VisitorMut,
+{
+    match ty {
+        Type::Path(path) => visitor.visit_type_path_mut(path),
+        Type::Tuple { element_types, .. } => {
+            walk_list_mut!(visitor, visit_type_mut, element_types);
+        }
+        Type::Function {
+            parameter_types,
+            return_type,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_type_mut, parameter_types);
+            visitor.visit_type_mut(return_type);
+        }
+        Type::Parenthesized { inner, .. } | Type::Reference { inner, .. } => {
+            visitor.visit_type_mut(inner);
+        }
+        Type::TraitObject { bounds, .. } => {
+            walk_list_mut!(visitor, visit_type_path_mut, bounds);
+        }
+        Type::Array { element, size, .. } => {
+            visitor.visit_type_mut(element);
+
+            if let Some(size) = size {
+                visitor.visit_expression_mut(size);
+            }
+        }
+        Type::WithQualifiedPath {
+            left,
+            right,
+            segments,
+            ..
+        } => {
+            visitor.visit_type_mut(left);
+            visitor.visit_type_path_mut(right);
+            walk_list_mut!(visitor, visit_type_path_segment_mut, segments);
+        }
+    }
+}
+
+pub fn walk_type_path_mut<V>(visitor: &mut V, path: &mut TypePath)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_type_path_segment_mut, &mut path.segments);
+}
+
+pub fn walk_type_path_segment_mut<V>(visitor: &mut V, segment: &mut TypePathSegment)
+where
+    V: VisitorMut,
+{
+    visitor.visit_path_mut(&mut segment.path);
+
+    if let Some(generic_arguments) = &mut segment.generic_arguments {
+        walk_list_mut!(visitor, visit_generic_argument_mut, generic_arguments);
+    }
+}
+
+pub fn walk_generic_argument_mut<V>(visitor: &mut V, argument: &mut GenericArgument)
+where
+    V: VisitorMut,
+{
+    match argument {
+        GenericArgument::Type(ty) => visitor.visit_type_mut(ty),
+        GenericArgument::AssociatedType { name, value } => {
+            visitor.visit_identifier_mut(name);
+            visitor.visit_type_mut(value);
+        }
+    }
+}
+
+pub fn walk_statements_block_mut<V>(visitor: &mut V, block: &mut StatementsBlock)
+where
+    V: VisitorMut,
+{
+    walk_list_mut!(visitor, visit_statement_mut, block);
+}
+
+pub fn walk_statement_mut<V>(visitor: &mut V, statement: &mut Statement)
+where
+    V: VisitorMut,
+{
+    match statement {
+        Statement::Defer { call } => visitor.visit_expression_mut(call),
+        Statement::Expression { expression, .. } => {
+            visitor.visit_expression_mut(expression);
+        }
+        Statement::Return { expression, .. } => {
+            if let Some(expression) = expression {
+                visitor.visit_expression_mut(expression);
+            }
+        }
+        Statement::Let { pattern, value, ty } => {
+            visitor.visit_pattern_mut(pattern);
+            visitor.visit_expression_mut(value);
+
+            if let Some(ty) = ty {
+                visitor.visit_type_mut(ty);
+            }
+        }
+        Statement::Break { .. } | Statement::Continue { .. } => {}
+    }
+}
+
+pub fn walk_expression_mut<V>(visitor: &mut V, expression: &mut Expression)
+where
+    V: VisitorMut,
+{
+    match expression {
+        Expression::As { left, right, .. } => {
+            visitor.visit_expression_mut(left);
+            visitor.visit_type_mut(right);
+        }
+        Expression::Binary { left, right, .. } => {
+            visitor.visit_expression_mut(left);
+            visitor.visit_expression_mut(right);
+        }
+        Expression::Call {
+            left, arguments, ..
+        } => {
+            visitor.visit_expression_mut(left);
+            walk_list_mut!(visitor, visit_expression_mut, arguments);
+        }
+        Expression::FieldAccess { left, right, .. } => {
+            visitor.visit_expression_mut(left);
+            visitor.visit_identifier_mut(right);
+        }
+        Expression::TupleFieldAccess { left, .. } => visitor.visit_expression_mut(left),
+        Expression::Index { left, index, .. } => {
+            visitor.visit_expression_mut(left);
+            visitor.visit_expression_mut(index);
+        }
+        Expression::Lambda {
+            parameters,
+            return_type,
+            block,
+            ..
+        } => {
+            walk_list_mut!(visitor, visit_lambda_function_parameter_mut, parameters);
+
+            if let Some(return_type) = return_type {
+                visitor.visit_type_mut(return_type);
+            }
+
+            visitor.visit_statements_block_mut(block);
+        }
+        Expression::GenericArguments {
+            left,
+            generic_arguments,
+            ..
+        } => {
+            visitor.visit_expression_mut(left);
+            walk_list_mut!(visitor, visit_generic_argument_mut, generic_arguments);
+        }
+        Expression::Identifier(identifier) => visitor.visit_identifier_mut(identifier),
+        Expression::If {
+            if_blocks, r#else, ..
+        } => {
+            for (condition, block) in if_blocks {
+                visitor.visit_expression_mut(condition);
+                visitor.visit_statements_block_mut(block);
+            }
+
+            if let Some(r#else) = r#else {
+                visitor.visit_statements_block_mut(r#else);
+            }
+        }
+        Expression::List { elements, .. } | Expression::Tuple { elements, .. } => {
+            walk_list_mut!(visitor, visit_expression_mut, elements);
+        }
+        Expression::Literal(literal) => visitor.visit_literal_mut(literal),
+        Expression::Match {
+            expression, block, ..
+        } => {
+            visitor.visit_expression_mut(expression);
+            walk_list_mut!(visitor, visit_match_expression_item_mut, block);
+        }
+        Expression::Parenthesized { inner, .. }
+        | Expression::Postfix { inner, .. }
+        | Expression::Prefix { inner, .. } => visitor.visit_expression_mut(inner),
+        Expression::StatementsBlock { block, .. } => visitor.visit_statements_block_mut(block),
+        Expression::Struct { left, fields, .. } => {
+            visitor.visit_expression_mut(left);
+            walk_list_mut!(visitor, visit_struct_expression_item_mut, fields);
+        }
+        Expression::Range { start, end, .. } => {
+            if let Some(start) = start {
+                visitor.visit_expression_mut(start);
+            }
+
+            if let Some(end) = end {
+                visitor.visit_expression_mut(end);
+            }
+        }
+        Expression::While {
+            condition, body, ..
+        } => {
+            visitor.visit_expression_mut(condition);
+            visitor.visit_statements_block_mut(body);
+        }
+        Expression::For {
+            pattern,
+            iterable,
+            body,
+            ..
+        } => {
+            visitor.visit_pattern_mut(pattern);
+            visitor.visit_expression_mut(iterable);
+            visitor.visit_statements_block_mut(body);
+        }
+    }
+}
+
+pub fn walk_lambda_function_parameter_mut<V>(
+    visitor: &mut V,
+    parameter: &mut LambdaFunctionParameter,
+) where
+    V: VisitorMut,
+{
+    visitor.visit_identifier_mut(&mut parameter.name);
+
+    if let Some(ty) = &mut parameter.ty {
+        visitor.visit_type_mut(ty);
+    }
+}
+
+pub fn walk_match_expression_item_mut<V>(visitor: &mut V, item: &mut MatchExpressionItem)
+where
+    V: VisitorMut,
+{
+    visitor.visit_pattern_mut(&mut item.left);
+    visitor.visit_expression_mut(&mut item.right);
+}
+
+pub fn walk_struct_expression_item_mut<V>(visitor: &mut V, item: &mut StructExpressionItem)
+where
+    V: VisitorMut,
+{
+    visitor.visit_identifier_mut(&mut item.name);
+
+    if let Some(value) = &mut item.value {
+        visitor.visit_expression_mut(value);
+    }
+}
+
+pub fn walk_pattern_mut<V>(visitor: &mut V, pattern: &mut Pattern)
+where
+    V: VisitorMut,
+{
+    match pattern {
+        Pattern::Grouped { inner, .. } => visitor.visit_pattern_mut(inner),
+        Pattern::Identifier {
+            identifier,
+            pattern,
+            ..
+        } => {
+            visitor.visit_identifier_mut(identifier);
+
+            if let Some(pattern) = pattern {
+                visitor.visit_pattern_mut(pattern);
+            }
+        }
+        Pattern::List { inner_patterns, .. } => {
+            walk_list_mut!(visitor, visit_pattern_mut, inner_patterns);
+        }
+        Pattern::Literal(literal) | Pattern::NegativeLiteral { literal, .. } => {
+            visitor.visit_literal_mut(literal);
+        }
+        Pattern::Or { left, right, .. } => {
+            visitor.visit_pattern_mut(left);
+            visitor.visit_pattern_mut(right);
+        }
+        Pattern::Path { path, .. } => visitor.visit_path_mut(path),
+        Pattern::Rest { .. } | Pattern::Wildcard { .. } => {}
+        Pattern::Struct { path, fields, .. } => {
+            visitor.visit_path_mut(path);
+            walk_list_mut!(visitor, visit_struct_field_pattern_mut, fields);
+        }
+        Pattern::Tuple { elements, .. } => {
+            walk_list_mut!(visitor, visit_pattern_mut, elements);
+        }
+        Pattern::TupleLike {
+            path,
+            inner_patterns,
+            ..
+        } => {
+            visitor.visit_path_mut(path);
+            walk_list_mut!(visitor, visit_pattern_mut, inner_patterns);
+        }
+    }
+}
+
+pub fn walk_struct_field_pattern_mut<V>(visitor: &mut V, pattern: &mut StructFieldPattern)
+where
+    V: VisitorMut,
+{
+    match pattern {
+        StructFieldPattern::NotRest {
+            field_name,
+            value_pattern,
+            ..
+        } => {
+            visitor.visit_identifier_mut(field_name);
+
+            if let Some(value_pattern) = value_pattern {
+                visitor.visit_pattern_mut(value_pattern);
+            }
+        }
+        StructFieldPattern::Rest { .. } => {}
+    }
+}
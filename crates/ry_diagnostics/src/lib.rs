@@ -68,11 +68,11 @@ use std::{
 
 use codespan_reporting::{
     diagnostic::{self, Severity},
-    files::{self, Files},
+    files::{self, Files, SimpleFile},
     term::{
         self,
-        termcolor::{ColorChoice, StandardStream},
-        Config,
+        termcolor::{ColorChoice, NoColor, StandardStream},
+        Chars, Config,
     },
 };
 use ry_filesystem::file::InMemoryFile;
@@ -213,10 +213,18 @@ impl DiagnosticsEmitter {
 
     /// Emit diagnostics associated with a particular file.
     pub fn emit_file_diagnostics(&self, path: &Path, file_diagnostics: &[Diagnostic]) {
-        let file = InMemoryFile::new_or_panic(path);
+        self.emit_in_memory_file_diagnostics(&InMemoryFile::new_or_panic(path), file_diagnostics);
+    }
 
+    /// Emit diagnostics associated with a file, which is already read
+    /// (or does not exist on the disk, e.g. a source read from stdin).
+    pub fn emit_in_memory_file_diagnostics(
+        &self,
+        file: &InMemoryFile<'_>,
+        file_diagnostics: &[Diagnostic],
+    ) {
         for diagnostic in file_diagnostics {
-            term::emit(&mut self.writer.lock(), &self.config, &file, diagnostic)
+            term::emit(&mut self.writer.lock(), &self.config, file, diagnostic)
                 .expect("Cannot emit the diagnostic");
         }
     }
@@ -255,6 +263,33 @@ impl DiagnosticsEmitter {
     }
 }
 
+/// Renders the diagnostic against the source text in a rustc-like format,
+/// without colors and using only ASCII characters for decorations:
+///
+/// ```txt
+/// error[E001]: unexpected token
+///   --> main.ry:1:9
+///   |
+/// 1 | let a = ;
+///   |         ^ expected expression
+/// ```
+///
+/// # Panics
+/// If a label of the diagnostic is out of bounds of the source text.
+#[must_use]
+pub fn render_diagnostic(diagnostic: &Diagnostic, source: &str, filename: &str) -> String {
+    let file = SimpleFile::new(filename, source);
+    let config = Config {
+        chars: Chars::ascii(),
+        ..Config::default()
+    };
+    let mut writer = NoColor::new(Vec::new());
+
+    term::emit(&mut writer, &config, &file, diagnostic).expect("Cannot render the diagnostic");
+
+    String::from_utf8(writer.into_inner()).expect("Rendered diagnostic is not valid UTF-8")
+}
+
 /// General status of diagnostics.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DiagnosticsStatus {
@@ -291,6 +326,95 @@ pub fn check_global_diagnostics(diagnostics: &GlobalDiagnostics) -> DiagnosticsS
     DiagnosticsStatus::Ok
 }
 
+/// Code of a diagnostic, shared by all the compiler stages, so that codes
+/// never collide and tooling can filter diagnostics by them.
+///
+/// Every variant is mapped to a stable number, which must never be reused
+/// for another diagnostic. Displayed as `E` (errors) or `W` (warnings)
+/// followed by the three-digit number.
+///
+/// # Example
+/// ```
+/// # use ry_diagnostics::DiagnosticCode;
+/// assert_eq!(DiagnosticCode::UnexpectedToken.to_string(), "E001");
+/// assert_eq!(DiagnosticCode::UnusedVariable.to_string(), "W000");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+    /// Invalid token found by the lexer.
+    LexError,
+
+    /// Unexpected token found by the parser.
+    UnexpectedToken,
+
+    /// Unexpected end of file when parsing an item.
+    UnexpectedEndOfFile,
+
+    /// Float literal overflow.
+    FloatOverflow,
+
+    /// Symbol is not found in the current scope.
+    NotFound,
+
+    /// Unnecessary visibility qualifier.
+    UnnecessaryVisibilityQualifier,
+
+    /// Tuple index is not a plain decimal integer.
+    InvalidTupleIndex,
+
+    /// Variable is defined, but never used.
+    UnusedVariable,
+
+    /// Variable shadows another one defined in the same scope.
+    ShadowedVariable,
+}
+
+impl DiagnosticCode {
+    /// All the diagnostic codes.
+    pub const ALL: [Self; 9] = [
+        Self::LexError,
+        Self::UnexpectedToken,
+        Self::UnexpectedEndOfFile,
+        Self::FloatOverflow,
+        Self::NotFound,
+        Self::UnnecessaryVisibilityQualifier,
+        Self::InvalidTupleIndex,
+        Self::UnusedVariable,
+        Self::ShadowedVariable,
+    ];
+
+    /// Returns the stable number of the code (unique among errors and among
+    /// warnings separately).
+    #[inline]
+    #[must_use]
+    pub const fn number(self) -> u16 {
+        match self {
+            Self::LexError | Self::UnusedVariable => 0,
+            Self::UnexpectedToken | Self::ShadowedVariable => 1,
+            Self::UnexpectedEndOfFile => 2,
+            Self::FloatOverflow => 3,
+            Self::NotFound => 4,
+            Self::UnnecessaryVisibilityQualifier => 5,
+            Self::InvalidTupleIndex => 6,
+        }
+    }
+
+    /// Returns `true` if the code belongs to a warning.
+    #[inline]
+    #[must_use]
+    pub const fn is_warning(self) -> bool {
+        matches!(self, Self::UnusedVariable | Self::ShadowedVariable)
+    }
+}
+
+impl Display for DiagnosticCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let prefix = if self.is_warning() { 'W' } else { 'E' };
+
+        write!(f, "{prefix}{:03}", self.number())
+    }
+}
+
 /// Anything that can be reported using [`DiagnosticsEmitter`].
 pub trait BuildDiagnostic {
     /// Convert [`self`] into [`SingleContextDiagnostic`].
@@ -0,0 +1,22 @@
+use std::collections::HashSet;
+
+use ry_diagnostics::DiagnosticCode;
+
+#[test]
+fn unique_codes() {
+    let codes: HashSet<_> = DiagnosticCode::ALL
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+    assert_eq!(codes.len(), DiagnosticCode::ALL.len());
+}
+
+#[test]
+fn code_format() {
+    assert_eq!(DiagnosticCode::LexError.to_string(), "E000");
+    assert_eq!(DiagnosticCode::NotFound.to_string(), "E004");
+    assert_eq!(DiagnosticCode::InvalidTupleIndex.to_string(), "E006");
+    assert_eq!(DiagnosticCode::UnusedVariable.to_string(), "W000");
+    assert_eq!(DiagnosticCode::ShadowedVariable.to_string(), "W001");
+}
@@ -0,0 +1,21 @@
+use codespan_reporting::diagnostic::Diagnostic;
+use ry_diagnostics::{render_diagnostic, DiagnosticCode};
+use ry_filesystem::span::Span;
+
+#[test]
+fn single_label() {
+    let source = "fun main() {\n    let a = ;\n}\n";
+    let diagnostic = Diagnostic::error()
+        .with_message("unexpected token")
+        .with_code(DiagnosticCode::UnexpectedToken.to_string())
+        .with_labels(vec![Span { start: 25, end: 26 }
+            .to_primary_label()
+            .with_message("expected expression")]);
+
+    let rendered = render_diagnostic(&diagnostic, source, "main.ry");
+
+    assert!(rendered.starts_with("error[E001]: unexpected token\n"));
+    assert!(rendered.contains("--> main.ry:2:13\n"));
+    assert!(rendered.contains("2 |     let a = ;\n"));
+    assert!(rendered.contains("  |             ^ expected expression\n"));
+}
@@ -8,6 +8,7 @@ use codespan_reporting::diagnostic::Label;
 ///
 /// Implements [`Copy`], when [`Range<usize>`] does not.
 #[derive(Copy, Clone, Hash, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Span {
     /// Offset of starting byte in the source text.
     pub start: usize,
@@ -24,6 +25,47 @@ pub struct Span {
 /// when start and end positions are equal
 pub const DUMMY_SPAN: Span = Span { start: 0, end: 0 };
 
+/// Anything that has a location in the source text, e.g. a token or an AST
+/// node. Allows writing diagnostic helpers generic over node types.
+///
+/// # Example
+/// ```
+/// # use ry_filesystem::span::{Span, Spanned};
+/// fn width<T: Spanned>(node: &T) -> usize {
+///     node.span().len()
+/// }
+///
+/// assert_eq!(width(&Span { start: 2, end: 5 }), 3);
+/// ```
+pub trait Spanned {
+    /// Returns the location of the node in the source text.
+    fn span(&self) -> Span;
+}
+
+impl Spanned for Span {
+    #[inline]
+    fn span(&self) -> Span {
+        *self
+    }
+}
+
+/// Represents a human-readable position in the source text.
+#[derive(Copy, Clone, Hash, Debug, Default, PartialEq, Eq)]
+pub struct LineColumn {
+    /// Line number (starting from `1`).
+    pub line: usize,
+    /// Column number (starting from `0`). Counted in characters by
+    /// [`Span::to_line_column`] and in UTF-16 code units by
+    /// [`Span::to_utf16_line_column`].
+    pub column: usize,
+}
+
+impl Display for LineColumn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}:{}", self.line, self.column))
+    }
+}
+
 impl Display for Span {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_fmt(format_args!("{}..{}", self.start, self.end))
@@ -46,6 +88,253 @@ impl Span {
     pub fn to_secondary_label(self) -> Label<()> {
         Label::secondary((), self)
     }
+
+    /// Returns the length of the span in bytes. Reversed spans (where
+    /// `start > end`) have zero length.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// assert_eq!(Span { start: 2, end: 5 }.len(), 3);
+    /// assert_eq!(Span { start: 5, end: 2 }.len(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns `true` if the span doesn't cover any bytes, i.e. it is either
+    /// empty or reversed.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// assert!(Span { start: 3, end: 3 }.is_empty());
+    /// assert!(!Span { start: 2, end: 3 }.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Returns the smallest span covering both `self` and `other`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// let span = Span { start: 0, end: 3 }.to(Span { start: 5, end: 8 });
+    ///
+    /// assert_eq!(span, Span { start: 0, end: 8 });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to(self, other: Self) -> Self {
+        Self {
+            start: if self.start < other.start {
+                self.start
+            } else {
+                other.start
+            },
+            end: if self.end > other.end {
+                self.end
+            } else {
+                other.end
+            },
+        }
+    }
+
+    /// Returns the union of two spans, if they overlap or are adjacent,
+    /// otherwise returns [`None`], because the union cannot be represented
+    /// as a single span.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// let a = Span { start: 0, end: 3 };
+    ///
+    /// assert_eq!(a.union(Span { start: 3, end: 5 }), Some(Span { start: 0, end: 5 }));
+    /// assert_eq!(a.union(Span { start: 4, end: 5 }), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn union(self, other: Self) -> Option<Self> {
+        if self.start > other.end || other.start > self.end {
+            None
+        } else {
+            Some(self.to(other))
+        }
+    }
+
+    /// Moves both ends of the span by `delta` bytes. Ends, that would become
+    /// negative, are clamped to `0`.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// let span = Span { start: 2, end: 5 };
+    ///
+    /// assert_eq!(span.offset(3), Span { start: 5, end: 8 });
+    /// assert_eq!(span.offset(-3), Span { start: 0, end: 2 });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn offset(self, delta: isize) -> Self {
+        Self {
+            start: self.start.saturating_add_signed(delta),
+            end: self.end.saturating_add_signed(delta),
+        }
+    }
+
+    /// Converts the span from the coordinates of a source text, that is
+    /// located at `base` in another (parent) source text, e.g. an included
+    /// file, into the coordinates of the parent.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// assert_eq!(Span { start: 0, end: 3 }.shifted_into(2), Span { start: 2, end: 5 });
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn shifted_into(self, base: usize) -> Self {
+        Self {
+            start: base + self.start,
+            end: base + self.end,
+        }
+    }
+
+    /// Returns `true` if the given byte offset is located inside the span.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// let span = Span { start: 2, end: 4 };
+    ///
+    /// assert!(span.contains(2));
+    /// assert!(!span.contains(4));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn contains(self, offset: usize) -> bool {
+        self.start <= offset && offset < self.end
+    }
+
+    /// Returns the part of the source text located in the span, or [`None`]
+    /// if the span is reversed, out of bounds or does not lie on character
+    /// boundaries.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// assert_eq!(Span { start: 1, end: 3 }.slice("test"), Some("es"));
+    /// assert_eq!(Span { start: 3, end: 1 }.slice("test"), None);
+    /// assert_eq!(Span { start: 2, end: 10 }.slice("test"), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn slice<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.start..self.end)
+    }
+
+    /// Same as [`Span::slice`], but returns an empty string if the span
+    /// cannot be used to index the source text.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// assert_eq!(Span { start: 0, end: 2 }.slice_or_empty("test"), "te");
+    /// assert_eq!(Span { start: 2, end: 10 }.slice_or_empty("test"), "");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn slice_or_empty<'a>(&self, source: &'a str) -> &'a str {
+        self.slice(source).unwrap_or_default()
+    }
+
+    /// Resolves line and column numbers of both ends of the span in the given
+    /// source text. Columns are counted in characters, not bytes.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::{Span, LineColumn};
+    /// let (start, end) = Span { start: 4, end: 7 }.to_line_column("foo\nbar");
+    ///
+    /// assert_eq!(start, LineColumn { line: 2, column: 0 });
+    /// assert_eq!(end.to_string(), "2:3");
+    /// ```
+    #[must_use]
+    pub fn to_line_column(&self, source: &str) -> (LineColumn, LineColumn) {
+        self.line_columns(source, |_| 1)
+    }
+
+    /// Same as [`Span::to_line_column`], but columns are counted in UTF-16
+    /// code units, as required by the Language Server Protocol.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::{Span, LineColumn};
+    /// let (start, _) = Span { start: 6, end: 7 }.to_utf16_line_column("a\n😀 b");
+    ///
+    /// assert_eq!(start, LineColumn { line: 2, column: 2 });
+    /// ```
+    #[must_use]
+    pub fn to_utf16_line_column(&self, source: &str) -> (LineColumn, LineColumn) {
+        self.line_columns(source, char::len_utf16)
+    }
+
+    /// Converts both ends of the span from byte offsets into offsets in
+    /// UTF-16 code units, as required by the Language Server Protocol.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_filesystem::span::Span;
+    /// // `😀` takes 4 bytes, but only 2 UTF-16 code units.
+    /// assert_eq!(Span { start: 5, end: 8 }.to_utf16("😀 foo"), (3, 6));
+    /// ```
+    #[must_use]
+    pub fn to_utf16(&self, source: &str) -> (usize, usize) {
+        let offset = |offset: usize| {
+            source
+                .char_indices()
+                .take_while(|(index, _)| *index < offset)
+                .map(|(_, c)| c.len_utf16())
+                .sum()
+        };
+
+        (offset(self.start), offset(self.end))
+    }
+
+    /// Resolves line and column numbers of both ends of the span, counting
+    /// the width of every character in columns with `width`.
+    fn line_columns(
+        &self,
+        source: &str,
+        width: impl Fn(char) -> usize,
+    ) -> (LineColumn, LineColumn) {
+        let mut start = None;
+        let mut current = LineColumn { line: 1, column: 0 };
+
+        for (offset, c) in source.char_indices() {
+            if start.is_none() && offset >= self.start {
+                start = Some(current);
+            }
+
+            if offset >= self.end {
+                break;
+            }
+
+            if c == '\n' {
+                current.line += 1;
+                current.column = 0;
+            } else {
+                current.column += width(c);
+            }
+        }
+
+        (start.unwrap_or(current), current)
+    }
 }
 
 impl From<Span> for Range<usize> {
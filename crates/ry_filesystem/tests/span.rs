@@ -0,0 +1,197 @@
+use ry_filesystem::span::{LineColumn, Span};
+
+#[test]
+fn line_column() {
+    let source = "foo\nбар baz\n";
+
+    assert_eq!(
+        Span { start: 0, end: 3 }.to_line_column(source),
+        (
+            LineColumn { line: 1, column: 0 },
+            LineColumn { line: 1, column: 3 }
+        )
+    );
+    assert_eq!(
+        Span { start: 11, end: 14 }.to_line_column(source),
+        (
+            LineColumn { line: 2, column: 4 },
+            LineColumn { line: 2, column: 7 }
+        )
+    );
+    assert_eq!(
+        Span { start: 15, end: 15 }.to_line_column(source),
+        (
+            LineColumn { line: 3, column: 0 },
+            LineColumn { line: 3, column: 0 }
+        )
+    );
+}
+
+#[test]
+fn utf16() {
+    let source = "😀 foo\nбар 😀 baz";
+
+    let foo = Span { start: 5, end: 8 };
+    assert_eq!(foo.slice(source), Some("foo"));
+    assert_eq!(foo.to_utf16(source), (3, 6));
+    assert_eq!(
+        foo.to_utf16_line_column(source),
+        (
+            LineColumn { line: 1, column: 3 },
+            LineColumn { line: 1, column: 6 }
+        )
+    );
+
+    let baz = Span { start: 21, end: 24 };
+    assert_eq!(baz.slice(source), Some("baz"));
+    assert_eq!(baz.to_utf16(source), (14, 17));
+    assert_eq!(
+        baz.to_utf16_line_column(source),
+        (
+            LineColumn { line: 2, column: 7 },
+            LineColumn {
+                line: 2,
+                column: 10
+            }
+        )
+    );
+    assert_eq!(
+        baz.to_line_column(source),
+        (
+            LineColumn { line: 2, column: 6 },
+            LineColumn { line: 2, column: 9 }
+        )
+    );
+
+    let ascii = Span { start: 1, end: 3 };
+    assert_eq!(ascii.to_utf16("abcd"), (1, 3));
+}
+
+#[test]
+fn line_column_display() {
+    assert_eq!(
+        LineColumn {
+            line: 3,
+            column: 12
+        }
+        .to_string(),
+        "3:12"
+    );
+}
+
+#[test]
+fn to() {
+    let disjoint = (Span { start: 0, end: 3 }, Span { start: 5, end: 8 });
+    assert_eq!(disjoint.0.to(disjoint.1), Span { start: 0, end: 8 });
+    assert_eq!(disjoint.1.to(disjoint.0), Span { start: 0, end: 8 });
+
+    let overlapping = (Span { start: 0, end: 5 }, Span { start: 3, end: 8 });
+    assert_eq!(overlapping.0.to(overlapping.1), Span { start: 0, end: 8 });
+
+    let nested = (Span { start: 0, end: 10 }, Span { start: 3, end: 5 });
+    assert_eq!(nested.0.to(nested.1), Span { start: 0, end: 10 });
+    assert_eq!(nested.1.to(nested.0), Span { start: 0, end: 10 });
+}
+
+#[test]
+fn offset() {
+    let span = Span { start: 4, end: 10 };
+
+    assert_eq!(span.offset(0), span);
+    assert_eq!(span.offset(5), Span { start: 9, end: 15 });
+    assert_eq!(span.offset(-4), Span { start: 0, end: 6 });
+    assert_eq!(span.offset(-6), Span { start: 0, end: 4 });
+    assert_eq!(span.offset(-20), Span { start: 0, end: 0 });
+    assert_eq!(
+        span.offset(isize::MAX).offset(isize::MAX),
+        Span {
+            start: usize::MAX,
+            end: usize::MAX
+        }
+    );
+}
+
+#[test]
+fn shifted_into() {
+    let parent = "fun main() { foo(); }";
+    let child = "foo()";
+    let base = parent.find(child).unwrap();
+
+    let span = Span { start: 0, end: 3 }.shifted_into(base);
+
+    assert_eq!(span, Span { start: 13, end: 16 });
+    assert_eq!(span.slice(parent), Some("foo"));
+    assert_eq!(
+        Span { start: 2, end: 2 }.shifted_into(0),
+        Span { start: 2, end: 2 }
+    );
+}
+
+#[test]
+fn union() {
+    assert_eq!(
+        Span { start: 0, end: 3 }.union(Span { start: 5, end: 8 }),
+        None
+    );
+    assert_eq!(
+        Span { start: 0, end: 5 }.union(Span { start: 3, end: 8 }),
+        Some(Span { start: 0, end: 8 })
+    );
+    assert_eq!(
+        Span { start: 0, end: 3 }.union(Span { start: 3, end: 8 }),
+        Some(Span { start: 0, end: 8 })
+    );
+    assert_eq!(
+        Span { start: 3, end: 5 }.union(Span { start: 0, end: 10 }),
+        Some(Span { start: 0, end: 10 })
+    );
+}
+
+#[test]
+fn contains() {
+    let span = Span { start: 3, end: 5 };
+
+    assert!(!span.contains(2));
+    assert!(span.contains(3));
+    assert!(span.contains(4));
+    assert!(!span.contains(5));
+    assert!(!Span { start: 3, end: 3 }.contains(3));
+}
+
+#[test]
+fn slice() {
+    let source = "foo бар";
+
+    assert_eq!(Span { start: 0, end: 3 }.slice(source), Some("foo"));
+    assert_eq!(Span { start: 4, end: 10 }.slice(source), Some("бар"));
+    assert_eq!(Span { start: 3, end: 3 }.slice(source), Some(""));
+
+    // reversed
+    assert_eq!(Span { start: 3, end: 0 }.slice(source), None);
+
+    // out of bounds
+    assert_eq!(Span { start: 4, end: 11 }.slice(source), None);
+    assert_eq!(Span { start: 20, end: 30 }.slice(source), None);
+
+    // not on a character boundary
+    assert_eq!(Span { start: 4, end: 5 }.slice(source), None);
+}
+
+#[test]
+fn slice_or_empty() {
+    assert_eq!(Span { start: 0, end: 3 }.slice_or_empty("foo"), "foo");
+    assert_eq!(Span { start: 3, end: 0 }.slice_or_empty("foo"), "");
+    assert_eq!(Span { start: 0, end: 4 }.slice_or_empty("foo"), "");
+}
+
+#[test]
+fn len() {
+    assert_eq!(Span { start: 3, end: 7 }.len(), 4);
+    assert!(!Span { start: 3, end: 7 }.is_empty());
+
+    assert_eq!(Span { start: 4, end: 4 }.len(), 0);
+    assert!(Span { start: 4, end: 4 }.is_empty());
+
+    assert_eq!(Span { start: 7, end: 3 }.len(), 0);
+    assert!(Span { start: 7, end: 3 }.is_empty());
+}
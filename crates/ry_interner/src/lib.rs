@@ -65,11 +65,16 @@
 
 use core::{
     hash::{BuildHasher, Hash, Hasher},
+    mem::size_of,
     str::from_utf8_unchecked,
 };
 
 extern crate alloc;
 
+// Used by the serialization round-trip tests only.
+#[cfg(test)]
+use serde_json as _;
+
 use alloc::{string::String, vec::Vec};
 
 use hashbrown::{
@@ -185,6 +190,35 @@ impl Default for Interner {
     }
 }
 
+impl Interner {
+    /// Creates a new [`Interner`], that contains builtin symbols followed by
+    /// the given strings, which are assigned consecutive symbols in the given
+    /// order. This way the symbols are the same across runs and can be
+    /// compared with directly, e.g. to check for keywords.
+    ///
+    /// Strings that are already interned (including builtin ones) keep their
+    /// symbols and don't take up a new one.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::{symbols, Interner};
+    /// let interner = Interner::with_preloaded(&["fun", "struct"]);
+    ///
+    /// assert_eq!(interner.get("fun"), Some(symbols::STD + 1));
+    /// assert_eq!(interner.get("struct"), Some(symbols::STD + 2));
+    /// ```
+    #[must_use]
+    pub fn with_preloaded(strings: &[&'static str]) -> Self {
+        let mut interner = Self::new();
+
+        for string in strings {
+            interner.get_or_intern(string);
+        }
+
+        interner
+    }
+}
+
 fn hash_value<T>(hasher: &impl BuildHasher, value: &T) -> u64
 where
     T: ?Sized + Hash,
@@ -253,6 +287,19 @@ impl Backend {
         unsafe { from_utf8_unchecked(&self.buffer.as_bytes()[span.start..span.end]) }
     }
 
+    /// Returns an iterator over all symbols and their strings in the order
+    /// they were interned.
+    fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> + '_ {
+        let mut start = 0;
+
+        self.ends.iter().enumerate().map(move |(symbol, &end)| {
+            let string = self.str_at(Span { start, end });
+            start = end;
+
+            (symbol, string)
+        })
+    }
+
     /// Pushes the string into the buffer and returns corresponding symbol.
     fn push(&mut self, string: &str) -> Symbol {
         self.buffer.push_str(string);
@@ -330,18 +377,50 @@ where
     }
 
     /// Returns the number of symbols/strings interned by the interner.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// let mut interner = Interner::default();
+    /// let len = interner.len();
+    ///
+    /// interner.get_or_intern("foo");
+    /// interner.get_or_intern("foo");
+    ///
+    /// assert_eq!(interner.len(), len + 1);
+    /// ```
     #[inline]
-    #[allow(clippy::len_without_is_empty)] // interner is never empty
     pub fn len(&self) -> usize {
         self.dedup.len()
     }
 
-    /// Returns the symbol for the given string if it is interned.
+    /// Returns `true` if the interner contains no strings. Note that the
+    /// interner created with [`Interner::default`] always contains builtin
+    /// symbols.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.dedup.is_empty()
+    }
+
+    /// Returns an approximate amount of memory (in bytes) used by the
+    /// interner: the total length of interned strings plus the size of
+    /// internal bookkeeping tables.
+    #[must_use]
+    pub fn approximate_bytes(&self) -> usize {
+        self.backend.buffer.len()
+            + self.backend.ends.len() * size_of::<usize>()
+            + self.dedup.capacity() * size_of::<Symbol>()
+    }
+
+    /// Returns the symbol for the given string if it is interned. Unlike
+    /// [`Interner::get_or_intern`], never modifies the interner.
     ///
     /// # Example
     /// ```
     /// # use ry_interner::Interner;
     /// let mut interner = Interner::default();
+    /// assert_eq!(interner.get("hello"), None);
+    ///
     /// let hello_symbol = interner.get_or_intern("hello");
     /// assert_eq!(Some(hello_symbol), interner.get("hello"));
     /// ```
@@ -424,6 +503,108 @@ where
     pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
         self.backend.resolve(symbol)
     }
+
+    /// Returns the string for the given symbol, or `default` if the symbol
+    /// cannot be resolved (for instance, because it comes from another interner).
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// let mut interner = Interner::default();
+    /// let hello_symbol = interner.get_or_intern("hello");
+    ///
+    /// assert_eq!(interner.resolve_or(hello_symbol, "<unknown>"), "hello");
+    /// assert_eq!(interner.resolve_or(usize::MAX, "<unknown>"), "<unknown>");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn resolve_or<'a>(&'a self, symbol: Symbol, default: &'a str) -> &'a str {
+        self.resolve(symbol).unwrap_or(default)
+    }
+
+    /// Returns an iterator over all interned symbols and their strings, in
+    /// the order they were interned (builtin symbols go first).
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::{Interner, symbols::UNDERSCORE};
+    /// let mut interner = Interner::default();
+    /// let hello_symbol = interner.get_or_intern("hello");
+    ///
+    /// assert_eq!(interner.iter().next(), Some((UNDERSCORE, "_")));
+    /// assert_eq!(interner.iter().last(), Some((hello_symbol, "hello")));
+    /// assert_eq!(interner.iter().count(), interner.len());
+    /// ```
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (Symbol, &str)> + '_ {
+        self.backend.iter()
+    }
+
+    /// Returns all symbols whose strings start with the given prefix, in the
+    /// order they were interned.
+    ///
+    /// The lookup is a linear scan over all interned strings.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::{Interner, symbols::{UINT8, UINT16, UINT32, UINT64}};
+    /// let mut interner = Interner::default();
+    /// let foo = interner.get_or_intern("foo");
+    /// let foobar = interner.get_or_intern("foobar");
+    /// interner.get_or_intern("bar");
+    ///
+    /// assert_eq!(interner.symbols_with_prefix("foo"), vec![foo, foobar]);
+    /// assert_eq!(interner.symbols_with_prefix("uint"), vec![UINT8, UINT16, UINT32, UINT64]);
+    /// assert!(interner.symbols_with_prefix("baz").is_empty());
+    /// ```
+    #[must_use]
+    pub fn symbols_with_prefix(&self, prefix: &str) -> Vec<Symbol> {
+        self.iter()
+            .filter(|(_, string)| string.starts_with(prefix))
+            .map(|(symbol, _)| symbol)
+            .collect()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<H> serde::Serialize for Interner<H>
+where
+    H: BuildHasher + Default,
+{
+    /// Serializes the interner as a sequence of interned strings, ordered by
+    /// their symbols.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.collect_seq(self.iter().map(|(_, string)| string))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, H> serde::Deserialize<'de> for Interner<H>
+where
+    H: BuildHasher + Default,
+{
+    /// Restores the interner from a sequence of strings, so that every symbol
+    /// resolves to the same string as before serialization.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let strings = Vec::<String>::deserialize(deserializer)?;
+        let mut interner = Self::with_capacity(strings.len());
+
+        for (expected_symbol, string) in strings.iter().enumerate() {
+            if interner.get_or_intern(string) != expected_symbol {
+                return Err(serde::de::Error::custom(format!(
+                    "duplicate interned string `{string}`"
+                )));
+            }
+        }
+
+        Ok(interner)
+    }
 }
 
 /// Represents a location of an interned string inside the [`Backend`]'s internal
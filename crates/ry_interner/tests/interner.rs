@@ -0,0 +1,86 @@
+use ry_interner::{symbols, Interner};
+
+#[test]
+fn get() {
+    let mut interner = Interner::default();
+    let len = interner.len();
+
+    assert_eq!(interner.get("foo"), None);
+    assert_eq!(interner.len(), len);
+
+    let symbol = interner.get_or_intern("foo");
+
+    assert_eq!(interner.get("foo"), Some(symbol));
+    assert_eq!(interner.get(String::from("foo")), Some(symbol));
+    assert_eq!(interner.len(), len + 1);
+}
+
+#[test]
+fn get_preinterned() {
+    let interner = Interner::default();
+
+    assert_eq!(interner.get("uint32"), Some(symbols::UINT32));
+}
+
+#[test]
+fn with_preloaded() {
+    let mut interner = Interner::with_preloaded(&["fun", "struct", "uint32", "fun", "enum"]);
+
+    assert_eq!(interner.get("fun"), Some(symbols::STD + 1));
+    assert_eq!(interner.get("struct"), Some(symbols::STD + 2));
+    assert_eq!(interner.get("uint32"), Some(symbols::UINT32));
+    assert_eq!(interner.get("enum"), Some(symbols::STD + 3));
+    assert_eq!(interner.resolve(symbols::STD + 3), Some("enum"));
+
+    assert_eq!(interner.get_or_intern("foo"), symbols::STD + 4);
+    assert_eq!(
+        Interner::with_preloaded(&["fun", "struct"]).get("struct"),
+        Some(symbols::STD + 2)
+    );
+}
+
+#[test]
+fn resolve_or() {
+    let mut interner = Interner::default();
+    let symbol = interner.get_or_intern("foo");
+    let invalid = interner.len();
+
+    assert_eq!(interner.resolve_or(symbol, "<unknown>"), "foo");
+    assert_eq!(interner.resolve(invalid), None);
+    assert_eq!(interner.resolve_or(invalid, "<unknown>"), "<unknown>");
+}
+
+#[test]
+fn len() {
+    let mut interner = Interner::default();
+    let len = interner.len();
+
+    interner.get_or_intern("foo");
+    assert_eq!(interner.len(), len + 1);
+
+    interner.get_or_intern("foo");
+    interner.get_or_intern("uint32");
+    assert_eq!(interner.len(), len + 1);
+
+    interner.get_or_intern("bar");
+    assert_eq!(interner.len(), len + 2);
+    assert!(!interner.is_empty());
+}
+
+#[test]
+fn empty() {
+    let interner: Interner = Interner::with_capacity(0);
+
+    assert_eq!(interner.len(), 0);
+    assert!(interner.is_empty());
+}
+
+#[test]
+fn approximate_bytes() {
+    let mut interner = Interner::default();
+    let bytes = interner.approximate_bytes();
+
+    interner.get_or_intern("a".repeat(1000));
+
+    assert!(interner.approximate_bytes() >= bytes + 1000);
+}
@@ -0,0 +1,30 @@
+#![cfg(feature = "serde")]
+
+use ry_interner::{symbols, Interner};
+
+#[test]
+fn round_trip() {
+    let mut interner = Interner::default();
+    let symbols = ["foo", "bar", "Point", "привет", "with \"quotes\""]
+        .map(|string| interner.get_or_intern(string));
+
+    let json = serde_json::to_string(&interner).unwrap();
+    let deserialized: Interner = serde_json::from_str(&json).unwrap();
+
+    assert_eq!(deserialized.len(), interner.len());
+    assert_eq!(deserialized.resolve(symbols::UINT32), Some("uint32"));
+
+    for symbol in symbols {
+        assert_eq!(deserialized.resolve(symbol), interner.resolve(symbol));
+    }
+
+    assert_eq!(
+        deserialized.iter().collect::<Vec<_>>(),
+        interner.iter().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn duplicate_strings() {
+    assert!(serde_json::from_str::<Interner>(r#"["a", "b", "a"]"#).is_err());
+}
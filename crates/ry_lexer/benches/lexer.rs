@@ -0,0 +1,70 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use ry_ast::token::RawToken;
+use ry_interner::Interner;
+use ry_lexer::Lexer;
+
+/// A piece of a typical Ry source file, which mostly consists of
+/// ASCII identifiers and keywords.
+const CHUNK: &str = r#"
+/// Represents a person.
+pub struct Person[T] {
+    name: String,
+    age: uint32,
+    metadata: Option[T],
+}
+
+impl[T] Person[T] where T: Clone {
+    pub fun new(name: String, age: uint32): Self {
+        Self { name, age, metadata: None }
+    }
+
+    pub fun is_adult(self): bool {
+        self.age >= 18 && self.name.len() > 0
+    }
+}
+
+fun main() {
+    let people = [Person.new("Alice", 24), Person.new("Bob", 17)];
+    let mut adults_count = 0;
+
+    while adults_count < people.len() {
+        if people[adults_count].is_adult() { adults_count += 1; } else { break; }
+    }
+
+    println("привет, мир: " + adults_count.to_string());
+}
+"#;
+
+/// Builds a large synthetic source file.
+fn source() -> String {
+    CHUNK.repeat(1000)
+}
+
+fn lex_all(source: &str, interner: &mut Interner) -> usize {
+    let mut lexer = Lexer::new(source, interner);
+    let mut count = 0;
+
+    while lexer.next_token().raw != RawToken::EndOfFile {
+        count += 1;
+    }
+
+    count
+}
+
+fn lexer(c: &mut Criterion) {
+    let source = source();
+
+    let mut group = c.benchmark_group("lexer");
+    group.throughput(Throughput::Bytes(source.len() as u64));
+    group.bench_function("synthetic", |b| {
+        b.iter_batched_ref(
+            Interner::default,
+            |interner| lex_all(black_box(&source), interner),
+            BatchSize::SmallInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lexer);
+criterion_main!(benches);
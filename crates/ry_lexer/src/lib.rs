@@ -66,7 +66,11 @@
     clippy::unnested_or_patterns
 )]
 
-use std::{mem, str::Chars, string::String};
+// Used by the lexer benchmark only.
+#[cfg(test)]
+use criterion as _;
+
+use std::{collections::HashMap, mem, ops::ControlFlow, str::Chars, string::String};
 
 use ry_ast::{
     token::{LexError, RawLexError, RawToken, Token, RESERVED},
@@ -77,6 +81,7 @@ use ry_interner::{Interner, Symbol};
 use ry_stable_likely::unlikely;
 
 mod number;
+pub mod trivia;
 
 /// Represents a lexer state machine.
 /// Lexer is fairly standart. It returns [`type@Token`] and then advances its state on
@@ -93,7 +98,7 @@ mod number;
 ///     lexer.next_token(),
 ///     Token {
 ///         raw: EndOfFile,
-///         span: Span { start: 0, end: 1 }
+///         span: Span { start: 0, end: 0 }
 ///     }
 /// );
 /// ```
@@ -138,10 +143,37 @@ pub struct Lexer<'source, 'interner> {
 
     /// Symbol corresponding to an identifier being processed early on.
     pub scanned_identifier: Symbol,
+    /// Value of an integer literal being processed early on.
+    pub scanned_integer: u64,
+    /// Magnitude of an imaginary literal being processed early on.
+    pub scanned_imaginary: f64,
     /// Buffer for storing scanned characters (after processing escape sequences).
     pub scanned_char: char,
     /// Buffer for storing scanned strings (after processing escape sequences).
     scanned_string: String,
+    /// Buffer for storing scanned byte strings and byte literals
+    /// (after processing escape sequences).
+    scanned_bytes: Vec<u8>,
+    /// Text of a comment being processed early on (without the comment
+    /// delimiters).
+    scanned_comment: &'source str,
+
+    /// Stack of string interpolations being processed: location of `${` and
+    /// the amount of unclosed `{` inside of the interpolated expression.
+    interpolation_stack: Vec<(usize, usize)>,
+
+    /// Token, that was scanned by [`Lexer::peek_token`], but not yet consumed.
+    peeked: Option<Token>,
+
+    /// Custom set of reserved names (see [`Lexer::with_keywords`]).
+    /// [`RESERVED`] is used when [`None`].
+    keywords: Option<&'source HashMap<&'source str, RawToken>>,
+
+    /// Whether indentation mixing tabs and spaces is recorded (see
+    /// [`Lexer::with_mixed_indentation_check`]).
+    check_mixed_indentation: bool,
+    /// Locations of indentation mixing tabs and spaces.
+    mixed_indentation: Vec<Span>,
 }
 
 impl<'source, 'interner> Lexer<'source, 'interner> {
@@ -153,7 +185,7 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         let current = chars.next().unwrap_or('\0');
         let next = chars.next().unwrap_or('\0');
 
-        Self {
+        let mut lexer = Self {
             source,
             current,
             next,
@@ -161,9 +193,119 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             interner,
             location: 0,
             scanned_identifier: 0,
+            scanned_integer: 0,
+            scanned_imaginary: 0.0,
             scanned_char: '\0',
             scanned_string: String::new(),
-        }
+            scanned_bytes: Vec::new(),
+            scanned_comment: "",
+            interpolation_stack: Vec::new(),
+            peeked: None,
+            keywords: None,
+            check_mixed_indentation: false,
+            mixed_indentation: Vec::new(),
+        };
+
+        lexer.skip_shebang();
+        lexer
+    }
+
+    /// Creates a new [`Lexer`] instance, which uses a custom set of reserved
+    /// names instead of the default one ([`RESERVED`]). Names, that are not
+    /// in the set, are scanned as identifiers.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::collections::HashMap;
+    /// # use ry_interner::Interner;
+    /// # use ry_lexer::Lexer;
+    /// # use ry_ast::{token::RawToken, Token};
+    /// let keywords = HashMap::from([("fun", Token![fun])]);
+    /// let mut interner = Interner::default();
+    /// let mut lexer = Lexer::with_keywords("fun match", &mut interner, &keywords);
+    ///
+    /// assert_eq!(lexer.next_token().raw, Token![fun]);
+    /// assert_eq!(lexer.next_token().raw, RawToken::Identifier);
+    /// ```
+    #[must_use]
+    pub fn with_keywords(
+        source: &'source str,
+        interner: &'interner mut Interner,
+        keywords: &'source HashMap<&'source str, RawToken>,
+    ) -> Self {
+        let mut lexer = Self::new(source, interner);
+        lexer.keywords = Some(keywords);
+        lexer
+    }
+
+    /// Enables or disables recording of lines, which leading whitespace
+    /// mixes tabs and spaces. Disabled by default. Recorded locations are
+    /// available via [`Lexer::mixed_indentation`].
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// # use ry_lexer::Lexer;
+    /// # use ry_filesystem::span::Span;
+    /// let mut interner = Interner::default();
+    /// let mut lexer =
+    ///     Lexer::new("a\n\t b", &mut interner).with_mixed_indentation_check(true);
+    ///
+    /// lexer.next_token();
+    /// lexer.next_token();
+    ///
+    /// assert_eq!(lexer.mixed_indentation(), [Span { start: 2, end: 4 }]);
+    /// ```
+    #[must_use]
+    pub const fn with_mixed_indentation_check(mut self, enabled: bool) -> Self {
+        self.check_mixed_indentation = enabled;
+        self
+    }
+
+    /// Returns locations of indentation mixing tabs and spaces, scanned so
+    /// far. Always empty, unless enabled with
+    /// [`Lexer::with_mixed_indentation_check`].
+    #[inline]
+    #[must_use]
+    pub fn mixed_indentation(&self) -> &[Span] {
+        &self.mixed_indentation
+    }
+
+    /// Starts scanning a new source text, keeping the interner and the
+    /// already allocated internal buffers, which is useful in interactive
+    /// loops, where a lot of small inputs are scanned one by one.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// # use ry_lexer::Lexer;
+    /// # use ry_ast::token::RawToken;
+    /// let mut interner = Interner::default();
+    /// let mut lexer = Lexer::new("foo", &mut interner);
+    ///
+    /// assert_eq!(lexer.next_token().raw, RawToken::Identifier);
+    ///
+    /// lexer.reset("3");
+    ///
+    /// assert_eq!(lexer.next_token().raw, RawToken::IntegerLiteral);
+    /// ```
+    pub fn reset(&mut self, source: &'source str) {
+        let mut chars = source.chars();
+
+        self.current = chars.next().unwrap_or('\0');
+        self.next = chars.next().unwrap_or('\0');
+        self.chars = chars;
+        self.source = source;
+        self.location = 0;
+
+        self.scanned_string.clear();
+        self.scanned_bytes.clear();
+        self.scanned_comment = "";
+        self.interpolation_stack.clear();
+        self.peeked = None;
+        self.mixed_indentation.clear();
+
+        self.skip_shebang();
     }
 
     /// Returns a string being scanned early on (after processing escape sequences) and
@@ -181,6 +323,76 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         &self.scanned_string
     }
 
+    /// Returns bytes being scanned early on (after processing escape sequences) and
+    /// cleans internal lexer byte buffer. So it must be used only once!
+    #[inline]
+    #[must_use]
+    pub fn scanned_bytes(&mut self) -> Vec<u8> {
+        mem::take(&mut self.scanned_bytes)
+    }
+
+    /// Returns text of a comment being scanned early on. Comment delimiters
+    /// (`//`, `///`, `//!`, `/*` and `*/`) are not included and for line
+    /// comments one space following the delimiter is stripped as well.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// # use ry_lexer::Lexer;
+    /// let mut interner = Interner::default();
+    /// let mut lexer = Lexer::new("/// Docs.", &mut interner);
+    /// lexer.next_token();
+    ///
+    /// assert_eq!(lexer.scanned_comment(), "Docs.");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn scanned_comment(&self) -> &'source str {
+        self.scanned_comment
+    }
+
+    /// Returns the byte offset of the current character in the source text.
+    ///
+    /// # Note
+    /// If a token was peeked with [`Lexer::peek_token`], the location is
+    /// already past the peeked token.
+    ///
+    /// # Example
+    /// ```
+    /// # use ry_interner::Interner;
+    /// # use ry_lexer::Lexer;
+    /// let mut interner = Interner::default();
+    /// let mut lexer = Lexer::new("foo bar", &mut interner);
+    ///
+    /// assert_eq!(lexer.location(), 0);
+    ///
+    /// lexer.next_token();
+    ///
+    /// assert_eq!(lexer.location(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn location(&self) -> usize {
+        self.location
+    }
+
+    /// Returns the current character (`'\0'` at the end of file).
+    #[inline]
+    #[must_use]
+    pub const fn current_char(&self) -> char {
+        self.current
+    }
+
+    /// Skips a shebang line (`#!/usr/bin/env ry`), if it is located
+    /// at the very beginning of the source text.
+    fn skip_shebang(&mut self) {
+        if self.source.starts_with("#!") && !self.source.starts_with("#![") {
+            while !is_line_break(self.current) && !self.eof() {
+                self.advance();
+            }
+        }
+    }
+
     /// Returns `true` if current character is EOF (`\0`).
     #[inline]
     const fn eof(&self) -> bool {
@@ -189,9 +401,43 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
 
     /// Skips whitespace characters. See [`Lexer::is_whitespace()`] for more details.
     fn eat_whitespaces(&mut self) {
+        let start_location = self.location;
+
         while is_whitespace(self.current) {
             self.advance();
         }
+
+        if self.check_mixed_indentation {
+            self.check_indentation(start_location);
+        }
+    }
+
+    /// Records the indentation, that precedes the current character, if it
+    /// mixes tabs and spaces. `start_location` is where the whitespace,
+    /// that was just skipped, begins.
+    fn check_indentation(&mut self, start_location: usize) {
+        if self.eof() {
+            return;
+        }
+
+        let whitespace = &self.source[start_location..self.location];
+
+        // The whitespace must start a line: either contain a newline or
+        // be located at the very beginning of the source text.
+        let line_start = match whitespace.rfind(['\n', '\r']) {
+            Some(newline) => start_location + newline + 1,
+            None if start_location == 0 => 0,
+            None => return,
+        };
+
+        let indentation = &self.source[line_start..self.location];
+
+        if indentation.contains('\t') && indentation.contains(' ') {
+            self.mixed_indentation.push(Span {
+                start: line_start,
+                end: self.location,
+            });
+        }
     }
 
     /// Advances the lexer state to the next character.
@@ -229,7 +475,7 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
     const fn current_char_span(&self) -> Span {
         Span {
             start: self.location,
-            end: self.location + 1,
+            end: self.location + self.current.len_utf8(),
         }
     }
 
@@ -272,8 +518,16 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         &self.source[start_location..self.location]
     }
 
+    /// Returns `true` if the current character is a `\` that ends the
+    /// source text, so the escape sequence is cut off by the end of file.
+    const fn escape_at_eof(&self) -> bool {
+        self.current == '\\' && self.location + 1 == self.source.len()
+    }
+
     /// Parses an escape sequence.
     fn eat_escape(&mut self) -> Result<char, LexError> {
+        let start_location = self.location;
+
         self.advance(); // `\`
         let r = match self.current {
             'b' => Ok('\u{0008}'),
@@ -284,145 +538,158 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             '\'' => Ok('\''),
             '"' => Ok('"'),
             '\\' => Ok('\\'),
-            '\0' => Err(LexError {
-                raw: RawLexError::EmptyEscapeSequence,
+            '$' => Ok('$'),
+            'u' => self.eat_unicode_escape(6),
+            'U' => self.eat_unicode_escape(8),
+            'x' => self
+                .eat_hex_byte()
+                .map(char::from)
+                // Highlight the escape sequence from the backslash up to
+                // the unexpected character.
+                .map_err(|error| LexError {
+                    raw: error.raw,
+                    span: Span {
+                        start: start_location,
+                        end: error.span.end,
+                    },
+                }),
+            _ => Err(LexError {
+                raw: RawLexError::UnknownEscapeSequence,
                 span: self.current_char_span(),
             }),
-            'u' => {
-                self.advance();
+        };
 
-                if self.current != '{' {
-                    return Err(LexError {
-                        raw: RawLexError::ExpectedOpenBracketInUnicodeEscapeSequence,
-                        span: self.current_char_span(),
-                    });
-                }
+        self.advance();
 
-                self.advance();
+        r
+    }
 
-                let mut buffer = String::new();
+    /// Parses a byte escape sequence (`x{NN}`), where `NN` are exactly
+    /// 2 hexadecimal digits.
+    fn eat_hex_byte(&mut self) -> Result<u8, LexError> {
+        self.advance(); // `x`
 
-                for _ in 0..4 {
-                    if !self.current.is_ascii_hexdigit() {
-                        return Err(LexError {
-                            raw: RawLexError::ExpectedDigitInUnicodeEscapeSequence,
-                            span: self.current_char_span(),
-                        });
-                    }
+        if self.current != '{' {
+            return Err(LexError {
+                raw: RawLexError::ExpectedOpenBracketInByteEscapeSequence,
+                span: self.current_char_span(),
+            });
+        }
 
-                    buffer.push(self.current);
-                    self.advance();
-                }
+        self.advance();
 
-                if self.current != '}' {
+        let mut value = 0;
+
+        for _ in 0..2 {
+            match self.current.to_digit(16) {
+                Some(digit) => value = value * 16 + digit,
+                None => {
                     return Err(LexError {
-                        raw: RawLexError::ExpectedCloseBracketInUnicodeEscapeSequence,
+                        raw: RawLexError::ExpectedDigitInByteEscapeSequence,
                         span: self.current_char_span(),
                     });
                 }
-
-                match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
-                    Some(c) => Ok(c),
-                    None => Err(LexError {
-                        raw: RawLexError::InvalidUnicodeEscapeSequence,
-                        span: self.current_char_span(),
-                    }),
-                }
             }
-            'U' => {
-                self.advance();
 
-                if self.current != '{' {
-                    return Err(LexError {
-                        raw: RawLexError::ExpectedOpenBracketInUnicodeEscapeSequence,
-                        span: self.current_char_span(),
-                    });
-                }
+            self.advance();
+        }
 
-                self.advance();
+        if self.current != '}' {
+            return Err(LexError {
+                raw: RawLexError::ExpectedCloseBracketInByteEscapeSequence,
+                span: self.current_char_span(),
+            });
+        }
+
+        #[allow(clippy::cast_possible_truncation)]
+        Ok(value as u8)
+    }
 
-                let mut buffer = String::new();
+    /// Parses a Unicode escape sequence (`u{...}` or `U{...}`), containing
+    /// from 1 to `max_digits` hexadecimal digits.
+    fn eat_unicode_escape(&mut self, max_digits: usize) -> Result<char, LexError> {
+        self.advance(); // `u` or `U`
 
-                for _ in 0..8 {
-                    if !self.current.is_ascii_hexdigit() {
-                        return Err(LexError {
-                            raw: RawLexError::ExpectedDigitInUnicodeEscapeSequence,
-                            span: self.current_char_span(),
-                        });
-                    }
+        if self.current != '{' {
+            return Err(LexError {
+                raw: RawLexError::ExpectedOpenBracketInUnicodeEscapeSequence,
+                span: self.current_char_span(),
+            });
+        }
 
-                    buffer.push(self.current);
-                    self.advance();
-                }
+        self.advance();
 
-                if self.current != '}' {
-                    return Err(LexError {
-                        raw: RawLexError::ExpectedCloseBracketInUnicodeEscapeSequence,
-                        span: self.current_char_span(),
-                    });
-                }
+        let digits_start_location = self.location;
+        let mut value = 0;
+        let mut digits = 0;
 
-                match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
-                    Some(c) => Ok(c),
-                    None => Err(LexError {
-                        raw: RawLexError::InvalidUnicodeEscapeSequence,
-                        span: self.current_char_span(),
-                    }),
-                }
+        while let Some(digit) = self.current.to_digit(16) {
+            if digits == max_digits {
+                return Err(LexError {
+                    raw: RawLexError::ExpectedCloseBracketInUnicodeEscapeSequence,
+                    span: self.current_char_span(),
+                });
             }
-            'x' => {
-                self.advance();
 
-                if self.current != '{' {
-                    return Err(LexError {
-                        raw: RawLexError::ExpectedOpenBracketInByteEscapeSequence,
-                        span: self.current_char_span(),
-                    });
-                }
+            value = value * 16 + digit;
+            digits += 1;
 
-                self.advance();
+            self.advance();
+        }
 
-                let mut buffer = String::new();
+        if digits == 0 {
+            return Err(LexError {
+                raw: RawLexError::ExpectedDigitInUnicodeEscapeSequence,
+                span: self.current_char_span(),
+            });
+        }
 
-                for _ in 0..2 {
-                    if !self.current.is_ascii_hexdigit() {
-                        return Err(LexError {
-                            raw: RawLexError::ExpectedDigitInByteEscapeSequence,
-                            span: self.current_char_span(),
-                        });
-                    }
+        if self.current != '}' {
+            return Err(LexError {
+                raw: RawLexError::ExpectedCloseBracketInUnicodeEscapeSequence,
+                span: self.current_char_span(),
+            });
+        }
 
-                    buffer.push(self.current);
-                    self.advance();
-                }
+        if (0xD800..=0xDFFF).contains(&value) {
+            return Err(LexError {
+                raw: RawLexError::SurrogateInUnicodeEscape,
+                span: self.span_from(digits_start_location),
+            });
+        }
 
-                if self.current != '}' {
-                    return Err(LexError {
-                        raw: RawLexError::ExpectedCloseBracketInByteEscapeSequence,
-                        span: self.current_char_span(),
-                    });
-                }
+        char::from_u32(value).ok_or_else(|| LexError {
+            raw: RawLexError::InvalidUnicodeEscapeSequence,
+            span: self.span_from(digits_start_location),
+        })
+    }
 
-                match char::from_u32(u32::from_str_radix(&buffer, 16).expect("Invalid hex")) {
-                    Some(c) => Ok(c),
-                    None => Err(LexError {
-                        raw: RawLexError::InvalidByteEscapeSequence,
-                        span: Span {
-                            start: self.location - 4,
-                            end: self.location,
-                        },
-                    }),
-                }
-            }
-            _ => Err(LexError {
-                raw: RawLexError::UnknownEscapeSequence,
-                span: self.current_char_span(),
-            }),
-        };
+    /// Parses an escape sequence inside of a byte literal or a byte string.
+    ///
+    /// Only escapes resulting in ASCII characters and `\x{NN}` are allowed.
+    fn eat_byte_escape(&mut self) -> Result<u8, LexError> {
+        let start_location = self.location;
 
-        self.advance();
+        if self.next == 'x' {
+            self.advance(); // `\`
 
-        r
+            let byte = self.eat_hex_byte()?;
+            self.advance(); // `}`
+
+            return Ok(byte);
+        }
+
+        let c = self.eat_escape()?;
+
+        if c.is_ascii() {
+            #[allow(clippy::cast_possible_truncation)]
+            Ok(c as u8)
+        } else {
+            Err(LexError {
+                raw: RawLexError::NonAsciiByteInByteLiteral,
+                span: self.span_from(start_location),
+            })
+        }
     }
 
     /// Parses a char literal.
@@ -432,15 +699,27 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         self.advance();
 
         let mut size = 0;
+        let mut excess_start_location = None;
+        let mut tab_location = None;
 
         while self.current != '\'' {
-            if self.current == '\n' || self.eof() {
+            if is_line_break(self.current) || self.eof() {
                 return Token {
                     raw: RawToken::Error(RawLexError::UnterminatedCharLiteral),
                     span: self.span_from(start_location),
                 };
             }
 
+            if size == 1 {
+                excess_start_location = Some(self.location);
+            }
+
+            if self.escape_at_eof() {
+                // The literal is reported as unterminated below.
+                self.advance();
+                continue;
+            }
+
             if self.current == '\\' {
                 let e = self.eat_escape();
 
@@ -456,6 +735,10 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
                     }
                 }
             } else {
+                if self.current == '\t' && tab_location.is_none() {
+                    tab_location = Some(self.location);
+                }
+
                 self.scanned_char = self.current;
                 self.advance();
             }
@@ -463,6 +746,89 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             size += 1;
         }
 
+        let end_location = self.location;
+
+        self.advance();
+
+        if let Some(location) = tab_location {
+            return Token {
+                raw: RawToken::Error(RawLexError::UnescapedTabInCharLiteral),
+                span: Span {
+                    start: location,
+                    end: location + 1,
+                },
+            };
+        }
+
+        if let Some(location) = excess_start_location {
+            return Token {
+                raw: RawToken::Error(RawLexError::MoreThanOneCharInCharLiteral),
+                span: Span {
+                    start: location,
+                    end: end_location,
+                },
+            };
+        }
+
+        if size == 0 {
+            return Token {
+                raw: RawToken::Error(RawLexError::EmptyCharLiteral),
+                span: self.span_from(start_location),
+            };
+        }
+
+        Token {
+            raw: RawToken::CharLiteral,
+            span: self.span_from(start_location),
+        }
+    }
+
+    /// Parses a byte literal.
+    fn eat_byte_char(&mut self) -> Token {
+        self.scanned_bytes.clear();
+        let start_location = self.location;
+
+        self.advance_twice(); // `b'`
+
+        let mut size = 0;
+
+        while self.current != '\'' {
+            if is_line_break(self.current) || self.eof() {
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnterminatedCharLiteral),
+                    span: self.span_from(start_location),
+                };
+            }
+
+            if self.escape_at_eof() {
+                // The literal is reported as unterminated below.
+                self.advance();
+                continue;
+            }
+
+            if self.current == '\\' {
+                match self.eat_byte_escape() {
+                    Ok(b) => {
+                        self.scanned_bytes.push(b);
+                    }
+                    Err(e) => {
+                        return Token {
+                            span: e.span,
+                            raw: RawToken::from(e.raw),
+                        }
+                    }
+                }
+            } else if self.current.is_ascii() {
+                #[allow(clippy::cast_possible_truncation)]
+                self.scanned_bytes.push(self.current as u8);
+                self.advance();
+            } else {
+                return self.advance_with(RawToken::Error(RawLexError::NonAsciiByteInByteLiteral));
+            }
+
+            size += 1;
+        }
+
         self.advance();
 
         match size {
@@ -482,25 +848,208 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         }
 
         Token {
-            raw: RawToken::CharLiteral,
+            raw: RawToken::ByteLiteral,
+            span: self.span_from(start_location),
+        }
+    }
+
+    /// Parses a byte string literal.
+    fn eat_byte_string(&mut self) -> Token {
+        self.scanned_bytes.clear();
+        let start_location = self.location;
+
+        self.advance_twice(); // `b"`
+
+        while !self.eof() && !is_line_break(self.current) {
+            let c = self.current;
+
+            if c == '"' {
+                break;
+            }
+
+            if c < ' ' {
+                let location = self.location;
+                self.advance();
+
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnescapedControlCharacterInString),
+                    span: self.span_from(location),
+                };
+            }
+
+            if self.escape_at_eof() {
+                // The literal is reported as unterminated below.
+                self.advance();
+                continue;
+            }
+
+            if c == '\\' {
+                match self.eat_byte_escape() {
+                    Ok(b) => {
+                        self.scanned_bytes.push(b);
+                    }
+                    Err(e) => {
+                        return Token {
+                            span: e.span,
+                            raw: RawToken::from(e.raw),
+                        }
+                    }
+                }
+            } else if c.is_ascii() {
+                #[allow(clippy::cast_possible_truncation)]
+                self.scanned_bytes.push(c as u8);
+                self.advance();
+            } else {
+                return self.advance_with(RawToken::Error(RawLexError::NonAsciiByteInByteLiteral));
+            }
+        }
+
+        if self.eof() || is_line_break(self.current) {
+            return Token {
+                raw: RawToken::Error(RawLexError::UnterminatedStringLiteral),
+                span: self.span_from(start_location),
+            };
+        }
+
+        self.advance();
+
+        Token {
+            raw: RawToken::ByteStringLiteral,
             span: self.span_from(start_location),
         }
     }
 
     /// Parses a string literal.
     fn eat_string(&mut self) -> Token {
+        let start_location = self.location;
+
+        self.advance(); // `"`
+
+        self.eat_string_contents(start_location)
+    }
+
+    /// Parses a multi-line string literal (`"""..."""`), which may contain
+    /// line breaks (`\r\n` is stored as `\n`). Escape sequences are
+    /// processed, but interpolation is not supported, so `${` is scanned as is.
+    fn eat_multiline_string(&mut self) -> Token {
         self.scanned_string.clear();
         let start_location = self.location;
 
-        self.advance();
+        for _ in 0..3 {
+            self.advance(); // `"`
+        }
 
-        while !self.eof() && self.current != '\n' {
+        while !self.source[self.location..].starts_with("\"\"\"") {
+            let c = self.current;
+
+            if self.eof() {
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnterminatedStringLiteral),
+                    span: self.span_from(start_location),
+                };
+            }
+
+            if is_line_break(c) {
+                if c == '\r' && self.next == '\n' {
+                    self.advance();
+                }
+
+                self.scanned_string.push('\n');
+                self.advance();
+                continue;
+            }
+
+            if c < ' ' {
+                let location = self.location;
+                self.advance();
+
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnescapedControlCharacterInString),
+                    span: self.span_from(location),
+                };
+            }
+
+            if self.escape_at_eof() {
+                // The literal is reported as unterminated above.
+                self.advance();
+                continue;
+            }
+
+            if c == '\\' {
+                match self.eat_escape() {
+                    Ok(c) => self.scanned_string.push(c),
+                    Err(e) => {
+                        return Token {
+                            span: e.span,
+                            raw: RawToken::from(e.raw),
+                        }
+                    }
+                }
+            } else {
+                self.scanned_string.push(c);
+                self.advance();
+            }
+        }
+
+        for _ in 0..3 {
+            self.advance(); // `"`
+        }
+
+        Token {
+            raw: RawToken::StringLiteral,
+            span: self.span_from(start_location),
+        }
+    }
+
+    /// Parses the rest of a string literal after the interpolated expression.
+    fn eat_string_continuation(&mut self) -> Token {
+        let start_location = self.location;
+
+        self.advance(); // `}`
+
+        self.eat_string_contents(start_location)
+    }
+
+    /// Parses contents of a string literal, until the closing `"` or the
+    /// start of an interpolation (`${`).
+    ///
+    /// See [`RawToken::StringFragment`] for more details.
+    fn eat_string_contents(&mut self, start_location: usize) -> Token {
+        self.scanned_string.clear();
+
+        while !self.eof() && !is_line_break(self.current) {
             let c = self.current;
 
             if c == '"' {
                 break;
             }
 
+            if c < ' ' {
+                let location = self.location;
+                self.advance();
+
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnescapedControlCharacterInString),
+                    span: self.span_from(location),
+                };
+            }
+
+            if c == '$' && self.next == '{' {
+                self.advance_twice();
+                self.interpolation_stack.push((start_location, 0));
+
+                return Token {
+                    raw: RawToken::StringFragment,
+                    span: self.span_from(start_location),
+                };
+            }
+
+            if self.escape_at_eof() {
+                // The literal is reported as unterminated below.
+                self.advance();
+                continue;
+            }
+
             if c == '\\' {
                 let e = self.eat_escape();
 
@@ -521,7 +1070,7 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             }
         }
 
-        if self.eof() || self.current == '\n' {
+        if self.eof() || is_line_break(self.current) {
             return Token {
                 raw: RawToken::Error(RawLexError::UnterminatedStringLiteral),
                 span: self.span_from(start_location),
@@ -536,6 +1085,68 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         }
     }
 
+    /// Returns `true` if the lexer is at the start of a raw string literal,
+    /// i.e. `r` followed by zero or more `#`s and `"`.
+    fn raw_string_start(&self) -> bool {
+        self.current == 'r'
+            && self.source[self.location + 1..]
+                .trim_start_matches('#')
+                .starts_with('"')
+    }
+
+    /// Parses a raw string literal (`r"..."` or `r#"..."#`).
+    ///
+    /// Escape sequences are not processed and the closing `"` must be
+    /// followed by the same amount of `#`s as the opening one.
+    fn eat_raw_string(&mut self) -> Token {
+        self.scanned_string.clear();
+        let start_location = self.location;
+
+        self.advance(); // `r`
+
+        let mut hashes = 0;
+
+        while self.current == '#' {
+            hashes += 1;
+            self.advance();
+        }
+
+        self.advance(); // `"`
+
+        loop {
+            if self.eof() {
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnterminatedRawStringLiteral),
+                    span: self.span_from(start_location),
+                };
+            }
+
+            if self.current == '"'
+                && self.source[self.location + 1..]
+                    .bytes()
+                    .take_while(|&b| b == b'#')
+                    .count()
+                    >= hashes
+            {
+                break;
+            }
+
+            self.scanned_string.push(self.current);
+            self.advance();
+        }
+
+        self.advance(); // `"`
+
+        for _ in 0..hashes {
+            self.advance();
+        }
+
+        Token {
+            raw: RawToken::RawStringLiteral,
+            span: self.span_from(start_location),
+        }
+    }
+
     /// Parses a wrapped identifier.
     fn eat_wrapped_id(&mut self) -> Token {
         let start_location = self.location;
@@ -543,7 +1154,7 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         self.advance();
 
         let name = &self.advance_while(start_location, |current, _| {
-            current.is_alphanumeric() || current == '_'
+            current != '`' && !is_line_break(current)
         })[1..];
 
         if self.current != '`' {
@@ -553,6 +1164,8 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             };
         }
 
+        self.advance();
+
         if name.is_empty() {
             return Token {
                 raw: RawToken::Error(RawLexError::EmptyWrappedIdentifier),
@@ -560,7 +1173,15 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             };
         }
 
-        self.advance();
+        // Reserved words are intentionally not checked here, so that
+        // `` `fun` `` can be used as an ordinary identifier.
+        let mut chars = name.chars();
+        if !chars.next().is_some_and(is_id_start) || !chars.all(is_id_continue) {
+            return Token {
+                raw: RawToken::Error(RawLexError::InvalidWrappedIdentifier),
+                span: self.span_from(start_location),
+            };
+        }
 
         self.scanned_identifier = self.interner.get_or_intern(name);
 
@@ -570,13 +1191,76 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         }
     }
 
+    /// Checks if the name after the current `'` is followed by another `'`,
+    /// in which case it is a character literal (`'a'`) rather than a loop
+    /// label (`'a`).
+    fn closing_quote_after_name(&self) -> bool {
+        let rest = self.source.get(self.location + 1..).unwrap_or_default();
+        let name_length = rest.find(|c| !is_id_continue(c)).unwrap_or(rest.len());
+
+        rest.get(name_length..)
+            .is_some_and(|rest| rest.starts_with('\''))
+    }
+
+    /// Parses a loop label (`'outer`). The label's name is stored in
+    /// [`Lexer::scanned_identifier`].
+    fn eat_label(&mut self) -> Token {
+        let start_location = self.location;
+        self.advance(); // `'`
+
+        let name = self.advance_while(self.location, |current, _| is_id_continue(current));
+        self.scanned_identifier = self.interner.get_or_intern(name);
+
+        Token {
+            raw: RawToken::Label,
+            span: self.span_from(start_location),
+        }
+    }
+
     /// Parses a usual comment (prefix is `//`).
     fn eat_comment(&mut self) -> Token {
         // first `/` character is already advanced
         let start_location = self.location - 1;
         self.advance();
 
-        self.advance_while(start_location + 2, |current, _| (current != '\n'));
+        let text = self.advance_while(start_location + 2, |current, _| !is_line_break(current));
+        self.scanned_comment = text.strip_prefix(' ').unwrap_or(text);
+
+        Token {
+            raw: RawToken::Comment,
+            span: self.span_from(start_location),
+        }
+    }
+
+    /// Parses a block comment (`/* ... */`). Block comments can be nested.
+    fn eat_block_comment(&mut self) -> Token {
+        let start_location = self.location;
+        self.advance_twice(); // `/*`
+
+        let mut depth = 1;
+
+        while depth > 0 {
+            if self.eof() {
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnterminatedBlockComment),
+                    span: self.span_from(start_location),
+                };
+            }
+
+            match (self.current, self.next) {
+                ('/', '*') => {
+                    depth += 1;
+                    self.advance_twice();
+                }
+                ('*', '/') => {
+                    depth -= 1;
+                    self.advance_twice();
+                }
+                _ => self.advance(),
+            }
+        }
+
+        self.scanned_comment = &self.source[start_location + 2..self.location - 2];
 
         Token {
             raw: RawToken::Comment,
@@ -595,7 +1279,8 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         let start_location = self.location - 1;
         self.advance_twice(); // `/` and (`!` or `/`)
 
-        self.advance_while(start_location + 3, |current, _| (current != '\n'));
+        let text = self.advance_while(start_location + 3, |current, _| !is_line_break(current));
+        self.scanned_comment = text.strip_prefix(' ').unwrap_or(text);
 
         Token {
             span: self.span_from(start_location),
@@ -612,7 +1297,12 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         let start_location = self.location;
         let name = self.advance_while(start_location, |current, _| is_id_continue(current));
 
-        if let Some(reserved) = RESERVED.get(name) {
+        let reserved = match self.keywords {
+            Some(keywords) => keywords.get(name),
+            None => RESERVED.get(name),
+        };
+
+        if let Some(reserved) = reserved {
             Token {
                 raw: *reserved,
                 span: self.span_from(start_location),
@@ -636,28 +1326,132 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
         }
     }
 
+    /// Drives the lexer to the end of file, collecting all the tokens
+    /// (except [`RawToken::EndOfFile`]) and errors separately.
+    ///
+    /// Error tokens are not included into the token stream, lexing
+    /// continues after the offending character.
+    #[must_use]
+    pub fn tokenize_all(mut self) -> (Vec<Token>, Vec<LexError>) {
+        let mut tokens = vec![];
+        let mut errors = vec![];
+
+        loop {
+            let start_location = self.location;
+            let token = self.next_token();
+
+            match token.raw {
+                RawToken::EndOfFile => break,
+                RawToken::Error(raw) => {
+                    errors.push(LexError {
+                        span: token.span,
+                        raw,
+                    });
+
+                    if self.location == start_location {
+                        self.advance();
+                    }
+                }
+                _ => tokens.push(token),
+            }
+        }
+
+        (tokens, errors)
+    }
+
+    /// Drives the lexer, feeding every token to `f` until the end of file
+    /// is reached or `f` returns [`ControlFlow::Break`]. Unlike
+    /// [`Lexer::tokenize_all`], tokens are not collected.
+    ///
+    /// The [`RawToken::EndOfFile`] token is passed to `f` before stopping.
+    /// Error tokens are passed to `f` as well, lexing continues after the
+    /// offending character.
+    pub fn for_each_token<F>(mut self, mut f: F)
+    where
+        F: FnMut(Token) -> ControlFlow<()>,
+    {
+        loop {
+            let start_location = self.location;
+            let token = self.next_token();
+
+            if f(token).is_break() || token.raw == RawToken::EndOfFile {
+                return;
+            }
+
+            if matches!(token.raw, RawToken::Error(..)) && self.location == start_location {
+                self.advance();
+            }
+        }
+    }
+
     /// Proceeds to the next token and returns it (see [top level documentation](../index.html) for more details).
     pub fn next_token(&mut self) -> Token {
+        match self.peeked.take() {
+            Some(token) => token,
+            None => self.scan_token(),
+        }
+    }
+
+    /// Returns the next token without consuming it, so that the following
+    /// [`Lexer::next_token`] call returns the same token.
+    ///
+    /// # Note
+    /// The token is scanned right away, so `scanned_*` buffers (identifier
+    /// symbol, string contents, etc.) correspond to the peeked token.
+    pub fn peek_token(&mut self) -> Token {
+        if let Some(token) = self.peeked {
+            return token;
+        }
+
+        let token = self.scan_token();
+        self.peeked = Some(token);
+        token
+    }
+
+    /// Scans the next token.
+    fn scan_token(&mut self) -> Token {
         self.eat_whitespaces();
 
         if unlikely(self.current == '\0') {
+            if let Some((start_location, _)) = self.interpolation_stack.pop() {
+                self.interpolation_stack.clear();
+
+                return Token {
+                    raw: RawToken::Error(RawLexError::UnterminatedInterpolation),
+                    span: self.span_from(start_location),
+                };
+            }
+
+            // EOF token has zero width and is located right after the end of the source.
             return Token {
                 raw: RawToken::EndOfFile,
-                span: self.current_char_span(),
+                span: Span {
+                    start: self.source.len(),
+                    end: self.source.len(),
+                },
             };
         }
 
         match (self.current, self.next) {
+            (':', ':') => self.advance_twice_with(Token![::]),
             (':', _) => self.advance_with(Token![:]),
             ('@', _) => self.advance_with(Token![@]),
 
+            ('"', '"') if self.source[self.location..].starts_with("\"\"\"") => {
+                self.eat_multiline_string()
+            }
             ('"', _) => self.eat_string(),
+            ('b', '"') => self.eat_byte_string(),
+            ('b', '\'') => self.eat_byte_char(),
+            ('r', '"' | '#') if self.raw_string_start() => self.eat_raw_string(),
+            ('\'', n) if is_id_start(n) && !self.closing_quote_after_name() => self.eat_label(),
             ('\'', _) => self.eat_char(),
             ('`', _) => self.eat_wrapped_id(),
 
             ('+', '+') => self.advance_twice_with(Token![++]),
             ('+', '=') => self.advance_twice_with(Token![+=]),
             ('+', _) => self.advance_with(Token![+]),
+            ('-', '>') => self.advance_twice_with(Token![->]),
             ('-', '-') => self.advance_twice_with(Token![--]),
             ('-', '=') => self.advance_twice_with(Token![-=]),
             ('-', _) => self.advance_with(Token![-]),
@@ -670,13 +1464,18 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             ('/', '/') => {
                 self.advance();
 
+                // `current` is the second `/`, `next` is the character after it.
                 match self.next {
                     '!' => self.eat_doc_comment(true),
-                    '/' => self.eat_doc_comment(false),
+                    // Four or more slashes start an ordinary comment.
+                    '/' if !self.source[self.location..].starts_with("///") => {
+                        self.eat_doc_comment(false)
+                    }
                     _ => self.eat_comment(),
                 }
             }
 
+            ('/', '*') => self.eat_block_comment(),
             ('/', '=') => self.advance_twice_with(Token![/=]),
             ('/', _) => self.advance_with(Token![/]),
             ('!', '=') => self.advance_twice_with(Token![!=]),
@@ -695,6 +1494,7 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             ('|', _) => self.advance_with(Token![|]),
             ('?', _) => self.advance_with(Token![?]),
             ('&', '&') => self.advance_twice_with(Token![&&]),
+            ('&', '=') => self.advance_twice_with(Token![&=]),
             ('&', _) => self.advance_with(Token![&]),
             ('^', '=') => self.advance_twice_with(Token![^=]),
             ('^', _) => self.advance_with(Token![^]),
@@ -703,14 +1503,47 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
             (')', _) => self.advance_with(Token![')']),
             ('[', _) => self.advance_with(Token!['[']),
             (']', _) => self.advance_with(Token![']']),
-            ('{', _) => self.advance_with(Token!['{']),
-            ('}', _) => self.advance_with(Token!['}']),
+            ('{', _) => {
+                if let Some((_, depth)) = self.interpolation_stack.last_mut() {
+                    *depth += 1;
+                }
+
+                self.advance_with(Token!['{'])
+            }
+            ('}', _) => match self.interpolation_stack.last_mut() {
+                Some((_, 0)) => {
+                    self.interpolation_stack.pop();
+                    self.eat_string_continuation()
+                }
+                Some((_, depth)) => {
+                    *depth -= 1;
+                    self.advance_with(Token!['}'])
+                }
+                None => self.advance_with(Token!['}']),
+            },
             (',', _) => self.advance_with(Token![,]),
             (';', _) => self.advance_with(Token![;]),
-            ('%', '=') => self.advance_with(Token![%=]),
+            ('%', '=') => self.advance_twice_with(Token![%=]),
             ('%', _) => self.advance_with(Token![%]),
 
-            ('.', '.') => self.advance_twice_with(Token![..]),
+            ('.', '.') => {
+                let start_location = self.location;
+                self.advance_twice();
+
+                if self.current == '=' {
+                    self.advance();
+
+                    Token {
+                        raw: Token![..=],
+                        span: self.span_from(start_location),
+                    }
+                } else {
+                    Token {
+                        raw: Token![..],
+                        span: self.span_from(start_location),
+                    }
+                }
+            }
 
             (c, n) => {
                 if number::decimal(c) || (c == '.' && number::decimal(n)) {
@@ -727,6 +1560,39 @@ impl<'source, 'interner> Lexer<'source, 'interner> {
     }
 }
 
+/// Scans the whole source text and returns all the tokens, including
+/// the [`RawToken::EndOfFile`] token at the end.
+///
+/// Comments are included into the result, so they must be filtered out
+/// by the caller if needed.
+///
+/// ```
+/// # use ry_lexer::tokenize;
+/// # use ry_ast::token::RawToken::{Identifier, Comment, EndOfFile};
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let tokens = tokenize("a // comment", &mut interner);
+///
+/// assert_eq!(
+///     tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+///     vec![Identifier, Comment, EndOfFile]
+/// );
+/// ```
+#[must_use]
+pub fn tokenize(source: &str, interner: &mut Interner) -> Vec<Token> {
+    let mut lexer = Lexer::new(source, interner);
+    let mut tokens = vec![];
+
+    loop {
+        let token = lexer.next_token();
+        tokens.push(token);
+
+        if token.raw.eof() {
+            return tokens;
+        }
+    }
+}
+
 /// True if `c` is a whitespace.
 const fn is_whitespace(c: char) -> bool {
     // Note that it is ok to hard-code the values, because
@@ -754,12 +1620,31 @@ const fn is_whitespace(c: char) -> bool {
     )
 }
 
+/// True if `c` starts a line break: `\n`, `\r\n` or a lone `\r` (old Mac OS
+/// line ending).
+const fn is_line_break(c: char) -> bool {
+    matches!(c, '\n' | '\r')
+}
+
 /// True if `c` is valid as a first character of an identifier.
+///
+/// ASCII characters are checked directly, because they dominate real code,
+/// and only non-ASCII ones are looked up in the Unicode tables.
 fn is_id_start(c: char) -> bool {
-    c == '_' || unicode_xid::UnicodeXID::is_xid_start(c)
+    if c.is_ascii() {
+        c == '_' || c.is_ascii_alphabetic()
+    } else {
+        unicode_xid::UnicodeXID::is_xid_start(c)
+    }
 }
 
 /// True if `c` is valid as a non-first character of an identifier.
+///
+/// See [`is_id_start`] for the ASCII fast path.
 fn is_id_continue(c: char) -> bool {
-    unicode_xid::UnicodeXID::is_xid_continue(c)
+    if c.is_ascii() {
+        c == '_' || c.is_ascii_alphanumeric()
+    } else {
+        unicode_xid::UnicodeXID::is_xid_continue(c)
+    }
 }
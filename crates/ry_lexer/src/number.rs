@@ -3,7 +3,7 @@ use std::char::from_u32;
 use ry_ast::token::{NumberKind, RawLexError, RawToken, Token};
 use ry_filesystem::span::Span;
 
-use crate::{is_id_start, Lexer};
+use crate::{is_id_continue, is_id_start, Lexer};
 
 /// True if `c` is a valid decimal digit.
 #[inline]
@@ -25,7 +25,7 @@ fn invalid_separator(string: &str) -> i32 {
     let bytes = string.as_bytes();
 
     if string.len() >= 2 && bytes[0] as char == '0' {
-        base = bytes[1] as char;
+        base = (bytes[1] as char).to_ascii_lowercase();
         if base == 'x' || base == 'o' || base == 'b' {
             d = '0';
             i = 2;
@@ -62,6 +62,23 @@ fn invalid_separator(string: &str) -> i32 {
     -1
 }
 
+/// Parses an integer literal, taking into account its radix prefix
+/// (`0x`, `0o` or `0b`) and digit separators.
+///
+/// Returns [`None`] if the value doesn't fit into [`u64`].
+fn parse_integer(literal: &str) -> Option<u64> {
+    let literal = literal.replace('_', "");
+
+    let (digits, radix) = match literal.get(..2).map(str::to_ascii_lowercase).as_deref() {
+        Some("0x") => (&literal[2..], 16),
+        Some("0o") => (&literal[2..], 8),
+        Some("0b") => (&literal[2..], 2),
+        _ => (literal.as_str(), 10),
+    };
+
+    u64::from_str_radix(digits, radix).ok()
+}
+
 impl Lexer<'_, '_> {
     pub(crate) fn eat_number(&mut self) -> Token {
         let start_location = self.location;
@@ -111,7 +128,9 @@ impl Lexer<'_, '_> {
             if self.current == '.' {
                 // 1.to_string() is parsed as:
                 // Int(1) Punct(Dot) Ident Punct(Lparen) ...
-                if is_id_start(self.next) {
+                // and 0..10 is parsed as:
+                // Int(0) Punct(DotDot) Int(10)
+                if is_id_start(self.next) || self.next == '.' {
                     break 'processing_float;
                 }
 
@@ -131,7 +150,7 @@ impl Lexer<'_, '_> {
 
             if digit_separator & 1 == 0 {
                 return Token {
-                    raw: RawToken::Error(RawLexError::HasNoDigits),
+                    raw: RawToken::Error(RawLexError::MissingDigitsAfterRadixPrefix),
                     span: self.span_from(start_location),
                 };
             }
@@ -160,18 +179,28 @@ impl Lexer<'_, '_> {
 
             if ds & 1 == 0 {
                 return Token {
-                    raw: RawToken::Error(RawLexError::ExponentHasNoDigits),
+                    raw: RawToken::Error(RawLexError::MissingExponentDigits),
                     span: self.span_from(start_location),
                 };
             }
         }
 
-        let string = &self.source[start_location..self.location];
+        let end_location = self.location;
+
+        // `3i` and `2.5i` are imaginary literals, while `3ix` is deliberately
+        // scanned as an integer literal followed by the identifier `ix`.
+        if base == 10 && self.current == 'i' && !is_id_continue(self.next) {
+            self.advance();
+
+            number_kind = NumberKind::Imaginary;
+        }
+
+        let string = &self.source[start_location..end_location];
 
         if let Some(location) = invalid_digit_location {
             if number_kind == NumberKind::Int {
                 return Token {
-                    raw: RawToken::Error(RawLexError::InvalidDigit),
+                    raw: RawToken::Error(RawLexError::InvalidDigitForRadix),
                     span: Span {
                         start: location,
                         end: location + 1,
@@ -183,10 +212,10 @@ impl Lexer<'_, '_> {
         let s = invalid_separator(string);
 
         if digit_separator & 2 != 0 && s >= 0 {
-            let separator_location =
-                TryInto::<usize>::try_into(s).expect("Invalid separator in Lexer::eat_number");
+            let separator_location = start_location
+                + TryInto::<usize>::try_into(s).expect("Invalid separator in Lexer::eat_number");
             return Token {
-                raw: RawToken::Error(RawLexError::UnderscoreMustSeparateSuccessiveDigits),
+                raw: RawToken::Error(RawLexError::InvalidDigitSeparator),
                 span: Span {
                     start: separator_location,
                     end: separator_location + 1,
@@ -195,14 +224,40 @@ impl Lexer<'_, '_> {
         }
 
         match number_kind {
-            NumberKind::Int => Token {
-                raw: RawToken::IntegerLiteral,
-                span: self.span_from(start_location),
-            },
+            NumberKind::Int => {
+                if let Some(value) = parse_integer(string) {
+                    self.scanned_integer = value;
+
+                    Token {
+                        raw: RawToken::IntegerLiteral,
+                        span: self.span_from(start_location),
+                    }
+                } else {
+                    Token {
+                        raw: RawToken::Error(RawLexError::IntegerLiteralOverflow),
+                        span: self.span_from(start_location),
+                    }
+                }
+            }
             NumberKind::Float => Token {
                 raw: RawToken::FloatLiteral,
                 span: self.span_from(start_location),
             },
+            NumberKind::Imaginary => {
+                if let Ok(value) = string.replace('_', "").parse::<f64>() {
+                    self.scanned_imaginary = value;
+
+                    Token {
+                        raw: RawToken::ImaginaryLiteral,
+                        span: self.span_from(start_location),
+                    }
+                } else {
+                    Token {
+                        raw: RawToken::Error(RawLexError::NumberParseError),
+                        span: self.span_from(start_location),
+                    }
+                }
+            }
             NumberKind::Invalid => {
                 unreachable!()
             }
@@ -0,0 +1,239 @@
+//! Defines [`TriviaLexer`], a lexer, that preserves whitespace and comments,
+//! that are usually skipped by [`Lexer`], for tools like formatters, that need
+//! to reproduce the source text exactly.
+
+use std::mem;
+
+use ry_ast::token::{RawToken, Token};
+use ry_filesystem::span::Span;
+use ry_interner::Interner;
+
+use crate::{is_whitespace, Lexer};
+
+/// Kind of a [`Trivia`] piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    /// Sequence of whitespace characters (see [`Lexer`] for the full list).
+    Whitespace,
+    /// Regular comment (not a doc comment, doc comments are tokens).
+    Comment,
+    /// Shebang line (`#!/usr/bin/env ry`) at the very beginning of the source.
+    Shebang,
+    /// Text, that the lexer skipped, while recovering from an error.
+    Skipped,
+}
+
+/// Piece of source text, that has no meaning for the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trivia {
+    /// Kind of the trivia.
+    pub kind: TriviaKind,
+    /// Location of the trivia in the source text.
+    pub span: Span,
+}
+
+/// Token with trivia attached to it.
+///
+/// Trailing trivia is everything after the token up to (not including) the
+/// next line break. Everything else before the next token becomes its leading
+/// trivia. So in:
+///
+/// ```txt
+/// foo // comment
+///     bar
+/// ```
+///
+/// ` ` and `// comment` are trailing trivia of `foo`, and line break with
+/// the indentation is leading trivia of `bar`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenWithTrivia {
+    /// Trivia before the token.
+    pub leading_trivia: Vec<Trivia>,
+    /// The token itself.
+    pub token: Token,
+    /// Trivia after the token on the same line.
+    pub trailing_trivia: Vec<Trivia>,
+}
+
+/// Lexer, that attaches whitespace and comments to tokens.
+///
+/// The source text can be reproduced by concatenating leading trivia, token
+/// and trailing trivia of every token (including [`EndOfFile`], that holds
+/// whitespace and comments at the end of the source).
+///
+/// ```
+/// # use ry_lexer::trivia::TriviaLexer;
+/// # use ry_ast::token::RawToken;
+/// # use ry_interner::Interner;
+/// let mut interner = Interner::default();
+/// let mut lexer = TriviaLexer::new("foo // comment\n", &mut interner);
+///
+/// let foo = lexer.next_token();
+/// assert_eq!(foo.token.raw, RawToken::Identifier);
+/// assert_eq!(foo.trailing_trivia.len(), 2);
+///
+/// let eof = lexer.next_token();
+/// assert_eq!(eof.token.raw, RawToken::EndOfFile);
+/// assert_eq!(eof.leading_trivia.len(), 1);
+/// ```
+///
+/// [`EndOfFile`]: ry_ast::token::RawToken::EndOfFile
+#[derive(Debug)]
+pub struct TriviaLexer<'source, 'interner> {
+    /// Content of the file being scanned.
+    source: &'source str,
+
+    /// Underlying lexer.
+    pub lexer: Lexer<'source, 'interner>,
+
+    /// Location right after the last scanned token or trivia.
+    location: usize,
+
+    /// Trivia, that was scanned, but not yet attached to any token.
+    trivia: Vec<Trivia>,
+    /// Token, that follows [`TriviaLexer::trivia`].
+    next: Option<Token>,
+}
+
+impl<'source, 'interner> TriviaLexer<'source, 'interner> {
+    /// Creates a new trivia preserving lexer.
+    #[must_use]
+    pub fn new(source: &'source str, interner: &'interner mut Interner) -> Self {
+        Self {
+            source,
+            lexer: Lexer::new(source, interner),
+            location: 0,
+            trivia: vec![],
+            next: None,
+        }
+    }
+
+    /// Proceeds to the next token and returns it with its trivia.
+    /// After [`EndOfFile`] is reached, the lexer keeps returning it
+    /// without any trivia.
+    ///
+    /// [`EndOfFile`]: ry_ast::token::RawToken::EndOfFile
+    pub fn next_token(&mut self) -> TokenWithTrivia {
+        let token = match self.next.take() {
+            Some(token) => token,
+            None => self.scan_trivia(),
+        };
+        let leading_trivia = mem::take(&mut self.trivia);
+
+        if token.raw == RawToken::EndOfFile {
+            return TokenWithTrivia {
+                leading_trivia,
+                token,
+                trailing_trivia: vec![],
+            };
+        }
+
+        self.next = Some(self.scan_trivia());
+
+        TokenWithTrivia {
+            leading_trivia,
+            token,
+            trailing_trivia: self.split_trailing_trivia(),
+        }
+    }
+
+    /// Scans trivia into [`TriviaLexer::trivia`] and returns the first token,
+    /// that is not trivia.
+    fn scan_trivia(&mut self) -> Token {
+        loop {
+            let token = self.lexer.next_token();
+            self.push_gap(token.span.start);
+
+            if token.raw != RawToken::Comment {
+                // Zero width end of file token must not move the location
+                // past the end of the source.
+                self.location = self.location.max(token.span.end);
+                return token;
+            }
+
+            self.trivia.push(Trivia {
+                kind: TriviaKind::Comment,
+                span: token.span,
+            });
+            self.location = token.span.end;
+        }
+    }
+
+    /// Records the text, that the underlying lexer skipped before `end`, as
+    /// trivia.
+    fn push_gap(&mut self, end: usize) {
+        while self.location < end {
+            let start = self.location;
+            let rest = &self.source[start..end];
+
+            let (kind, length) = if start == 0 && rest.starts_with("#!") && !rest.starts_with("#![")
+            {
+                (
+                    TriviaKind::Shebang,
+                    rest.find(['\n', '\r']).unwrap_or(rest.len()),
+                )
+            } else if rest.starts_with(is_whitespace) {
+                (
+                    TriviaKind::Whitespace,
+                    rest.find(|c| !is_whitespace(c)).unwrap_or(rest.len()),
+                )
+            } else {
+                (
+                    TriviaKind::Skipped,
+                    rest.find(is_whitespace).unwrap_or(rest.len()),
+                )
+            };
+
+            self.location += length;
+            self.trivia.push(Trivia {
+                kind,
+                span: Span {
+                    start,
+                    end: self.location,
+                },
+            });
+        }
+    }
+
+    /// Removes trivia, that precedes the first line break, from
+    /// [`TriviaLexer::trivia`] and returns it.
+    fn split_trailing_trivia(&mut self) -> Vec<Trivia> {
+        let mut trailing_trivia = vec![];
+        let mut rest = mem::take(&mut self.trivia).into_iter();
+
+        for trivia in rest.by_ref() {
+            let text = &self.source[trivia.span.start..trivia.span.end];
+
+            if trivia.kind == TriviaKind::Whitespace {
+                if let Some(line_break) = text.find(['\n', '\r']) {
+                    let split = trivia.span.start + line_break;
+
+                    if line_break > 0 {
+                        trailing_trivia.push(Trivia {
+                            kind: TriviaKind::Whitespace,
+                            span: Span {
+                                start: trivia.span.start,
+                                end: split,
+                            },
+                        });
+                    }
+
+                    self.trivia.push(Trivia {
+                        kind: TriviaKind::Whitespace,
+                        span: Span {
+                            start: split,
+                            end: trivia.span.end,
+                        },
+                    });
+                    break;
+                }
+            }
+
+            trailing_trivia.push(trivia);
+        }
+
+        self.trivia.extend(rest);
+
+        trailing_trivia
+    }
+}
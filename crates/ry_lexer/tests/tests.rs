@@ -1,6 +1,12 @@
 #[cfg(test)]
 mod tests {
-    use ry_ast::token::{RawLexError, RawToken::*};
+    use std::{collections::HashMap, ops::ControlFlow};
+
+    use ry_ast::token::{
+        Keyword, LexError, Punctuator, RawLexError, RawToken::*, Token, TokenCategory,
+    };
+    use ry_filesystem::span::Span;
+    use ry_lexer::trivia::{TokenWithTrivia, Trivia, TriviaKind, TriviaLexer};
 
     macro_rules! lexer_test {
         ($name:ident, $source:expr, $expected:pat) => {
@@ -15,7 +21,14 @@ mod tests {
 
     lexer_test!(identifier, "test", Identifier);
     lexer_test!(identifier2, "тест", Identifier);
+    lexer_test!(identifier3, "_тест_1", Identifier);
     lexer_test!(comment, "//test comment", Comment);
+    lexer_test!(block_comment, "/* a /* b */ c */", Comment);
+    lexer_test!(
+        block_comment2,
+        "/* a /* b */ c",
+        Error(RawLexError::UnterminatedBlockComment)
+    );
     lexer_test!(global_doc_comment, "///test comment", LocalDocComment);
     lexer_test!(local_doc_comment, "//!test comment", GlobalDocComment);
     lexer_test!(unexpected_char, "١", Error(RawLexError::UnexpectedChar));
@@ -30,6 +43,36 @@ mod tests {
         "\"test\n",
         Error(RawLexError::UnterminatedStringLiteral)
     );
+    lexer_test!(
+        unterminated_multiline_string,
+        "\"\"\"test\n\"\"",
+        Error(RawLexError::UnterminatedStringLiteral)
+    );
+    lexer_test!(
+        string_ending_in_backslash,
+        "\"test\\",
+        Error(RawLexError::UnterminatedStringLiteral)
+    );
+    lexer_test!(
+        byte_string_ending_in_backslash,
+        "b\"test\\",
+        Error(RawLexError::UnterminatedStringLiteral)
+    );
+    lexer_test!(
+        char_ending_in_backslash,
+        "'\\",
+        Error(RawLexError::UnterminatedCharLiteral)
+    );
+    lexer_test!(
+        byte_char_ending_in_backslash,
+        "b'\\",
+        Error(RawLexError::UnterminatedCharLiteral)
+    );
+    lexer_test!(
+        nul_escape,
+        "\"\\\0\"",
+        Error(RawLexError::UnknownEscapeSequence)
+    );
     lexer_test!(wrapped_id, "`test`", Identifier);
     lexer_test!(
         wrapped_id2,
@@ -41,6 +84,1092 @@ mod tests {
         "`test\n",
         Error(RawLexError::UnterminatedWrappedIdentifier)
     );
+    lexer_test!(wrapped_keyword, "`match`", Identifier);
+    lexer_test!(
+        wrapped_id4,
+        "`a b`",
+        Error(RawLexError::InvalidWrappedIdentifier)
+    );
+    lexer_test!(
+        wrapped_id5,
+        "`1a`",
+        Error(RawLexError::InvalidWrappedIdentifier)
+    );
+    lexer_test!(
+        wrapped_id6,
+        "``",
+        Error(RawLexError::EmptyWrappedIdentifier)
+    );
+    lexer_test!(raw_string, "r\"C:\\path\\n\"", RawStringLiteral);
+    lexer_test!(raw_string2, "r#\"say \"hi\"\"#", RawStringLiteral);
+    lexer_test!(
+        raw_string3,
+        "r#\"test\"",
+        Error(RawLexError::UnterminatedRawStringLiteral)
+    );
+    lexer_test!(byte_string, "b\"\\x{FF}test\\n\"", ByteStringLiteral);
+    lexer_test!(
+        byte_string2,
+        "b\"тест\"",
+        Error(RawLexError::NonAsciiByteInByteLiteral)
+    );
+    lexer_test!(
+        byte_string3,
+        "b\"\\u{1E41}\"",
+        Error(RawLexError::NonAsciiByteInByteLiteral)
+    );
+    lexer_test!(byte_char, "b'a'", ByteLiteral);
+    lexer_test!(
+        byte_char2,
+        "b'ы'",
+        Error(RawLexError::NonAsciiByteInByteLiteral)
+    );
+    lexer_test!(digit_separator, "1_000", IntegerLiteral);
+    lexer_test!(digit_separator2, "3.14_15", FloatLiteral);
+    lexer_test!(digit_separator3, "_1", Identifier);
+    lexer_test!(
+        digit_separator4,
+        "1_",
+        Error(RawLexError::InvalidDigitSeparator)
+    );
+    lexer_test!(
+        digit_separator5,
+        "1__0",
+        Error(RawLexError::InvalidDigitSeparator)
+    );
+    lexer_test!(hex, "0x1F_ff", IntegerLiteral);
+    lexer_test!(octal, "0o17", IntegerLiteral);
+    lexer_test!(binary, "0B1010", IntegerLiteral);
+    lexer_test!(binary2, "0b102", Error(RawLexError::InvalidDigitForRadix));
+    lexer_test!(hex3, "0X1_F", IntegerLiteral);
+    lexer_test!(octal2, "0O1_7", IntegerLiteral);
+    lexer_test!(binary3, "0B1_0", IntegerLiteral);
+    lexer_test!(
+        hex2,
+        "0x",
+        Error(RawLexError::MissingDigitsAfterRadixPrefix)
+    );
+    lexer_test!(exponent, "1e10", FloatLiteral);
+    lexer_test!(exponent2, "1.5e-3", FloatLiteral);
+    lexer_test!(exponent3, "2E+4", FloatLiteral);
+    lexer_test!(exponent4, "1e", Error(RawLexError::MissingExponentDigits));
+    lexer_test!(exponent5, "1e+", Error(RawLexError::MissingExponentDigits));
+    lexer_test!(
+        exponent6,
+        "1e+-3",
+        Error(RawLexError::MissingExponentDigits)
+    );
+    lexer_test!(max_integer, "18446744073709551615", IntegerLiteral);
+    lexer_test!(
+        integer_overflow,
+        "184467440737095516150",
+        Error(RawLexError::IntegerLiteralOverflow)
+    );
+    lexer_test!(
+        integer_overflow2,
+        "0x1_0000_0000_0000_0000",
+        Error(RawLexError::IntegerLiteralOverflow)
+    );
+    lexer_test!(escaped_interpolation, "\"\\${a}\"", StringLiteral);
     lexer_test!(small_u, "'\\u{1E41}'", CharLiteral);
     lexer_test!(big_u, "\"\\U{0010FFFF}\"", StringLiteral);
+    lexer_test!(short_u, "\"\\u{41}\"", StringLiteral);
+    lexer_test!(emoji_u, "'\\u{1F600}'", CharLiteral);
+    lexer_test!(
+        surrogate_u,
+        "'\\u{D800}'",
+        Error(RawLexError::SurrogateInUnicodeEscape)
+    );
+    lexer_test!(
+        out_of_range_u,
+        "'\\U{FFFFFFFF}'",
+        Error(RawLexError::InvalidUnicodeEscapeSequence)
+    );
+    lexer_test!(
+        too_long_u,
+        "'\\u{1234567}'",
+        Error(RawLexError::ExpectedCloseBracketInUnicodeEscapeSequence)
+    );
+    lexer_test!(
+        empty_u,
+        "'\\u{}'",
+        Error(RawLexError::ExpectedDigitInUnicodeEscapeSequence)
+    );
+
+    #[test]
+    fn multiline_string() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new(
+            "\"\"\"first \"line\"\r\n\\tsecond ${line}\"\"\" \"\"",
+            &mut interner,
+        );
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: StringLiteral,
+                span: Span { start: 0, end: 36 }
+            }
+        );
+        assert_eq!(lexer.scanned_string(), "first \"line\"\n\tsecond ${line}");
+        assert_eq!(lexer.next_token().raw, StringLiteral);
+        assert_eq!(lexer.scanned_string(), "");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn raw_string_contents() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("r##\"a\\n \"# b\"##", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, RawStringLiteral);
+        assert_eq!(lexer.scanned_string(), "a\\n \"# b");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn byte_string_contents() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("b\"a\\x{FF}\\n\" b'\\x{07}'", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, ByteStringLiteral);
+        assert_eq!(lexer.scanned_bytes(), vec![b'a', 0xFF, b'\n']);
+        assert_eq!(lexer.next_token().raw, ByteLiteral);
+        assert_eq!(lexer.scanned_bytes(), vec![0x07]);
+    }
+
+    #[test]
+    fn digit_separator_span() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("x 1__0", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::InvalidDigitSeparator),
+                span: Span { start: 4, end: 5 }
+            }
+        );
+    }
+
+    #[test]
+    fn radix_spans() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("0x1F 0b102", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: IntegerLiteral,
+                span: Span { start: 0, end: 4 }
+            }
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::InvalidDigitForRadix),
+                span: Span { start: 9, end: 10 }
+            }
+        );
+    }
+
+    #[test]
+    fn nested_block_comment() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("/* a /* b */ c */ d", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Comment,
+                span: Span { start: 0, end: 17 }
+            }
+        );
+        assert_eq!(lexer.next_token().raw, Identifier);
+    }
+
+    #[test]
+    fn string_interpolation() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("\"hello ${ {a} } and ${b}!\"", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: StringFragment,
+                span: Span { start: 0, end: 9 }
+            }
+        );
+        assert_eq!(lexer.scanned_string(), "hello ");
+        assert_eq!(lexer.next_token().raw, Punctuator(Punctuator::OpenBrace));
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(lexer.next_token().raw, Punctuator(Punctuator::CloseBrace));
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: StringFragment,
+                span: Span { start: 14, end: 22 }
+            }
+        );
+        assert_eq!(lexer.scanned_string(), " and ");
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: StringLiteral,
+                span: Span { start: 23, end: 26 }
+            }
+        );
+        assert_eq!(lexer.scanned_string(), "!");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn unterminated_string_interpolation() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("\"${a", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, StringFragment);
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::UnterminatedInterpolation),
+                span: Span { start: 0, end: 4 }
+            }
+        );
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn mixed_identifiers() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("abc_1 переменная x_тест2 _ 1x", &mut interner);
+
+        for (span, name) in [
+            (Span { start: 0, end: 5 }, "abc_1"),
+            (Span { start: 6, end: 26 }, "переменная"),
+            (Span { start: 27, end: 38 }, "x_тест2"),
+            (Span { start: 39, end: 40 }, "_"),
+        ] {
+            assert_eq!(
+                lexer.next_token(),
+                Token {
+                    raw: Identifier,
+                    span
+                }
+            );
+            assert_eq!(lexer.interner.resolve(lexer.scanned_identifier), Some(name));
+        }
+
+        assert_eq!(lexer.next_token().raw, IntegerLiteral);
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn eof_span() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: EndOfFile,
+                span: Span { start: 0, end: 0 }
+            }
+        );
+
+        let mut lexer = ry_lexer::Lexer::new("a тест ", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: EndOfFile,
+                span: Span { start: 11, end: 11 }
+            }
+        );
+
+        // EOF token has zero width, no matter how many times it is requested.
+        assert!(lexer.next_token().span.is_empty());
+    }
+
+    #[test]
+    fn crlf_comments() {
+        let mut interner = ry_interner::Interner::default();
+
+        for (lf, crlf) in [
+            ("//x\n", "//x\r\n"),
+            ("///x\n", "///x\r\n"),
+            ("//!x\n", "//!x\r\n"),
+        ] {
+            let mut lexer = ry_lexer::Lexer::new(lf, &mut interner);
+            let lf_token = lexer.next_token();
+
+            let mut lexer = ry_lexer::Lexer::new(crlf, &mut interner);
+            let crlf_token = lexer.next_token();
+
+            assert_eq!(lf_token, crlf_token);
+            assert_eq!(lexer.next_token().raw, EndOfFile);
+        }
+    }
+
+    #[test]
+    fn lone_carriage_return() {
+        let mut interner = ry_interner::Interner::default();
+
+        let mut lexer = ry_lexer::Lexer::new("// comment\rfoo", &mut interner);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Comment,
+                span: Span { start: 0, end: 10 }
+            }
+        );
+        assert_eq!(lexer.scanned_comment(), "comment");
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Identifier,
+                span: Span { start: 11, end: 14 }
+            }
+        );
+
+        let mut lexer = ry_lexer::Lexer::new("\"a\rfoo", &mut interner);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::UnterminatedStringLiteral),
+                span: Span { start: 0, end: 2 }
+            }
+        );
+        assert_eq!(lexer.next_token().raw, Identifier);
+
+        let mut lexer = ry_lexer::Lexer::new("\"\"\"a\rb\"\"\"", &mut interner);
+        assert_eq!(lexer.next_token().raw, StringLiteral);
+        assert_eq!(lexer.scanned_string(), "a\nb");
+    }
+
+    #[test]
+    fn tokenize_all() {
+        let mut interner = ry_interner::Interner::default();
+        let lexer = ry_lexer::Lexer::new("a ١ b 0b2 c", &mut interner);
+
+        let (tokens, errors) = lexer.tokenize_all();
+
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![Identifier, Identifier, Identifier]
+        );
+        assert_eq!(
+            errors,
+            vec![
+                LexError {
+                    raw: RawLexError::UnexpectedChar,
+                    span: Span { start: 2, end: 4 }
+                },
+                LexError {
+                    raw: RawLexError::InvalidDigitForRadix,
+                    span: Span { start: 9, end: 10 }
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn for_each_token_break() {
+        let mut interner = ry_interner::Interner::default();
+        let lexer = ry_lexer::Lexer::new("a b c d e", &mut interner);
+
+        let mut tokens = vec![];
+        lexer.for_each_token(|token| {
+            tokens.push(token.raw);
+
+            if tokens.len() == 3 {
+                ControlFlow::Break(())
+            } else {
+                ControlFlow::Continue(())
+            }
+        });
+
+        assert_eq!(tokens, vec![Identifier, Identifier, Identifier]);
+    }
+
+    #[test]
+    fn for_each_token_to_completion() {
+        let mut interner = ry_interner::Interner::default();
+        let lexer = ry_lexer::Lexer::new("a ١ b", &mut interner);
+
+        let mut tokens = vec![];
+        lexer.for_each_token(|token| {
+            tokens.push(token.raw);
+            ControlFlow::Continue(())
+        });
+
+        assert_eq!(
+            tokens,
+            vec![
+                Identifier,
+                Error(RawLexError::UnexpectedChar),
+                Identifier,
+                EndOfFile
+            ]
+        );
+    }
+
+    #[test]
+    fn custom_keywords() {
+        let keywords = HashMap::from([("fun", Keyword(Keyword::Fun))]);
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::with_keywords("fun match", &mut interner, &keywords);
+
+        assert_eq!(lexer.next_token().raw, Keyword(Keyword::Fun));
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(
+            lexer.interner.resolve(lexer.scanned_identifier),
+            Some("match")
+        );
+    }
+
+    #[test]
+    fn raw_token_display() {
+        for (token, expected) in [
+            (ry_ast::Token![**], "`**`"),
+            (ry_ast::Token![..=], "`..=`"),
+            (ry_ast::Token!['('], "`(`"),
+            (ry_ast::Token!['}'], "`}`"),
+            (ry_ast::Token![fun], "`fun`"),
+            (ry_ast::Token![in], "`in`"),
+            (TrueBoolLiteral, "`true`"),
+            (Identifier, "<identifier>"),
+            (IntegerLiteral, "<integer literal>"),
+            (StringLiteral, "<string literal>"),
+            (LocalDocComment, "<doc comment>"),
+            (Error(RawLexError::UnexpectedChar), "<error>"),
+            (EndOfFile, "<eof>"),
+        ] {
+            assert_eq!(token.to_string(), expected);
+        }
+    }
+
+    #[test]
+    fn token_categories() {
+        let mut interner = ry_interner::Interner::default();
+        let tokens = ry_lexer::tokenize(
+            "fun f(a: T) { a += 1.5; 'l: 'c' == \"s\" } // c\n/// d\n§",
+            &mut interner,
+        );
+
+        assert_eq!(
+            tokens
+                .iter()
+                .map(|token| token.raw.category())
+                .collect::<Vec<_>>(),
+            vec![
+                TokenCategory::Keyword,
+                TokenCategory::Identifier,
+                TokenCategory::Punctuation,
+                TokenCategory::Identifier,
+                TokenCategory::Punctuation,
+                TokenCategory::Identifier,
+                TokenCategory::Punctuation,
+                TokenCategory::Punctuation,
+                TokenCategory::Identifier,
+                TokenCategory::Operator,
+                TokenCategory::Literal,
+                TokenCategory::Punctuation,
+                TokenCategory::Identifier,
+                TokenCategory::Punctuation,
+                TokenCategory::Literal,
+                TokenCategory::Operator,
+                TokenCategory::Literal,
+                TokenCategory::Punctuation,
+                TokenCategory::Comment,
+                TokenCategory::Comment,
+                TokenCategory::Error,
+                TokenCategory::EndOfFile,
+            ]
+        );
+    }
+
+    #[test]
+    fn mixed_indentation() {
+        let source = "a\n\t b\n \tc\n\t\td\n    e f\t g\n \t\n";
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer =
+            ry_lexer::Lexer::new(source, &mut interner).with_mixed_indentation_check(true);
+
+        while lexer.next_token().raw != EndOfFile {}
+
+        assert_eq!(
+            lexer.mixed_indentation(),
+            [Span { start: 2, end: 4 }, Span { start: 6, end: 8 }]
+        );
+
+        let mut lexer = ry_lexer::Lexer::new(" \ta", &mut interner);
+        lexer.next_token();
+
+        assert!(lexer.mixed_indentation().is_empty());
+
+        let mut lexer =
+            ry_lexer::Lexer::new(" \ta", &mut interner).with_mixed_indentation_check(true);
+        lexer.next_token();
+
+        assert_eq!(lexer.mixed_indentation(), [Span { start: 0, end: 2 }]);
+    }
+
+    #[test]
+    fn shebang() {
+        let mut interner = ry_interner::Interner::default();
+        let (tokens, errors) =
+            ry_lexer::Lexer::new("#!/bin/ry\nfun main() {}", &mut interner).tokenize_all();
+
+        assert!(errors.is_empty());
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Keyword(Keyword::Fun),
+                Identifier,
+                Punctuator(Punctuator::OpenParent),
+                Punctuator(Punctuator::CloseParent),
+                Punctuator(Punctuator::OpenBrace),
+                Punctuator(Punctuator::CloseBrace),
+            ]
+        );
+        assert_eq!(tokens[0].span, Span { start: 10, end: 13 });
+
+        let (tokens, _) = ry_lexer::Lexer::new("a #!", &mut interner).tokenize_all();
+
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Identifier,
+                Punctuator(Punctuator::HashTag),
+                Punctuator(Punctuator::Bang)
+            ]
+        );
+    }
+
+    #[test]
+    fn byte_escape_span() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("\"ab\\x{4G}\"", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::ExpectedDigitInByteEscapeSequence),
+                span: Span { start: 3, end: 8 }
+            }
+        );
+
+        let mut lexer = ry_lexer::Lexer::new("\"\\x{41\"", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::ExpectedCloseBracketInByteEscapeSequence),
+                span: Span { start: 1, end: 7 }
+            }
+        );
+
+        let mut lexer = ry_lexer::Lexer::new("\"\\x{FF}\"", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, StringLiteral);
+        assert_eq!(lexer.scanned_string(), "\u{FF}");
+
+        let mut lexer = ry_lexer::Lexer::new("'\\x{41}'", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, CharLiteral);
+        assert_eq!(lexer.scanned_char, 'A');
+    }
+
+    #[test]
+    fn peek_token() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("foo \"bar\"", &mut interner);
+
+        let peeked = lexer.peek_token();
+        assert_eq!(lexer.peek_token(), peeked);
+
+        let symbol = lexer.scanned_identifier;
+        assert_eq!(lexer.next_token(), peeked);
+        assert_eq!(lexer.scanned_identifier, symbol);
+        assert_eq!(lexer.interner.resolve(symbol), Some("foo"));
+
+        assert_eq!(lexer.peek_token().raw, StringLiteral);
+        assert_eq!(lexer.next_token().raw, StringLiteral);
+        assert_eq!(lexer.scanned_string(), "bar");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn ranges() {
+        let mut interner = ry_interner::Interner::default();
+
+        let (tokens, _) = ry_lexer::Lexer::new("0..10", &mut interner).tokenize_all();
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                IntegerLiteral,
+                Punctuator(Punctuator::DotDot),
+                IntegerLiteral
+            ]
+        );
+
+        let (tokens, _) = ry_lexer::Lexer::new("0..=10", &mut interner).tokenize_all();
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                IntegerLiteral,
+                Punctuator(Punctuator::DotDotEq),
+                IntegerLiteral
+            ]
+        );
+        assert_eq!(tokens[1].span, Span { start: 1, end: 4 });
+    }
+
+    #[test]
+    fn arrow() {
+        let mut interner = ry_interner::Interner::default();
+
+        let (tokens, _) =
+            ry_lexer::Lexer::new("a -> b - c -= d => e", &mut interner).tokenize_all();
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Identifier,
+                Punctuator(Punctuator::Arrow),
+                Identifier,
+                Punctuator(Punctuator::Minus),
+                Identifier,
+                Punctuator(Punctuator::MinusEq),
+                Identifier,
+                Punctuator(Punctuator::FatArrow),
+                Identifier
+            ]
+        );
+        assert_eq!(tokens[1].span, Span { start: 2, end: 4 });
+    }
+
+    #[test]
+    fn imaginary() {
+        let mut interner = ry_interner::Interner::default();
+
+        let mut lexer = ry_lexer::Lexer::new("3i 2.5i 1_0e1i", &mut interner);
+        assert_eq!(lexer.next_token().raw, ImaginaryLiteral);
+        assert_eq!(lexer.scanned_imaginary, 3.0);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: ImaginaryLiteral,
+                span: Span { start: 3, end: 7 }
+            }
+        );
+        assert_eq!(lexer.scanned_imaginary, 2.5);
+        assert_eq!(lexer.next_token().raw, ImaginaryLiteral);
+        assert_eq!(lexer.scanned_imaginary, 100.0);
+
+        let (tokens, _) = ry_lexer::Lexer::new("3ix 0x1i", &mut interner).tokenize_all();
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![IntegerLiteral, Identifier, IntegerLiteral, Identifier]
+        );
+        assert_eq!(tokens[1].span, Span { start: 1, end: 3 });
+    }
+
+    #[test]
+    fn wrapped_keyword_symbol() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("`fun` `a b` c", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, Identifier);
+        let symbol = lexer.scanned_identifier;
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::InvalidWrappedIdentifier),
+                span: Span { start: 6, end: 11 }
+            }
+        );
+        assert_eq!(lexer.next_token().raw, Identifier);
+        assert_eq!(interner.resolve(symbol), Some("fun"));
+    }
+
+    #[test]
+    fn unescaped_control_character_in_string() {
+        let mut interner = ry_interner::Interner::default();
+
+        for source in ["\"a\tb\"", "\"a\u{1}b\"", "b\"\tb\""] {
+            assert_eq!(
+                ry_lexer::Lexer::new(source, &mut interner).next_token(),
+                Token {
+                    raw: Error(RawLexError::UnescapedControlCharacterInString),
+                    span: Span { start: 2, end: 3 }
+                }
+            );
+        }
+
+        assert_eq!(
+            ry_lexer::Lexer::new("\"a\r\nb\"", &mut interner)
+                .next_token()
+                .raw,
+            Error(RawLexError::UnterminatedStringLiteral)
+        );
+        assert_eq!(
+            ry_lexer::Lexer::new("\"a\\tb\"", &mut interner)
+                .next_token()
+                .raw,
+            StringLiteral
+        );
+    }
+
+    #[test]
+    fn labels() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("'outer: 'a' 'ab' 'b '", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Label,
+                span: Span { start: 0, end: 6 }
+            }
+        );
+        assert_eq!(
+            lexer.interner.resolve(lexer.scanned_identifier),
+            Some("outer")
+        );
+        assert_eq!(lexer.next_token().raw, Punctuator(Punctuator::Colon));
+
+        // Name followed by a closing quote is a character literal.
+        assert_eq!(lexer.next_token().raw, CharLiteral);
+        assert_eq!(lexer.scanned_char, 'a');
+        assert_eq!(
+            lexer.next_token().raw,
+            Error(RawLexError::MoreThanOneCharInCharLiteral)
+        );
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Label,
+                span: Span { start: 17, end: 19 }
+            }
+        );
+        assert_eq!(
+            lexer.next_token().raw,
+            Error(RawLexError::UnterminatedCharLiteral)
+        );
+    }
+
+    #[test]
+    fn char_literal_errors() {
+        let mut interner = ry_interner::Interner::default();
+
+        let mut lexer = ry_lexer::Lexer::new("'abc' d", &mut interner);
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Error(RawLexError::MoreThanOneCharInCharLiteral),
+                span: Span { start: 2, end: 4 }
+            }
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Identifier,
+                span: Span { start: 6, end: 7 }
+            }
+        );
+        assert_eq!(
+            ry_lexer::Lexer::new("'\t'", &mut interner).next_token(),
+            Token {
+                raw: Error(RawLexError::UnescapedTabInCharLiteral),
+                span: Span { start: 1, end: 2 }
+            }
+        );
+
+        let mut lexer = ry_lexer::Lexer::new("'\\t'", &mut interner);
+        assert_eq!(lexer.next_token().raw, CharLiteral);
+        assert_eq!(lexer.scanned_char, '\t');
+    }
+
+    #[test]
+    fn reset() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("\"unterminated ${a", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, StringFragment);
+        assert_eq!(lexer.peek_token().raw, Identifier);
+
+        lexer.reset("foo \"bar\"");
+
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: Identifier,
+                span: Span { start: 0, end: 3 }
+            }
+        );
+        assert_eq!(
+            lexer.next_token(),
+            Token {
+                raw: StringLiteral,
+                span: Span { start: 4, end: 9 }
+            }
+        );
+        assert_eq!(lexer.scanned_string_slice(), "bar");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+        assert!(interner.get("foo").is_some());
+    }
+
+    #[test]
+    fn comment_text() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new(
+            "// hello\n/// world\n//!  module\n/* a /* b */ c */\n//",
+            &mut interner,
+        );
+
+        assert_eq!(lexer.next_token().raw, Comment);
+        assert_eq!(lexer.scanned_comment(), "hello");
+        assert_eq!(lexer.next_token().raw, LocalDocComment);
+        assert_eq!(lexer.scanned_comment(), "world");
+        assert_eq!(lexer.next_token().raw, GlobalDocComment);
+        assert_eq!(lexer.scanned_comment(), " module");
+        assert_eq!(lexer.next_token().raw, Comment);
+        assert_eq!(lexer.scanned_comment(), " a /* b */ c ");
+        assert_eq!(lexer.next_token().raw, Comment);
+        assert_eq!(lexer.scanned_comment(), "");
+    }
+
+    #[test]
+    fn compound_assignment_operators() {
+        let mut interner = ry_interner::Interner::default();
+        let tokens = ry_lexer::tokenize("a &= b %= c", &mut interner);
+
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Identifier,
+                Punctuator(Punctuator::AndEq),
+                Identifier,
+                Punctuator(Punctuator::PercentEq),
+                Identifier,
+                EndOfFile,
+            ]
+        );
+        assert_eq!(tokens[3].span, Span { start: 7, end: 9 });
+    }
+
+    #[test]
+    fn location() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("foo  + bar", &mut interner);
+
+        assert_eq!(lexer.location(), 0);
+        assert_eq!(lexer.current_char(), 'f');
+
+        lexer.next_token();
+
+        assert_eq!(lexer.location(), 3);
+        assert_eq!(lexer.current_char(), ' ');
+
+        lexer.next_token();
+
+        assert_eq!(lexer.location(), 6);
+
+        lexer.next_token();
+        lexer.next_token();
+
+        assert_eq!(lexer.location(), 10);
+        assert_eq!(lexer.current_char(), '\0');
+    }
+
+    #[test]
+    fn colons() {
+        let mut interner = ry_interner::Interner::default();
+        let tokens = ry_lexer::tokenize("std::io a: b :::", &mut interner);
+
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Identifier,
+                Punctuator(Punctuator::ColonColon),
+                Identifier,
+                Identifier,
+                Punctuator(Punctuator::Colon),
+                Identifier,
+                Punctuator(Punctuator::ColonColon),
+                Punctuator(Punctuator::Colon),
+                EndOfFile,
+            ]
+        );
+        assert_eq!(tokens[1].span, Span { start: 3, end: 5 });
+    }
+
+    #[test]
+    fn comment_kinds() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("//!x\n///x\n//x\n////x", &mut interner);
+
+        assert_eq!(lexer.next_token().raw, GlobalDocComment);
+        assert_eq!(lexer.scanned_comment(), "x");
+        assert_eq!(lexer.next_token().raw, LocalDocComment);
+        assert_eq!(lexer.scanned_comment(), "x");
+        assert_eq!(lexer.next_token().raw, Comment);
+        assert_eq!(lexer.scanned_comment(), "x");
+        assert_eq!(lexer.next_token().raw, Comment);
+        assert_eq!(lexer.scanned_comment(), "//x");
+        assert_eq!(lexer.next_token().raw, EndOfFile);
+    }
+
+    #[test]
+    fn tokenize() {
+        let mut interner = ry_interner::Interner::default();
+        let tokens = ry_lexer::tokenize("foo + foo", &mut interner);
+
+        assert_eq!(
+            tokens.iter().map(|token| token.raw).collect::<Vec<_>>(),
+            vec![
+                Identifier,
+                Punctuator(Punctuator::Plus),
+                Identifier,
+                EndOfFile
+            ]
+        );
+        assert!(interner.get("foo").is_some());
+    }
+
+    #[test]
+    fn has_raw() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = ry_lexer::Lexer::new("fun main() {}", &mut interner);
+
+        assert!(lexer.next_token().has_raw(Keyword(Keyword::Fun)));
+        assert!(lexer.next_token().has_raw(Identifier));
+        assert!(lexer
+            .next_token()
+            .has_raw(Punctuator(Punctuator::OpenParent)));
+        assert!(!lexer
+            .next_token()
+            .has_raw(Punctuator(Punctuator::OpenParent)));
+    }
+
+    #[test]
+    fn raw_eq() {
+        let mut interner = ry_interner::Interner::default();
+        let tokens = ry_lexer::tokenize("a + b", &mut interner);
+
+        assert!(tokens[0].raw_eq(&tokens[2]));
+        assert_ne!(tokens[0], tokens[2]);
+        assert!(!tokens[0].raw_eq(&tokens[1]));
+        assert!(tokens[3].raw_eq(&Token {
+            raw: EndOfFile,
+            span: Span::default()
+        }));
+    }
+
+    /// Reproduces the source text from the tokens and the trivia, returned
+    /// by [`TriviaLexer`].
+    fn reproduce_with_trivia(source: &str) -> String {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = TriviaLexer::new(source, &mut interner);
+        let mut result = String::new();
+
+        let text = |span: Span| &source[span.start..span.end];
+
+        loop {
+            let token = lexer.next_token();
+
+            for trivia in token
+                .leading_trivia
+                .iter()
+                .chain(std::iter::once(&Trivia {
+                    kind: TriviaKind::Skipped,
+                    span: token.token.span,
+                }))
+                .chain(&token.trailing_trivia)
+            {
+                result.push_str(text(trivia.span));
+            }
+
+            if token.token.raw == EndOfFile {
+                return result;
+            }
+        }
+    }
+
+    #[test]
+    fn trivia_round_trip() {
+        for source in [
+            "",
+            "  \n\t ",
+            "#!/usr/bin/env ry\nfun main() {}\n",
+            "//! module\n/// item\nfun main() { // comment\n    /* block */ let a = 3;\r\n}\n\n",
+            "let s = \"hello ${name + \"${1}\"}!\"; // end",
+            "\"a\tb\" ١ 0b2 'ab' \r foo\u{2028}",
+        ] {
+            assert_eq!(reproduce_with_trivia(source), source);
+        }
+    }
+
+    #[test]
+    fn leading_and_trailing_trivia() {
+        let mut interner = ry_interner::Interner::default();
+        let mut lexer = TriviaLexer::new("foo // comment\n    bar  ", &mut interner);
+
+        assert_eq!(
+            lexer.next_token(),
+            TokenWithTrivia {
+                leading_trivia: vec![],
+                token: Token {
+                    raw: Identifier,
+                    span: Span { start: 0, end: 3 }
+                },
+                trailing_trivia: vec![
+                    Trivia {
+                        kind: TriviaKind::Whitespace,
+                        span: Span { start: 3, end: 4 }
+                    },
+                    Trivia {
+                        kind: TriviaKind::Comment,
+                        span: Span { start: 4, end: 14 }
+                    }
+                ]
+            }
+        );
+        assert_eq!(
+            lexer.next_token(),
+            TokenWithTrivia {
+                leading_trivia: vec![Trivia {
+                    kind: TriviaKind::Whitespace,
+                    span: Span { start: 14, end: 19 }
+                }],
+                token: Token {
+                    raw: Identifier,
+                    span: Span { start: 19, end: 22 }
+                },
+                trailing_trivia: vec![Trivia {
+                    kind: TriviaKind::Whitespace,
+                    span: Span { start: 22, end: 24 }
+                }]
+            }
+        );
+        assert_eq!(
+            lexer.next_token(),
+            TokenWithTrivia {
+                leading_trivia: vec![],
+                token: Token {
+                    raw: EndOfFile,
+                    span: Span { start: 24, end: 24 }
+                },
+                trailing_trivia: vec![]
+            }
+        );
+    }
 }
@@ -6,7 +6,7 @@ use ry_ast::{
     token::{LexError, Token},
     ItemKind,
 };
-use ry_diagnostics::{BuildDiagnostic, Diagnostic};
+use ry_diagnostics::{BuildDiagnostic, Diagnostic, DiagnosticCode};
 use ry_filesystem::span::Span;
 
 /// Represents list of expected tokens.
@@ -75,12 +75,6 @@ pub enum ParseDiagnostic {
         node: String,
     },
 
-    /// Integer overflow.
-    IntegerOverflowError {
-        /// Location of number when parsing which, overflow happened.
-        span: Span,
-    },
-
     /// Float overflow.
     FloatOverflowError {
         /// Location of number when parsing which, overflow happened.
@@ -96,6 +90,12 @@ pub enum ParseDiagnostic {
         context: UnnecessaryVisibilityQualifierContext,
     },
 
+    /// When a tuple index is not a plain decimal integer, e.g. `t.1e5` or `t.1_0`.
+    InvalidTupleIndexError {
+        /// Location of the index.
+        span: Span,
+    },
+
     /// When got EOF instead of close brace at the of the item.
     EOFInsteadOfCloseBrace {
         /// Type of item in which error occurred.
@@ -141,7 +141,7 @@ impl BuildDiagnostic for ParseDiagnostic {
             Self::LexError(error) =>
                 Diagnostic::error()
                     .with_message(error.raw.to_string())
-                    .with_code("E000")
+                    .with_code(DiagnosticCode::LexError.to_string())
                     .with_labels(vec![error.span.to_primary_label()]),
             Self::UnexpectedTokenError {
                 got,
@@ -150,23 +150,13 @@ impl BuildDiagnostic for ParseDiagnostic {
             } =>
                 Diagnostic::error()
                     .with_message(format!("unexpected {}", got.raw))
-                    .with_code("E001")
+                    .with_code(DiagnosticCode::UnexpectedToken.to_string())
                     .with_labels(vec![got.span.to_primary_label()
                         .with_message(format!("expected {expected} for {node}"))]),
-            Self::IntegerOverflowError { span } =>
-                Diagnostic::error()
-                    .with_message("unexpected integer overflow".to_owned())
-                    .with_code("E002")
-                    .with_labels(vec![span.to_primary_label()
-                        .with_message("error appeared when parsing this integer")])
-                    .with_notes(vec![
-                        "note: integer cannot exceed the maximum value of `u64` (u64.max() == 18_446_744_073_709_551_615)".to_owned(),
-                        "note: you can use exponent to do so, but be careful!".to_owned()
-                    ]),
             Self::FloatOverflowError { span } =>
                 Diagnostic::error()
                     .with_message("unexpected float overflow".to_owned())
-                    .with_code("E003")
+                    .with_code(DiagnosticCode::FloatOverflow.to_string())
                     .with_labels(vec![span.to_primary_label()
                         .with_message("error appeared when parsing this float literal")
                     ])
@@ -183,7 +173,7 @@ impl BuildDiagnostic for ParseDiagnostic {
 
                 Diagnostic::error()
                     .with_message("unnecessary visibility qualifier".to_owned())
-                    .with_code("E003")
+                    .with_code(DiagnosticCode::UnnecessaryVisibilityQualifier.to_string())
                     .with_labels(labels)
                     .with_notes(
                         match context {
@@ -202,10 +192,16 @@ impl BuildDiagnostic for ParseDiagnostic {
                         }
                     )
                 }
+            Self::InvalidTupleIndexError { span } =>
+                Diagnostic::error()
+                    .with_message("invalid tuple index".to_owned())
+                    .with_code(DiagnosticCode::InvalidTupleIndex.to_string())
+                    .with_labels(vec![span.to_primary_label()
+                        .with_message("expected a non-negative decimal integer")]),
             Self::EOFInsteadOfCloseBrace { item_kind, item_span, span } =>
                 Diagnostic::error()
                     .with_message("unexpected end of file".to_owned())
-                    .with_code("E001")
+                    .with_code(DiagnosticCode::UnexpectedEndOfFile.to_string())
                     .with_labels(vec![
                         item_span.to_primary_label()
                             .with_message(format!("happened when parsing this {}", item_kind.to_string())),
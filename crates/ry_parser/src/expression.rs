@@ -1,10 +1,14 @@
 use ry_ast::{
-    precedence::Precedence, token::RawToken, BinaryOperator, Expression, IdentifierAst,
-    LambdaFunctionParameter, MatchExpressionItem, PostfixOperator, PrefixOperator,
-    RawBinaryOperator, RawPostfixOperator, RawPrefixOperator, StructExpressionItem, Token,
+    precedence::{precedence_of, Associativity, Precedence},
+    token::RawToken,
+    BinaryOperator, Expression, GenericArgument, IdentifierAst, LambdaFunctionParameter,
+    MatchExpressionItem, Path, PostfixOperator, PrefixOperator, RawBinaryOperator,
+    RawPostfixOperator, RawPrefixOperator, Statement, StructExpressionItem, Token, Type, TypePath,
+    TypePathSegment,
 };
 use ry_diagnostics::BuildDiagnostic;
 use ry_filesystem::span::Span;
+use ry_interner::{symbols, Symbol};
 
 use crate::{
     diagnostics::ParseDiagnostic,
@@ -12,7 +16,7 @@ use crate::{
     literal::LiteralParser,
     macros::parse_list,
     pattern::PatternParser,
-    r#type::{GenericArgumentsParser, TypeParser},
+    r#type::{GenericArgumentParser, TypeParser},
     statement::StatementsBlockParser,
     Parse, ParseState,
 };
@@ -20,10 +24,18 @@ use crate::{
 #[derive(Default)]
 pub(crate) struct ExpressionParser {
     pub(crate) precedence: Precedence,
+    /// Set when parsing conditions of `if` and `while`, iterables of `for`
+    /// and scrutinees of `match`, where `{` always begins a block and never
+    /// a struct expression: `if a == B {}`. Parentheses, brackets and call
+    /// arguments reset it: `if (B { x: 1 }) == a {}`.
     pub(crate) ignore_struct: bool,
 }
 
-struct WhileExpressionParser;
+struct WhileExpressionParser {
+    pub(crate) label: Option<IdentifierAst>,
+}
+
+struct ForExpressionParser;
 
 struct MatchExpressionParser;
 
@@ -35,14 +47,25 @@ struct PrimaryExpressionParser {
     pub(crate) ignore_struct: bool,
 }
 
+struct GenericArgumentsOrIndexExpressionParser {
+    pub(crate) left: Expression,
+}
+
 struct GenericArgumentsExpressionParser {
     pub(crate) left: Expression,
+    /// Generic arguments, that were already parsed before the `,`
+    /// (see [`GenericArgumentsOrIndexExpressionParser`]).
+    pub(crate) generic_arguments: Vec<GenericArgument>,
 }
 
 struct PropertyAccessExpressionParser {
     pub(crate) left: Expression,
 }
 
+struct TupleFieldAccessExpressionParser {
+    pub(crate) left: Expression,
+}
+
 struct PrefixExpressionParser {
     pub(crate) ignore_struct: bool,
 }
@@ -51,6 +74,11 @@ struct PostfixExpressionParser {
     pub(crate) left: Expression,
 }
 
+struct RangeExpressionParser {
+    pub(crate) start: Option<Expression>,
+    pub(crate) ignore_struct: bool,
+}
+
 struct CastExpressionParser {
     pub(crate) left: Expression,
 }
@@ -89,12 +117,26 @@ impl Parse for ExpressionParser {
         }
         .parse(state)?;
 
-        while self.precedence < state.next_token.raw.to_precedence() {
+        // Right operand of a right-associative operator is parsed with the
+        // operator's own precedence, so operators of the same precedence must
+        // still be consumed here: `2 ** 3 ** 2` is `2 ** (3 ** 2)`.
+        while next_precedence(state).is_some_and(|p| {
+            self.precedence < p
+                || (self.precedence == p && p.associativity() == Associativity::Right)
+        }) {
             left = match state.next_token.raw {
+                _ if next_is_tuple_index(state) => {
+                    TupleFieldAccessExpressionParser { left }.parse(state)?
+                }
                 Token!['('] => CallExpressionParser { left }.parse(state)?,
                 Token![.] => PropertyAccessExpressionParser { left }.parse(state)?,
-                Token!['['] => GenericArgumentsExpressionParser { left }.parse(state)?,
+                Token!['['] => GenericArgumentsOrIndexExpressionParser { left }.parse(state)?,
                 Token![as] => CastExpressionParser { left }.parse(state)?,
+                Token![..] | Token![..=] => RangeExpressionParser {
+                    start: Some(left),
+                    ignore_struct: self.ignore_struct,
+                }
+                .parse(state)?,
                 Token!['{'] => {
                     if self.ignore_struct {
                         return Some(left);
@@ -126,7 +168,9 @@ impl Parse for WhileExpressionParser {
     type Output = Option<Expression>;
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
-        let start = state.next_token.span.start;
+        let start = self
+            .label
+            .map_or(state.next_token.span.start, |label| label.span.start);
         state.advance(); // `while`
 
         let condition = ExpressionParser {
@@ -139,12 +183,40 @@ impl Parse for WhileExpressionParser {
 
         Some(Expression::While {
             span: state.span_from(start),
+            label: self.label,
             condition: Box::new(condition),
             body,
         })
     }
 }
 
+impl Parse for ForExpressionParser {
+    type Output = Option<Expression>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        let start = state.next_token.span.start;
+        state.advance(); // `for`
+
+        let pattern = PatternParser.parse(state)?;
+        state.consume(Token![in], "for expression")?;
+
+        let iterable = ExpressionParser {
+            precedence: Precedence::Lowest,
+            ignore_struct: true,
+        }
+        .parse(state)?;
+
+        let body = StatementsBlockParser.parse(state)?;
+
+        Some(Expression::For {
+            span: state.span_from(start),
+            pattern,
+            iterable: Box::new(iterable),
+            body,
+        })
+    }
+}
+
 impl Parse for MatchExpressionParser {
     type Output = Option<Expression>;
 
@@ -174,9 +246,35 @@ impl Parse for MatchExpressionBlockParser {
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
         state.consume(Token!['{'], "match expression block")?;
 
-        let units = parse_list!(state, "match expression block", Token!['}'], {
-            MatchExpressionUnitParser.parse(state)
-        });
+        let mut units = vec![];
+
+        while state.next_token.raw != Token!['}'] {
+            let unit = MatchExpressionUnitParser.parse(state)?;
+
+            // `,` can be omitted after arms with block bodies:
+            // `match x { 1 => { .. } _ => { .. } }`.
+            let has_block =
+                unit.right.with_block() || matches!(unit.right, Expression::Match { .. });
+            units.push(unit);
+
+            match state.next_token.raw {
+                Token![,] => state.advance(),
+                Token!['}'] => break,
+                _ if has_block => {}
+                _ => {
+                    state.diagnostics.push(
+                        ParseDiagnostic::UnexpectedTokenError {
+                            got: state.next_token,
+                            expected: expected!(Token!['}'], Token![,]),
+                            node: "match expression block".to_owned(),
+                        }
+                        .build(),
+                    );
+
+                    return None;
+                }
+            }
+        }
 
         state.advance(); // `}`
 
@@ -191,7 +289,25 @@ impl Parse for MatchExpressionUnitParser {
         let left = PatternParser.parse(state)?;
         state.consume(Token![=>], "match expression unit")?;
 
-        let right = ExpressionParser::default().parse(state)?;
+        // Block-like bodies end right after their closing `}`, so that the
+        // next arm is not parsed as a part of the body, when `,` is omitted:
+        // `match x { 1 => { a } (b, c) => d }`.
+        let right = if matches!(
+            state.next_token.raw,
+            Token!['{']
+                | Token![if]
+                | Token![match]
+                | Token![while]
+                | Token![for]
+                | RawToken::Label
+        ) {
+            PrimaryExpressionParser {
+                ignore_struct: false,
+            }
+            .parse(state)?
+        } else {
+            ExpressionParser::default().parse(state)?
+        };
 
         Some(MatchExpressionItem { left, right })
     }
@@ -205,6 +321,7 @@ impl Parse for PrimaryExpressionParser {
             RawToken::IntegerLiteral
             | RawToken::FloatLiteral
             | RawToken::StringLiteral
+            | RawToken::RawStringLiteral
             | RawToken::CharLiteral
             | Token![true]
             | Token![false] => Some(Expression::Literal(LiteralParser.parse(state)?)),
@@ -220,10 +337,24 @@ impl Parse for PrimaryExpressionParser {
             Token!['('] => ParenthesizedOrTupleExpressionParser.parse(state),
             Token!['['] => ListExpressionParser.parse(state),
             Token!['{'] => StatementsBlockExpressionParser.parse(state),
-            Token![|] => LambdaExpressionParser.parse(state),
+            Token![|] | Token![||] => LambdaExpressionParser.parse(state),
+            Token![..] | Token![..=] => RangeExpressionParser {
+                start: None,
+                ignore_struct: self.ignore_struct,
+            }
+            .parse(state),
             Token![if] => IfExpressionParser.parse(state),
             Token![match] => MatchExpressionParser.parse(state),
-            Token![while] => WhileExpressionParser.parse(state),
+            Token![while] => WhileExpressionParser { label: None }.parse(state),
+            Token![for] => ForExpressionParser.parse(state),
+            RawToken::Label => {
+                let label = state.consume_label();
+
+                state.consume(Token![:], "labeled loop")?;
+                state.expect(Token![while], "labeled loop")?;
+
+                WhileExpressionParser { label }.parse(state)
+            }
             _ => {
                 if state.next_token.raw.prefix_operator() {
                     return PrefixExpressionParser {
@@ -235,19 +366,24 @@ impl Parse for PrimaryExpressionParser {
                     ParseDiagnostic::UnexpectedTokenError {
                         got: state.next_token,
                         expected: expected!(
-                            "integer literal",
-                            "float literal",
-                            "string literal",
-                            "char literal",
-                            "boolean literal",
+                            RawToken::IntegerLiteral,
+                            RawToken::FloatLiteral,
+                            RawToken::StringLiteral,
+                            RawToken::CharLiteral,
+                            "<boolean literal>",
                             Token![|],
+                            Token![||],
+                            Token![..],
+                            Token![..=],
                             Token!['('],
                             Token!['{'],
                             Token!['['],
-                            "identifier",
+                            RawToken::Identifier,
                             Token![if],
                             Token![while],
-                            Token![match]
+                            Token![for],
+                            Token![match],
+                            "label"
                         ),
                         node: "expression".to_owned(),
                     }
@@ -259,11 +395,161 @@ impl Parse for PrimaryExpressionParser {
     }
 }
 
+/// Both generic arguments (`into[uint32]`) and indexing (`list[0]`) use
+/// brackets, so the contents of brackets are parsed as an expression first,
+/// unless they start with a token, that can only begin a type (`&`, `dyn`).
+///
+/// Associated types (`foo[Item = T]`) and several comma separated arguments
+/// are always generic arguments. A single expression in brackets is treated
+/// as a generic argument, only when it looks like a type (see
+/// [`looks_like_type`]) and the brackets are followed by a call, a field
+/// access or a struct expression: `into[uint32](3)`, `List[T].new()`,
+/// `Point[T] { x: 1 }`. Otherwise it's an index: `list[i]`, `handlers[i](x)`.
+impl Parse for GenericArgumentsOrIndexExpressionParser {
+    type Output = Option<Expression>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        state.advance(); // `[`
+
+        if matches!(state.next_token.raw, Token![&] | Token![&&] | Token![dyn]) {
+            return GenericArgumentsExpressionParser {
+                left: self.left,
+                generic_arguments: vec![],
+            }
+            .parse(state);
+        }
+
+        let index = ExpressionParser::default().parse(state)?;
+
+        let generic_argument = match index {
+            Expression::Binary {
+                ref left,
+                operator:
+                    BinaryOperator {
+                        raw: RawBinaryOperator::Eq,
+                        ..
+                    },
+                ref right,
+                ..
+            } => match (left.as_ref(), expression_to_type(right)) {
+                (Expression::Identifier(name), Some(value)) => {
+                    Some(GenericArgument::AssociatedType { name: *name, value })
+                }
+                _ => None,
+            },
+            _ => expression_to_type(&index).map(GenericArgument::Type),
+        };
+
+        match (state.next_token.raw, generic_argument) {
+            (Token![']'], Some(generic_argument @ GenericArgument::AssociatedType { .. })) => {
+                state.advance(); // `]`
+
+                Some(Expression::GenericArguments {
+                    span: state.span_from(self.left.span().start),
+                    left: Box::new(self.left),
+                    generic_arguments: vec![generic_argument],
+                })
+            }
+            (Token![']'], generic_argument) => {
+                state.advance(); // `]`
+
+                let span = state.span_from(self.left.span().start);
+                let left = Box::new(self.left);
+
+                match generic_argument {
+                    Some(GenericArgument::Type(ty))
+                        if matches!(
+                            state.next_token.raw,
+                            Token!['('] | Token![.] | Token!['{']
+                        ) && looks_like_type(&ty, state) =>
+                    {
+                        Some(Expression::GenericArguments {
+                            span,
+                            left,
+                            generic_arguments: vec![GenericArgument::Type(ty)],
+                        })
+                    }
+                    _ => Some(Expression::Index {
+                        span,
+                        left,
+                        index: Box::new(index),
+                    }),
+                }
+            }
+            (Token![,], Some(generic_argument)) => {
+                state.advance(); // `,`
+
+                GenericArgumentsExpressionParser {
+                    left: self.left,
+                    generic_arguments: vec![generic_argument],
+                }
+                .parse(state)
+            }
+            _ => {
+                state.diagnostics.push(
+                    ParseDiagnostic::UnexpectedTokenError {
+                        got: state.next_token,
+                        expected: expected!(Token![']']),
+                        node: "index expression".to_owned(),
+                    }
+                    .build(),
+                );
+
+                None
+            }
+        }
+    }
+}
+
+/// Returns `true`, if the type, that was converted from an expression in
+/// brackets, is spelled like a type rather than like a value: a primitive
+/// type (`uint32`), a name in `PascalCase` or a single capital letter
+/// (`Point`, `T`), or a path with generic arguments (`Option[T]`). Tuples,
+/// arrays and parenthesized types look like types, if their elements do.
+fn looks_like_type(ty: &Type, state: &ParseState<'_, '_, '_>) -> bool {
+    match ty {
+        Type::Path(path) => path.segments.last().is_some_and(|segment| {
+            segment.generic_arguments.is_some()
+                || segment
+                    .path
+                    .identifiers
+                    .last()
+                    .is_some_and(|identifier| is_type_name(identifier.symbol, state))
+        }),
+        Type::Tuple { element_types, .. } => element_types
+            .iter()
+            .all(|element| looks_like_type(element, state)),
+        Type::Parenthesized { inner, .. } => looks_like_type(inner, state),
+        Type::Array { element, .. } => looks_like_type(element, state),
+        _ => true,
+    }
+}
+
+/// Returns `true`, if the identifier is a primitive type name, a name in
+/// `PascalCase` or a single capital letter, but not a `SCREAMING_CASE`
+/// constant.
+fn is_type_name(symbol: Symbol, state: &ParseState<'_, '_, '_>) -> bool {
+    if (symbols::INT8..=symbols::CHAR).contains(&symbol) {
+        return true;
+    }
+
+    let name = state.lexer.interner.resolve_or(symbol, "");
+
+    name.starts_with(|c: char| c.is_ascii_uppercase())
+        && (name.len() == 1 || name.contains(|c: char| c.is_ascii_lowercase()))
+}
+
 impl Parse for GenericArgumentsExpressionParser {
     type Output = Option<Expression>;
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
-        let generic_arguments = GenericArgumentsParser.parse(state)?;
+        let mut generic_arguments = self.generic_arguments;
+
+        generic_arguments.extend(parse_list!(state, "generic arguments", Token![']'], {
+            GenericArgumentParser.parse(state)
+        }));
+
+        state.advance(); // `]`
 
         Some(Expression::GenericArguments {
             span: state.span_from(self.left.span().start),
@@ -273,6 +559,104 @@ impl Parse for GenericArgumentsExpressionParser {
     }
 }
 
+/// Converts an expression, that was parsed in place of a generic argument,
+/// into a type, e.g. `Option[T]` or `(A, b.C)`. Returns [`None`], if the
+/// expression cannot be a type.
+fn expression_to_type(expression: &Expression) -> Option<Type> {
+    match expression {
+        Expression::Parenthesized { span, inner } => Some(Type::Parenthesized {
+            span: *span,
+            inner: Box::new(expression_to_type(inner)?),
+        }),
+        Expression::Tuple { span, elements } => Some(Type::Tuple {
+            span: *span,
+            element_types: elements
+                .iter()
+                .map(expression_to_type)
+                .collect::<Option<_>>()?,
+        }),
+        Expression::List { span, elements } => match elements.as_slice() {
+            [element] => Some(Type::Array {
+                span: *span,
+                element: Box::new(expression_to_type(element)?),
+                size: None,
+            }),
+            _ => None,
+        },
+        _ => Some(Type::Path(TypePath {
+            span: expression.span(),
+            segments: expression_to_type_path_segments(expression)?,
+        })),
+    }
+}
+
+/// Converts an expression into segments of a type path: `a.b[T].c` becomes
+/// `a.b[T]` and `c`.
+fn expression_to_type_path_segments(expression: &Expression) -> Option<Vec<TypePathSegment>> {
+    match expression {
+        Expression::Identifier(identifier) => Some(vec![TypePathSegment {
+            span: identifier.span,
+            path: Path {
+                span: identifier.span,
+                identifiers: vec![*identifier],
+            },
+            generic_arguments: None,
+        }]),
+        Expression::FieldAccess { left, right, .. } => {
+            let mut segments = expression_to_type_path_segments(left)?;
+
+            match segments.last_mut() {
+                Some(segment) if segment.generic_arguments.is_none() => {
+                    segment.span.end = right.span.end;
+                    segment.path.span.end = right.span.end;
+                    segment.path.identifiers.push(*right);
+                }
+                _ => segments.push(TypePathSegment {
+                    span: right.span,
+                    path: Path {
+                        span: right.span,
+                        identifiers: vec![*right],
+                    },
+                    generic_arguments: None,
+                }),
+            }
+
+            Some(segments)
+        }
+        Expression::GenericArguments {
+            span,
+            left,
+            generic_arguments,
+        } => with_generic_arguments(left, *span, generic_arguments.clone()),
+        Expression::Index { span, left, index } => with_generic_arguments(
+            left,
+            *span,
+            vec![GenericArgument::Type(expression_to_type(index)?)],
+        ),
+        _ => None,
+    }
+}
+
+/// Converts `left` into segments of a type path and attaches generic
+/// arguments to the last one.
+fn with_generic_arguments(
+    left: &Expression,
+    span: Span,
+    generic_arguments: Vec<GenericArgument>,
+) -> Option<Vec<TypePathSegment>> {
+    let mut segments = expression_to_type_path_segments(left)?;
+    let last = segments.last_mut()?;
+
+    if last.generic_arguments.is_some() {
+        return None;
+    }
+
+    last.span.end = span.end;
+    last.generic_arguments = Some(generic_arguments);
+
+    Some(segments)
+}
+
 impl Parse for PropertyAccessExpressionParser {
     type Output = Option<Expression>;
 
@@ -289,6 +673,64 @@ impl Parse for PropertyAccessExpressionParser {
     }
 }
 
+/// Returns `true` if the next token is a number literal starting with `.`.
+///
+/// The lexer scans `.0` in `t.0` as a single float literal, so in a postfix
+/// position such literal is a tuple index rather than a separate operand.
+fn next_is_tuple_index(state: &ParseState<'_, '_, '_>) -> bool {
+    matches!(
+        state.next_token.raw,
+        RawToken::IntegerLiteral | RawToken::FloatLiteral | RawToken::ImaginaryLiteral
+    ) && state
+        .next_token
+        .span
+        .slice_or_empty(state.source)
+        .starts_with('.')
+}
+
+/// Returns precedence of the next token, treating tuple indices as property
+/// accesses.
+fn next_precedence(state: &ParseState<'_, '_, '_>) -> Option<Precedence> {
+    if next_is_tuple_index(state) {
+        Some(Precedence::Property)
+    } else {
+        precedence_of(&state.next_token.raw)
+    }
+}
+
+impl Parse for TupleFieldAccessExpressionParser {
+    type Output = Option<Expression>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        state.advance(); // `.0`
+
+        let span = Span {
+            start: state.current_token.span.start + 1,
+            end: state.current_token.span.end,
+        };
+        let digits = span.slice_or_empty(state.source);
+
+        let index = if digits.bytes().all(|b| b.is_ascii_digit()) {
+            digits.parse::<usize>().ok()
+        } else {
+            None
+        };
+
+        let Some(index) = index else {
+            state
+                .diagnostics
+                .push(ParseDiagnostic::InvalidTupleIndexError { span }.build());
+            return None;
+        };
+
+        Some(Expression::TupleFieldAccess {
+            span: state.span_from(self.left.span().start),
+            left: Box::new(self.left),
+            index,
+        })
+    }
+}
+
 impl Parse for PrefixExpressionParser {
     type Output = Option<Expression>;
 
@@ -307,10 +749,7 @@ impl Parse for PrefixExpressionParser {
         .parse(state)?;
 
         Some(Expression::Prefix {
-            span: Span {
-                start: operator_token.span.start,
-                end: inner.span().end,
-            },
+            span: operator_token.span.to(inner.span()),
             inner: Box::new(inner),
             operator,
         })
@@ -336,6 +775,67 @@ impl Parse for PostfixExpressionParser {
     }
 }
 
+impl Parse for RangeExpressionParser {
+    type Output = Option<Expression>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        state.advance(); // `..` or `..=`
+
+        let start = self
+            .start
+            .as_ref()
+            .map_or(state.current_token.span.start, |start| start.span().start);
+        let inclusive = state.current_token.raw == Token![..=];
+
+        // The end bound is optional in `a..` and `..`, so it is only parsed
+        // if the next token can begin an expression. `..=` always requires it.
+        let end = if inclusive || starts_expression(state.next_token.raw, self.ignore_struct) {
+            Some(Box::new(
+                ExpressionParser {
+                    precedence: Precedence::Range,
+                    ignore_struct: self.ignore_struct,
+                }
+                .parse(state)?,
+            ))
+        } else {
+            None
+        };
+
+        Some(Expression::Range {
+            span: state.span_from(start),
+            start: self.start.map(Box::new),
+            end,
+            inclusive,
+        })
+    }
+}
+
+/// Returns `true` if the token can begin an expression. If `ignore_struct` is
+/// set, `{` is not treated as one, because it begins a block instead (e.g. in
+/// `match 0.. {`).
+const fn starts_expression(token: RawToken, ignore_struct: bool) -> bool {
+    match token {
+        RawToken::IntegerLiteral
+        | RawToken::FloatLiteral
+        | RawToken::StringLiteral
+        | RawToken::RawStringLiteral
+        | RawToken::CharLiteral
+        | RawToken::Identifier
+        | Token![true]
+        | Token![false]
+        | Token!['(']
+        | Token!['[']
+        | Token![|]
+        | Token![||]
+        | Token![if]
+        | Token![match]
+        | Token![while]
+        | Token![for] => true,
+        Token!['{'] => !ignore_struct,
+        _ => token.prefix_operator(),
+    }
+}
+
 impl Parse for ParenthesizedOrTupleExpressionParser {
     type Output = Option<Expression>;
 
@@ -343,7 +843,7 @@ impl Parse for ParenthesizedOrTupleExpressionParser {
         let start = state.next_token.span.start;
         state.advance();
 
-        let elements = parse_list!(state, "parenthesized or tuple expression", Token![')'], {
+        let (elements, trailing_comma) = parse_list!(@trailing_comma state, "parenthesized or tuple expression", Token![')'], {
             ExpressionParser::default().parse(state)
         });
 
@@ -355,10 +855,7 @@ impl Parse for ParenthesizedOrTupleExpressionParser {
 
         match (elements.next(), elements.next()) {
             (Some(element), None) => {
-                if state
-                    .resolve_span(state.span_from(element.span().end))
-                    .contains(',')
-                {
+                if trailing_comma {
                     Some(Expression::Tuple {
                         span,
                         elements: vec![element],
@@ -483,7 +980,7 @@ impl Parse for BinaryExpressionParser {
             span: operator_token.span,
             raw: RawBinaryOperator::from(operator_token.raw),
         };
-        let precedence = state.next_token.raw.to_precedence();
+        let precedence = precedence_of(&operator_token.raw).unwrap_or_default();
 
         state.advance();
 
@@ -579,23 +1076,32 @@ impl Parse for LambdaExpressionParser {
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
         let start = state.next_token.span.start;
-        state.advance(); // `|`
 
-        let parameters = parse_list!(state, "function expression parameters", Token![|], {
-            let name = state.consume_identifier("function parameter name")?;
+        let parameters = if state.next_token.raw == Token![||] {
+            state.advance(); // `||`
 
-            let ty = if state.next_token.raw == Token![:] {
-                state.advance();
+            vec![]
+        } else {
+            state.advance(); // `|`
 
-                Some(TypeParser.parse(state)?)
-            } else {
-                None
-            };
+            let parameters = parse_list!(state, "function expression parameters", Token![|], {
+                let name = state.consume_identifier("function parameter name")?;
 
-            Some(LambdaFunctionParameter { name, ty })
-        });
+                let ty = if state.next_token.raw == Token![:] {
+                    state.advance();
 
-        state.advance();
+                    Some(TypeParser.parse(state)?)
+                } else {
+                    None
+                };
+
+                Some(LambdaFunctionParameter { name, ty })
+            });
+
+            state.advance(); // `|`
+
+            parameters
+        };
 
         let return_type = if state.next_token.raw == Token![:] {
             state.advance();
@@ -605,7 +1111,16 @@ impl Parse for LambdaExpressionParser {
             None
         };
 
-        let block = StatementsBlockParser.parse(state)?;
+        // Body without braces is only allowed, when return type is not specified:
+        // `|x| x + 1`, but `|x|: uint32 { x + 1 }`.
+        let block = if return_type.is_none() && state.next_token.raw != Token!['{'] {
+            vec![Statement::Expression {
+                expression: ExpressionParser::default().parse(state)?,
+                has_semicolon: false,
+            }]
+        } else {
+            StatementsBlockParser.parse(state)?
+        };
 
         Some(Expression::Lambda {
             span: state.span_from(start),
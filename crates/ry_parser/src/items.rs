@@ -1,7 +1,7 @@
 use ry_ast::{
-    token::RawToken, EnumItem, Function, FunctionParameter, IdentifierAst, Impl, Item, ItemKind,
-    JustFunctionParameter, SelfParameter, StructField, Token, TraitItem, TupleField, TypeAlias,
-    Visibility,
+    token::RawToken, Attribute, EnumItem, Function, FunctionParameter, IdentifierAst, Impl, Item,
+    ItemKind, JustFunctionParameter, SelfParameter, StructField, Token, TraitItem, TupleField,
+    TypeAlias, Visibility,
 };
 use ry_diagnostics::BuildDiagnostic;
 use ry_filesystem::span::Span;
@@ -10,8 +10,9 @@ use ry_interner::symbols;
 use crate::{
     diagnostics::{ParseDiagnostic, UnnecessaryVisibilityQualifierContext},
     expected,
+    expression::ExpressionParser,
     macros::parse_list,
-    path::ImportPathParser,
+    path::{ImportPathParser, PathParser},
     r#type::{GenericParametersParser, TypeBoundsParser, TypeParser, WhereClauseParser},
     statement::StatementsBlockParser,
     OptionalParser, Parse, ParseState, VisibilityParser,
@@ -19,10 +20,12 @@ use crate::{
 
 struct ImportParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
 }
 
 struct StructParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
@@ -34,6 +37,7 @@ struct StructFieldParser {
 
 struct FunctionParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
@@ -41,11 +45,19 @@ pub(crate) struct FunctionParameterParser;
 
 struct TypeAliasParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
+    pub(crate) docstring: Option<String>,
+}
+
+struct ConstOrStaticParser {
+    pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
 struct TraitParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
@@ -56,11 +68,13 @@ struct TraitItemsParser {
 
 struct ImplParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
 struct EnumParser {
     pub(crate) visibility: Visibility,
+    pub(crate) attributes: Vec<Attribute>,
     pub(crate) docstring: Option<String>,
 }
 
@@ -75,6 +89,12 @@ struct EnumItemStructParser {
     pub(crate) docstring: Option<String>,
 }
 
+struct AttributesParser;
+
+/// Parses a decorator (`@path`), which is a shorthand for an attribute
+/// without arguments (`#[path]`).
+struct DecoratorParser;
+
 pub(crate) struct ItemParser;
 
 pub(crate) struct ItemsParser;
@@ -98,7 +118,10 @@ impl Parse for ImportParser {
         let path = ImportPathParser.parse(state)?;
         state.consume(Token![;], "import")?;
 
-        Some(Item::Import { path })
+        Some(Item::Import {
+            attributes: self.attributes,
+            path,
+        })
     }
 }
 
@@ -164,6 +187,7 @@ impl Parse for StructParser {
                 generic_parameters,
                 where_clause,
                 fields,
+                attributes: self.attributes,
                 docstring: self.docstring,
             })
         } else if state.next_token.raw == Token!['('] {
@@ -191,6 +215,7 @@ impl Parse for StructParser {
                 generic_parameters,
                 where_clause,
                 fields,
+                attributes: self.attributes,
                 docstring: self.docstring,
             })
         } else {
@@ -218,7 +243,19 @@ impl Parse for FunctionParameterParser {
 
         let ty = TypeParser.parse(state)?;
 
-        Some(JustFunctionParameter { name, ty })
+        let default_value = if state.next_token.raw == Token![=] {
+            state.advance();
+
+            Some(ExpressionParser::default().parse(state)?)
+        } else {
+            None
+        };
+
+        Some(JustFunctionParameter {
+            name,
+            ty,
+            default_value,
+        })
     }
 }
 
@@ -255,6 +292,12 @@ impl Parse for FunctionParser {
             }
         });
 
+        // `parse_list!` has already reported the unexpected token, so bail out
+        // here instead of producing follow-up errors for the rest of the function.
+        if state.next_token.raw != Token![')'] {
+            return None;
+        }
+
         state.advance();
 
         let return_type = if state.next_token.raw == Token![:] {
@@ -286,7 +329,7 @@ impl Parse for FunctionParser {
                     state.diagnostics.push(
                         ParseDiagnostic::UnexpectedTokenError {
                             got: state.current_token,
-                            expected: expected!(Token![;], Token!['(']),
+                            expected: expected!(Token![;], Token!['{']),
                             node: "function".to_owned(),
                         }
                         .build(),
@@ -295,6 +338,7 @@ impl Parse for FunctionParser {
                     None
                 }
             },
+            attributes: self.attributes,
             docstring: self.docstring,
         })
     }
@@ -308,6 +352,7 @@ impl Parse for TraitItemsParser {
 
         while state.next_token.raw != Token!['}'] {
             let docstring = state.consume_local_docstring();
+            let attributes = AttributesParser.parse(state)?;
 
             if let Some(span) = VisibilityParser.parse(state).span_of_pub() {
                 if !self.type_implementation {
@@ -327,6 +372,7 @@ impl Parse for TraitItemsParser {
                 Token![fun] => Some(TraitItem::AssociatedFunction(
                     FunctionParser {
                         visibility: Visibility::private(),
+                        attributes,
                         docstring,
                     }
                     .parse(state)?,
@@ -334,6 +380,7 @@ impl Parse for TraitItemsParser {
                 Token![type] => Some(TraitItem::TypeAlias(
                     TypeAliasParser {
                         visibility: Visibility::private(),
+                        attributes,
                         docstring,
                     }
                     .parse(state)?,
@@ -381,6 +428,8 @@ impl Parse for TypeAliasParser {
             None
         };
 
+        let where_clause = WhereClauseParser.optionally_parse(state)?;
+
         state.consume(Token![;], "type alias")?;
 
         Some(TypeAlias {
@@ -389,11 +438,52 @@ impl Parse for TypeAliasParser {
             generic_parameters,
             bounds,
             value,
+            where_clause,
+            attributes: self.attributes,
             docstring: self.docstring,
         })
     }
 }
 
+impl Parse for ConstOrStaticParser {
+    type Output = Option<Item>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        state.advance();
+        let is_static = state.current_token.raw == Token![static];
+        let node = if is_static { "static" } else { "constant" };
+
+        let name = state.consume_identifier(node)?;
+        state.consume(Token![:], node)?;
+
+        let ty = TypeParser.parse(state)?;
+        state.consume(Token![=], node)?;
+
+        let value = ExpressionParser::default().parse(state)?;
+        state.consume(Token![;], node)?;
+
+        Some(if is_static {
+            Item::Static {
+                visibility: self.visibility,
+                name,
+                ty,
+                value,
+                attributes: self.attributes,
+                docstring: self.docstring,
+            }
+        } else {
+            Item::Const {
+                visibility: self.visibility,
+                name,
+                ty,
+                value,
+                attributes: self.attributes,
+                docstring: self.docstring,
+            }
+        })
+    }
+}
+
 impl Parse for TraitParser {
     type Output = Option<Item>;
 
@@ -424,6 +514,7 @@ impl Parse for TraitParser {
             generic_parameters,
             where_clause,
             items: items.0,
+            attributes: self.attributes,
             docstring: self.docstring,
         })
     }
@@ -478,6 +569,7 @@ impl Parse for ImplParser {
             r#trait,
             where_clause,
             items: items.0,
+            attributes: self.attributes,
             docstring: self.docstring,
         }))
     }
@@ -509,6 +601,7 @@ impl Parse for EnumParser {
             generic_parameters,
             where_clause,
             items,
+            attributes: self.attributes,
             docstring: self.docstring,
         })
     }
@@ -531,7 +624,20 @@ impl Parse for EnumItemParser {
                 .parse(state)?,
                 docstring,
             }),
-            _ => Some(EnumItem::Just { name, docstring }),
+            Token![=] => {
+                state.advance();
+
+                Some(EnumItem::Just {
+                    name,
+                    discriminant: Some(ExpressionParser::default().parse(state)?),
+                    docstring,
+                })
+            }
+            _ => Some(EnumItem::Just {
+                name,
+                discriminant: None,
+                docstring,
+            }),
         }
     }
 }
@@ -574,6 +680,83 @@ impl Parse for TupleFieldsParser {
     }
 }
 
+impl Parse for AttributesParser {
+    type Output = Option<Vec<Attribute>>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        let mut attributes = vec![];
+
+        loop {
+            match state.next_token.raw {
+                Token![#] => {}
+                Token![@] => {
+                    attributes.push(DecoratorParser.parse(state)?);
+                    continue;
+                }
+                _ => break,
+            }
+
+            state.advance();
+            let start = state.current_token.span.start;
+
+            state.consume(Token!['['], "attribute")?;
+
+            let path = PathParser.parse(state)?;
+
+            let arguments = if state.next_token.raw == Token!['('] {
+                state.advance();
+
+                let arguments = parse_list!(state, "attribute arguments", Token![')'], {
+                    ExpressionParser::default().parse(state)
+                });
+
+                state.advance();
+
+                arguments
+            } else {
+                vec![]
+            };
+
+            state.consume(Token![']'], "attribute")?;
+
+            attributes.push(Attribute {
+                span: state.span_from(start),
+                path,
+                arguments,
+            });
+        }
+
+        Some(attributes)
+    }
+}
+
+impl Parse for DecoratorParser {
+    type Output = Option<Attribute>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        state.advance(); // `@`
+        let start = state.current_token.span.start;
+
+        if state.next_token.raw != RawToken::Identifier {
+            state.diagnostics.push(
+                ParseDiagnostic::UnexpectedTokenError {
+                    got: state.next_token,
+                    expected: expected!(RawToken::Identifier),
+                    node: "decorator".to_owned(),
+                }
+                .build(),
+            );
+            return None;
+        }
+
+        Some(Attribute {
+            path: PathParser.parse(state)?,
+            span: state.span_from(start),
+            arguments: vec![],
+        })
+    }
+}
+
 impl Parse for ItemsParser {
     type Output = Vec<Item>;
 
@@ -591,10 +774,19 @@ impl Parse for ItemsParser {
 }
 
 impl ItemParser {
+    /// Skips tokens until the beginning of the next item (or the end of file),
+    /// so that parsing can continue after a malformed item.
     fn go_to_next_item(state: &mut ParseState<'_, '_, '_>) {
         loop {
+            if Self::at_decorator(state) {
+                break;
+            }
+
             match state.next_token.raw {
-                Token![enum]
+                Token![#]
+                | Token![const]
+                | Token![static]
+                | Token![enum]
                 | Token![import]
                 | Token![struct]
                 | Token![trait]
@@ -606,6 +798,19 @@ impl ItemParser {
             }
         }
     }
+
+    /// Returns `true` if the next token is `@`, directly followed by a
+    /// decorator name (`@derive`). A standalone `@` is not enough to start
+    /// an item, because it is also used in patterns (`x @ 1`).
+    fn at_decorator(state: &mut ParseState<'_, '_, '_>) -> bool {
+        if state.next_token.raw != Token![@] {
+            return false;
+        }
+
+        let name = state.lexer.peek_token();
+
+        name.raw == RawToken::Identifier && name.span.start == state.next_token.span.end
+    }
 }
 
 macro_rules! go_to_next_valid_item {
@@ -624,6 +829,7 @@ impl Parse for ItemParser {
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
         let docstring = state.consume_local_docstring();
+        let attributes = go_to_next_valid_item!(state, AttributesParser.parse(state));
         let visibility = VisibilityParser.parse(state);
 
         Some(match state.next_token.raw {
@@ -632,19 +838,28 @@ impl Parse for ItemParser {
                     state,
                     EnumParser {
                         visibility,
+                        attributes,
                         docstring
                     }
                     .parse(state)
                 )
             }
             Token![import] => {
-                go_to_next_valid_item!(state, ImportParser { visibility }.parse(state))
+                go_to_next_valid_item!(
+                    state,
+                    ImportParser {
+                        visibility,
+                        attributes
+                    }
+                    .parse(state)
+                )
             }
             Token![struct] => {
                 go_to_next_valid_item!(
                     state,
                     StructParser {
                         visibility,
+                        attributes,
                         docstring
                     }
                     .parse(state)
@@ -655,6 +870,7 @@ impl Parse for ItemParser {
                     state,
                     TraitParser {
                         visibility,
+                        attributes,
                         docstring
                     }
                     .parse(state)
@@ -664,6 +880,7 @@ impl Parse for ItemParser {
                 state,
                 FunctionParser {
                     visibility,
+                    attributes,
                     docstring
                 }
                 .parse(state)
@@ -673,6 +890,7 @@ impl Parse for ItemParser {
                     state,
                     ImplParser {
                         visibility,
+                        attributes,
                         docstring
                     }
                     .parse(state)
@@ -682,10 +900,22 @@ impl Parse for ItemParser {
                 state,
                 TypeAliasParser {
                     visibility,
+                    attributes,
                     docstring
                 }
                 .parse(state)
             )),
+            Token![const] | Token![static] => {
+                go_to_next_valid_item!(
+                    state,
+                    ConstOrStaticParser {
+                        visibility,
+                        attributes,
+                        docstring
+                    }
+                    .parse(state)
+                )
+            }
             _ => {
                 state.diagnostics.push(
                     ParseDiagnostic::UnexpectedTokenError {
@@ -698,6 +928,8 @@ impl Parse for ItemParser {
                             Token![struct],
                             Token![impl],
                             Token![type],
+                            Token![const],
+                            Token![static],
                             RawToken::EndOfFile
                         ),
                         node: "item".to_owned(),
@@ -705,19 +937,7 @@ impl Parse for ItemParser {
                     .build(),
                 );
 
-                loop {
-                    match state.next_token.raw {
-                        Token![enum]
-                        | Token![import]
-                        | Token![struct]
-                        | Token![trait]
-                        | Token![fun]
-                        | Token![type]
-                        | Token![impl]
-                        | RawToken::EndOfFile => break,
-                        _ => state.advance(),
-                    }
-                }
+                Self::go_to_next_item(state);
                 return None;
             }
         })
@@ -177,6 +177,35 @@ pub fn parse_module(
     parse_module_using(ParseState::new(source, diagnostics, interner))
 }
 
+/// Parse a Ry module and return it together with the diagnostics emitted
+/// during parsing.
+///
+/// Parsing never panics on malformed input: the returned [`Module`] contains
+/// every item that could be recovered, and the diagnostics describe the rest.
+///
+/// # Example
+/// ```
+/// # use ry_interner::Interner;
+/// # use ry_parser::parse_module_with_diagnostics;
+/// let mut interner = Interner::default();
+/// let (module, diagnostics) =
+///     parse_module_with_diagnostics("fun foo( {} fun bar() {}", &mut interner);
+///
+/// assert_eq!(module.items.len(), 1);
+/// assert_eq!(diagnostics.len(), 1);
+/// ```
+#[inline]
+#[must_use]
+pub fn parse_module_with_diagnostics(
+    source: &str,
+    interner: &mut Interner,
+) -> (Module, Vec<Diagnostic>) {
+    let mut diagnostics = vec![];
+    let module = parse_module(source, &mut diagnostics, interner);
+
+    (module, diagnostics)
+}
+
 /// Parse a Ry module using a given parse state.
 #[inline]
 #[must_use]
@@ -222,6 +251,34 @@ where
     parse_expression_using(&mut ParseState::new(source.as_ref(), diagnostics, interner))
 }
 
+/// Parse an expression and return it together with the diagnostics emitted
+/// during parsing.
+///
+/// Returns [`None`] instead of the expression if it cannot be recovered
+/// from malformed input, in which case the diagnostics explain why.
+///
+/// # Example
+/// ```
+/// # use ry_interner::Interner;
+/// # use ry_parser::parse_expression_with_diagnostics;
+/// let mut interner = Interner::default();
+/// let (expression, diagnostics) = parse_expression_with_diagnostics("1 +", &mut interner);
+///
+/// assert!(expression.is_none());
+/// assert_eq!(diagnostics.len(), 1);
+/// ```
+#[inline]
+#[must_use]
+pub fn parse_expression_with_diagnostics(
+    source: &str,
+    interner: &mut Interner,
+) -> (Option<Expression>, Vec<Diagnostic>) {
+    let mut diagnostics = vec![];
+    let expression = parse_expression(source, &mut diagnostics, interner);
+
+    (expression, diagnostics)
+}
+
 /// Parse an expression.
 #[inline]
 #[must_use]
@@ -432,7 +489,7 @@ impl<'source, 'diagnostics, 'interner> ParseState<'source, 'diagnostics, 'intern
             self.diagnostics.push(
                 ParseDiagnostic::UnexpectedTokenError {
                     got: self.next_token,
-                    expected: expected!("identifier"),
+                    expected: expected!(RawToken::Identifier),
                     node: node.into(),
                 }
                 .build(),
@@ -445,42 +502,60 @@ impl<'source, 'diagnostics, 'interner> ParseState<'source, 'diagnostics, 'intern
         Some(spanned_symbol)
     }
 
-    /// Consumes the docstring for a module.
-    pub(crate) fn consume_module_docstring(&mut self) -> Option<String> {
-        if self.next_token.raw == RawToken::GlobalDocComment {
-            let mut module_docstring = String::new();
+    /// Consumes a loop label (`'outer`), if the next token is one.
+    fn consume_label(&mut self) -> Option<IdentifierAst> {
+        if self.next_token.raw != RawToken::Label {
+            return None;
+        }
 
-            while self.next_token.raw == RawToken::GlobalDocComment {
-                self.advance();
+        let label = IdentifierAst {
+            span: self.next_token.span,
+            symbol: self.lexer.scanned_identifier,
+        };
+        self.advance();
 
-                module_docstring.push_str(self.resolve_span(self.current_token.span));
-            }
+        Some(label)
+    }
 
-            trace!("consumed module level docstring");
+    /// Consumes the docstring for a module.
+    pub(crate) fn consume_module_docstring(&mut self) -> Option<String> {
+        let docstring = self.consume_docstring(RawToken::GlobalDocComment, "//!")?;
 
-            Some(module_docstring)
-        } else {
-            None
-        }
+        trace!("consumed module level docstring");
+
+        Some(docstring)
     }
 
     /// Consumes the docstring for a local item.
     pub(crate) fn consume_local_docstring(&mut self) -> Option<String> {
-        if self.next_token.raw == RawToken::LocalDocComment {
-            let mut local_docstring = String::new();
+        let docstring = self.consume_docstring(RawToken::LocalDocComment, "///")?;
 
-            while self.next_token.raw == RawToken::LocalDocComment {
-                self.advance();
+        trace!("consumed docstring");
 
-                local_docstring.push_str(self.resolve_span(self.current_token.span));
-            }
+        Some(docstring)
+    }
 
-            trace!("consumed docstring");
+    /// Consumes consecutive doc comments of a given kind and joins their
+    /// text with newlines. The comment prefix and one space following it
+    /// are stripped from every line.
+    fn consume_docstring(&mut self, raw: RawToken, prefix: &str) -> Option<String> {
+        if self.next_token.raw != raw {
+            return None;
+        }
 
-            Some(local_docstring)
-        } else {
-            None
+        let source = self.source;
+        let mut lines = vec![];
+
+        while self.next_token.raw == raw {
+            self.advance();
+
+            let line = source.index(self.current_token.span);
+            let line = line.strip_prefix(prefix).unwrap_or(line);
+
+            lines.push(line.strip_prefix(' ').unwrap_or(line));
         }
+
+        Some(lines.join("\n"))
     }
 }
 
@@ -12,20 +12,10 @@ impl Parse for LiteralParser {
         match state.next_token.raw {
             RawToken::IntegerLiteral => {
                 state.advance();
-                if let Ok(value) = state.resolve_current().replace('_', "").parse::<u64>() {
-                    Some(Literal::Integer {
-                        value,
-                        span: state.current_token.span,
-                    })
-                } else {
-                    state.diagnostics.push(
-                        ParseDiagnostic::IntegerOverflowError {
-                            span: state.current_token.span,
-                        }
-                        .build(),
-                    );
-                    None
-                }
+                Some(Literal::Integer {
+                    value: state.lexer.scanned_integer,
+                    span: state.current_token.span,
+                })
             }
             RawToken::FloatLiteral => {
                 state.advance();
@@ -44,7 +34,7 @@ impl Parse for LiteralParser {
                     None
                 }
             }
-            RawToken::StringLiteral => {
+            RawToken::StringLiteral | RawToken::RawStringLiteral => {
                 state.advance();
                 Some(Literal::String {
                     value: state.lexer.scanned_string(),
@@ -1,11 +1,28 @@
+/// Parses a comma separated list of nodes, until the closing token (the
+/// closing token itself is not consumed).
+///
+/// A single trailing comma before the closing token is accepted, but a
+/// comma that doesn't follow an element (as in `(,)` or `(a,,)`) results
+/// in an error reported by the element parser.
 macro_rules! parse_list {
     (
+        $state:ident,
+        $node_name:expr,
+        $closing_token:expr,
+        $blck:block) => {
+        $crate::macros::parse_list!(@trailing_comma $state, $node_name, $closing_token, $blck).0
+    };
+    // Same as the previous arm, but additionally returns whether a trailing
+    // comma was consumed right before the closing token.
+    (
+        @trailing_comma
         $state:ident,
         $node_name:expr,
         $closing_token:expr,
         $blck:block) => {
         {
             let mut result = vec![];
+            let mut trailing_comma = false;
 
             if $state.next_token.raw != $closing_token {
                 loop {
@@ -28,6 +45,7 @@ macro_rules! parse_list {
                         $state.advance();
 
                         if $state.next_token.raw == $closing_token {
+                            trailing_comma = true;
                             break;
                         }
                     } else {
@@ -36,7 +54,7 @@ macro_rules! parse_list {
                 }
             }
 
-            result
+            (result, trailing_comma)
         }
     };
     (
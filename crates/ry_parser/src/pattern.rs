@@ -1,6 +1,6 @@
 use ry_ast::{token::RawToken, Path, Pattern, StructFieldPattern, Token};
 use ry_diagnostics::BuildDiagnostic;
-use ry_filesystem::span::Span;
+use ry_interner::symbols;
 
 use crate::{
     diagnostics::ParseDiagnostic, expected, literal::LiteralParser, macros::parse_list,
@@ -35,10 +35,7 @@ impl Parse for PatternParser {
             let right = Self.parse(state)?;
 
             Some(Pattern::Or {
-                span: Span {
-                    start: left.span().start,
-                    end: right.span().end,
-                },
+                span: left.span().to(right.span()),
                 left: Box::new(left),
                 right: Box::new(right),
             })
@@ -54,11 +51,38 @@ impl Parse for PatternExceptOrParser {
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
         match state.next_token.raw {
             RawToken::StringLiteral
+            | RawToken::RawStringLiteral
             | RawToken::CharLiteral
             | RawToken::IntegerLiteral
             | RawToken::FloatLiteral
             | RawToken::TrueBoolLiteral
             | RawToken::FalseBoolLiteral => Some(Pattern::Literal(LiteralParser.parse(state)?)),
+            Token![-] => {
+                let start = state.next_token.span.start;
+                state.advance();
+
+                if !matches!(
+                    state.next_token.raw,
+                    RawToken::IntegerLiteral | RawToken::FloatLiteral
+                ) {
+                    state.diagnostics.push(
+                        ParseDiagnostic::UnexpectedTokenError {
+                            got: state.next_token,
+                            expected: expected!(RawToken::IntegerLiteral, RawToken::FloatLiteral),
+                            node: "negative literal pattern".to_owned(),
+                        }
+                        .build(),
+                    );
+                    return None;
+                }
+
+                let literal = LiteralParser.parse(state)?;
+
+                Some(Pattern::NegativeLiteral {
+                    span: state.span_from(start),
+                    literal,
+                })
+            }
             RawToken::Identifier => {
                 let path = PathParser.parse(state)?;
 
@@ -72,6 +96,13 @@ impl Parse for PatternExceptOrParser {
                     _ => {}
                 };
 
+                if let [identifier] = path.identifiers.as_slice() {
+                    if identifier.symbol == symbols::UNDERSCORE && state.next_token.raw != Token![@]
+                    {
+                        return Some(Pattern::Wildcard { span: path.span });
+                    }
+                }
+
                 // If it is only 1 identifier
                 if path.identifiers.len() == 1 {
                     let identifier = path.identifiers.first().expect(
@@ -86,12 +117,9 @@ impl Parse for PatternExceptOrParser {
                     };
 
                     Some(Pattern::Identifier {
-                        span: Span {
-                            start: path.span.start,
-                            end: match pattern {
-                                Some(ref pattern) => pattern.span().end,
-                                None => path.span.end,
-                            },
+                        span: match pattern {
+                            Some(ref pattern) => path.span.to(pattern.span()),
+                            None => path.span,
                         },
                         identifier: *identifier,
                         pattern,
@@ -107,7 +135,7 @@ impl Parse for PatternExceptOrParser {
             Token![..] => {
                 state.advance();
                 Some(Pattern::Rest {
-                    span: state.next_token.span,
+                    span: state.current_token.span,
                 })
             }
             Token!['('] => GroupedOrTuplePatternParser.parse(state),
@@ -116,13 +144,14 @@ impl Parse for PatternExceptOrParser {
                     ParseDiagnostic::UnexpectedTokenError {
                         got: state.next_token,
                         expected: expected!(
-                            "integer literal",
-                            "float literal",
-                            "string literal",
-                            "char literal",
-                            "boolean literal",
+                            RawToken::IntegerLiteral,
+                            RawToken::FloatLiteral,
+                            RawToken::StringLiteral,
+                            RawToken::CharLiteral,
+                            "<boolean literal>",
+                            Token![-],
                             Token!['['],
-                            "identifier",
+                            RawToken::Identifier,
                             Token![if],
                             Token![while]
                         ),
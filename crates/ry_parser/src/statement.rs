@@ -28,17 +28,21 @@ impl Parse for StatementParser {
             Token![defer] => DeferStatementParser.parse(state)?,
             Token![let] => LetStatementParser.parse(state)?,
             Token![continue] => {
+                let start = state.next_token.span.start;
                 state.advance();
 
                 Statement::Continue {
-                    span: state.current_token.span,
+                    label: state.consume_label(),
+                    span: state.span_from(start),
                 }
             }
             Token![break] => {
+                let start = state.next_token.span.start;
                 state.advance();
 
                 Statement::Break {
-                    span: state.current_token.span,
+                    label: state.consume_label(),
+                    span: state.span_from(start),
                 }
             }
             _ => {
@@ -150,10 +154,19 @@ impl Parse for ReturnStatementParser {
     type Output = Option<Statement>;
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
-        state.advance();
+        state.advance(); // `return`
+
+        let start = state.current_token.span.start;
+
+        let expression = if state.next_token.raw == Token![;] {
+            None
+        } else {
+            Some(ExpressionParser::default().parse(state)?)
+        };
 
         Some(Statement::Return {
-            expression: ExpressionParser::default().parse(state)?,
+            span: state.span_from(start),
+            expression,
         })
     }
 }
@@ -5,15 +5,22 @@ use ry_ast::{
 use ry_diagnostics::BuildDiagnostic;
 
 use crate::{
-    diagnostics::ParseDiagnostic, expected, macros::parse_list, path::PathParser, OptionalParser,
-    Parse, ParseState,
+    diagnostics::ParseDiagnostic, expected, expression::ExpressionParser, macros::parse_list,
+    path::PathParser, OptionalParser, Parse, ParseState,
 };
 
 pub(crate) struct TypeBoundsParser;
 
 pub(crate) struct TypeParser;
 
-struct TypeWithQualifiedPathParser;
+struct ArrayOrTypeWithQualifiedPathParser;
+
+struct TypeWithQualifiedPathParser {
+    start: usize,
+    left: Box<Type>,
+}
+
+struct ReferenceTypeParser;
 
 struct TraitObjectTypeParser;
 
@@ -27,6 +34,8 @@ pub(crate) struct GenericParametersParser;
 
 pub(crate) struct GenericArgumentsParser;
 
+pub(crate) struct GenericArgumentParser;
+
 pub(crate) struct WhereClauseParser;
 
 impl Parse for TypeBoundsParser {
@@ -54,12 +63,19 @@ impl Parse for TypeParser {
             Token!['('] => ParenthesizedTupleOrFunctionTypeParser.parse(state),
             RawToken::Identifier => TypePathParser.parse(state).map(Type::Path),
             Token![dyn] => TraitObjectTypeParser.parse(state),
-            Token!['['] => TypeWithQualifiedPathParser.parse(state),
+            Token!['['] => ArrayOrTypeWithQualifiedPathParser.parse(state),
+            Token![&] | Token![&&] => ReferenceTypeParser.parse(state),
             _ => {
                 state.diagnostics.push(
                     ParseDiagnostic::UnexpectedTokenError {
                         got: state.next_token,
-                        expected: expected!("identifier", Token!['['], Token![#], Token!['(']),
+                        expected: expected!(
+                            RawToken::Identifier,
+                            Token!['['],
+                            Token![#],
+                            Token!['('],
+                            Token![&]
+                        ),
                         node: "type".to_owned(),
                     }
                     .build(),
@@ -71,7 +87,7 @@ impl Parse for TypeParser {
     }
 }
 
-impl Parse for TypeWithQualifiedPathParser {
+impl Parse for ArrayOrTypeWithQualifiedPathParser {
     type Output = Option<Type>;
 
     fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
@@ -79,7 +95,54 @@ impl Parse for TypeWithQualifiedPathParser {
         state.advance(); // `[`
 
         let left = Box::new(TypeParser.parse(state)?);
-        state.consume(Token![as], "type with qualified path")?;
+
+        match state.next_token.raw {
+            Token![as] => TypeWithQualifiedPathParser { start, left }.parse(state),
+            Token![']'] => {
+                state.advance();
+
+                Some(Type::Array {
+                    span: state.span_from(start),
+                    element: left,
+                    size: None,
+                })
+            }
+            Token![;] => {
+                state.advance();
+
+                let size = Box::new(ExpressionParser::default().parse(state)?);
+
+                state.consume(Token![']'], "array type")?;
+
+                Some(Type::Array {
+                    span: state.span_from(start),
+                    element: left,
+                    size: Some(size),
+                })
+            }
+            _ => {
+                state.diagnostics.push(
+                    ParseDiagnostic::UnexpectedTokenError {
+                        got: state.next_token,
+                        expected: expected!(Token![as], Token![']'], Token![;]),
+                        node: "array type or type with qualified path".to_owned(),
+                    }
+                    .build(),
+                );
+
+                None
+            }
+        }
+    }
+}
+
+impl Parse for TypeWithQualifiedPathParser {
+    type Output = Option<Type>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        let Self { start, left } = self;
+
+        state.advance(); // `as`
 
         let right = TypePathParser.parse(state)?;
 
@@ -103,6 +166,46 @@ impl Parse for TypeWithQualifiedPathParser {
     }
 }
 
+impl Parse for ReferenceTypeParser {
+    type Output = Option<Type>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        let start = state.next_token.span.start;
+        let double = state.next_token.raw == Token![&&];
+
+        state.advance(); // `&` or `&&`
+
+        let mutable = if state.next_token.raw == Token![mut] {
+            state.advance();
+            true
+        } else {
+            false
+        };
+
+        let inner = Box::new(TypeParser.parse(state)?);
+
+        if double {
+            // `&&T` is a reference to a reference, the inner one starts right
+            // after the first `&`.
+            Some(Type::Reference {
+                span: state.span_from(start),
+                mutable: false,
+                inner: Box::new(Type::Reference {
+                    span: state.span_from(start + 1),
+                    mutable,
+                    inner,
+                }),
+            })
+        } else {
+            Some(Type::Reference {
+                span: state.span_from(start),
+                mutable,
+                inner,
+            })
+        }
+    }
+}
+
 impl Parse for TraitObjectTypeParser {
     type Output = Option<Type>;
 
@@ -125,7 +228,7 @@ impl Parse for ParenthesizedTupleOrFunctionTypeParser {
         let start = state.next_token.span.start;
         state.advance(); // `(`
 
-        let element_types = parse_list!(state, "parenthesized or tuple type", Token![')'], {
+        let (element_types, trailing_comma) = parse_list!(@trailing_comma state, "parenthesized or tuple type", Token![')'], {
             TypeParser.parse(state)
         });
 
@@ -149,10 +252,7 @@ impl Parse for ParenthesizedTupleOrFunctionTypeParser {
 
         match (element_types.next(), element_types.next()) {
             (Some(element), None) => {
-                if state
-                    .resolve_span(state.span_from(element.span().end))
-                    .contains(',')
-                {
+                if trailing_comma {
                     Some(Type::Tuple {
                         span,
                         element_types: vec![element],
@@ -276,28 +376,7 @@ impl Parse for GenericArgumentsParser {
         state.advance();
 
         let result = parse_list!(state, "generic arguments", Token![']'], {
-            let ty = TypeParser.parse(state)?;
-
-            match (state.next_token.raw, &ty) {
-                (Token![=], Type::Path(TypePath { segments, .. })) => match segments.as_slice() {
-                    [TypePathSegment {
-                        path: Path { identifiers, .. },
-                        generic_arguments: None,
-                        ..
-                    }] if identifiers.len() == 1 => {
-                        state.advance();
-                        let value = TypeParser.parse(state)?;
-                        Some(GenericArgument::AssociatedType {
-                            name: *identifiers
-                                .first()
-                                .expect("Cannot get first identifier of type path"),
-                            value,
-                        })
-                    }
-                    _ => None,
-                },
-                _ => Some(GenericArgument::Type(ty)),
-            }
+            GenericArgumentParser.parse(state)
         });
 
         state.advance();
@@ -306,6 +385,35 @@ impl Parse for GenericArgumentsParser {
     }
 }
 
+impl Parse for GenericArgumentParser {
+    type Output = Option<GenericArgument>;
+
+    fn parse(self, state: &mut ParseState<'_, '_, '_>) -> Self::Output {
+        let ty = TypeParser.parse(state)?;
+
+        match (state.next_token.raw, &ty) {
+            (Token![=], Type::Path(TypePath { segments, .. })) => match segments.as_slice() {
+                [TypePathSegment {
+                    path: Path { identifiers, .. },
+                    generic_arguments: None,
+                    ..
+                }] if identifiers.len() == 1 => {
+                    state.advance();
+                    let value = TypeParser.parse(state)?;
+                    Some(GenericArgument::AssociatedType {
+                        name: *identifiers
+                            .first()
+                            .expect("Cannot get first identifier of type path"),
+                        value,
+                    })
+                }
+                _ => None,
+            },
+            _ => Some(GenericArgument::Type(ty)),
+        }
+    }
+}
+
 impl OptionalParser for WhereClauseParser {
     type Output = Option<Option<WhereClause>>;
 
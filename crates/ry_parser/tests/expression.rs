@@ -1,12 +1,12 @@
 use ry_ast::{
-    BinaryOperator, Expression, GenericArgument, IdentifierAst, LambdaFunctionParameter, Literal,
-    MatchExpressionItem, Path, Pattern, PostfixOperator, PrefixOperator, RawBinaryOperator,
-    RawPostfixOperator, RawPrefixOperator, Statement, StructExpressionItem, Type, TypePath,
-    TypePathSegment,
+    block_value, serialize::SExprSerializer, visit::Visitor, BinaryOperator, Expression,
+    GenericArgument, IdentifierAst, LambdaFunctionParameter, Literal, MatchExpressionItem, Path,
+    Pattern, PostfixOperator, PrefixOperator, RawBinaryOperator, RawPostfixOperator,
+    RawPrefixOperator, Statement, StructExpressionItem, Type, TypePath, TypePathSegment,
 };
 use ry_filesystem::span::Span;
 use ry_interner::{symbols, Interner};
-use ry_parser::parse_expression;
+use ry_parser::{parse_expression, parse_pattern, parse_type};
 
 mod r#macro;
 
@@ -22,6 +22,20 @@ fn literal() {
             span: Span { start: 0, end: 1 }
         }))
     );
+    assert_eq!(
+        parse_expression("0x1_F", &mut diagnostics, &mut interner),
+        Some(Expression::Literal(Literal::Integer {
+            value: 31,
+            span: Span { start: 0, end: 5 }
+        }))
+    );
+    assert_eq!(
+        parse_expression("0b101", &mut diagnostics, &mut interner),
+        Some(Expression::Literal(Literal::Integer {
+            value: 5,
+            span: Span { start: 0, end: 5 }
+        }))
+    );
     assert_eq!(
         parse_expression("true", &mut diagnostics, &mut interner),
         Some(Expression::Literal(Literal::Boolean {
@@ -50,6 +64,20 @@ fn literal() {
             span: Span { start: 0, end: 6 }
         }))
     );
+    assert_eq!(
+        parse_expression("1e10", &mut diagnostics, &mut interner),
+        Some(Expression::Literal(Literal::Float {
+            value: 1e10,
+            span: Span { start: 0, end: 4 }
+        }))
+    );
+    assert_eq!(
+        parse_expression("2E+4", &mut diagnostics, &mut interner),
+        Some(Expression::Literal(Literal::Float {
+            value: 2e4,
+            span: Span { start: 0, end: 4 }
+        }))
+    );
     assert_eq!(
         parse_expression("'a'", &mut diagnostics, &mut interner),
         Some(Expression::Literal(Literal::Character {
@@ -133,6 +161,91 @@ fn generic_argument() {
     );
 }
 
+#[test]
+fn generic_arguments_and_index() {
+    assert_eq!(
+        parse_sexpr("into[uint32](3)"),
+        "(call (with_generic_arguments into (type uint32)) (integer 3))"
+    );
+    assert_eq!(parse_sexpr("list[0]"), "(index list (integer 0))");
+    assert_eq!(parse_sexpr("list[i]"), "(index list i)");
+    assert_eq!(
+        parse_sexpr("m[i + 1][j].len()"),
+        "(call (field_access (index (index m (binary + i (integer 1))) j) len))"
+    );
+    assert_eq!(
+        parse_sexpr("HashMap[String, uint32].new()"),
+        "(call (field_access (with_generic_arguments HashMap (type String) (type uint32)) new))"
+    );
+    assert_eq!(
+        parse_sexpr("size_of[&mut T]()"),
+        "(call (with_generic_arguments size_of (mutable_reference_type (type T))))"
+    );
+    assert_eq!(
+        parse_sexpr("callbacks[0](x)"),
+        "(call (index callbacks (integer 0)) x)"
+    );
+    assert_eq!(parse_sexpr("handlers[i](x)"), "(call (index handlers i) x)");
+    assert_eq!(
+        parse_sexpr("callbacks[idx]()"),
+        "(call (index callbacks idx))"
+    );
+    assert_eq!(
+        parse_sexpr("List[uint32].new()"),
+        "(call (field_access (with_generic_arguments List (type uint32)) new))"
+    );
+    assert_eq!(
+        parse_sexpr("Point[T] { x: 1 }"),
+        "(struct_expression (with_generic_arguments Point (type T)) (field x (integer 1)))"
+    );
+    assert_eq!(
+        parse_sexpr("foo[Item = T](x)"),
+        "(call (with_generic_arguments foo (associated_type Item (type T))) x)"
+    );
+    assert_eq!(
+        parse_sexpr("foo[T, Item = U]()"),
+        "(call (with_generic_arguments foo (type T) (associated_type Item (type U))))"
+    );
+}
+
+/// Types in generic arguments, that are first parsed as expressions, must
+/// be the same as if they were parsed by the type parser.
+#[test]
+fn generic_argument_as_type() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    for ty in [
+        "Option[T]",
+        "a.b[C].D",
+        "(A, [B])",
+        "(A)",
+        "List[Option[T]]",
+    ] {
+        let Some(Expression::Call { left, .. }) =
+            parse_expression(format!("f[{ty}]()"), &mut diagnostics, &mut interner)
+        else {
+            panic!("failed to parse a call with `{ty}`");
+        };
+        let Expression::GenericArguments {
+            generic_arguments, ..
+        } = *left
+        else {
+            panic!("`{ty}` is not parsed as a generic argument");
+        };
+
+        assert_eq!(
+            generic_arguments,
+            vec![GenericArgument::Type(
+                parse_type(format!("  {ty}"), &mut diagnostics, &mut interner).unwrap()
+            )],
+            "{ty}"
+        );
+    }
+
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn list() {
     let mut interner = Interner::default();
@@ -419,6 +532,7 @@ fn r#while() {
         ),
         Some(Expression::While {
             span: Span { start: 0, end: 38 },
+            label: None,
             condition: Box::new(Expression::Literal(Literal::Boolean {
                 value: true,
                 span: Span { start: 6, end: 10 }
@@ -523,6 +637,106 @@ fn lambda() {
     );
 }
 
+#[test]
+fn lambda_without_parameters() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("|| { 1 }", &mut diagnostics, &mut interner),
+        Some(Expression::Lambda {
+            span: Span { start: 0, end: 8 },
+            parameters: vec![],
+            return_type: None,
+            block: vec![Statement::Expression {
+                expression: Expression::Literal(Literal::Integer {
+                    value: 1,
+                    span: Span { start: 5, end: 6 }
+                }),
+                has_semicolon: false
+            }]
+        })
+    );
+}
+
+#[test]
+fn lambda_with_expression_body() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("|a| a | 1", &mut diagnostics, &mut interner),
+        Some(Expression::Lambda {
+            span: Span { start: 0, end: 9 },
+            parameters: vec![LambdaFunctionParameter {
+                name: IdentifierAst {
+                    span: Span { start: 1, end: 2 },
+                    symbol: interner.get_or_intern("a")
+                },
+                ty: None
+            }],
+            return_type: None,
+            block: vec![Statement::Expression {
+                expression: Expression::Binary {
+                    span: Span { start: 4, end: 9 },
+                    left: Box::new(Expression::Identifier(IdentifierAst {
+                        span: Span { start: 4, end: 5 },
+                        symbol: interner.get_or_intern("a")
+                    })),
+                    operator: BinaryOperator {
+                        span: Span { start: 6, end: 7 },
+                        raw: RawBinaryOperator::Or
+                    },
+                    right: Box::new(Expression::Literal(Literal::Integer {
+                        value: 1,
+                        span: Span { start: 8, end: 9 }
+                    }))
+                },
+                has_semicolon: false
+            }]
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn lambda_with_return_type() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("||: uint32 { 1 }", &mut diagnostics, &mut interner),
+        Some(Expression::Lambda {
+            span: Span { start: 0, end: 16 },
+            parameters: vec![],
+            return_type: Some(Type::Path(TypePath {
+                span: Span { start: 4, end: 10 },
+                segments: vec![TypePathSegment {
+                    span: Span { start: 4, end: 10 },
+                    path: Path {
+                        span: Span { start: 4, end: 10 },
+                        identifiers: vec![IdentifierAst {
+                            span: Span { start: 4, end: 10 },
+                            symbol: symbols::UINT32
+                        }]
+                    },
+                    generic_arguments: None
+                }]
+            })),
+            block: vec![Statement::Expression {
+                expression: Expression::Literal(Literal::Integer {
+                    value: 1,
+                    span: Span { start: 13, end: 14 }
+                }),
+                has_semicolon: false
+            }]
+        })
+    );
+}
+
+test!(lambda_as_argument: "fun main() { map(list, |x| x * 2); apply(|| { foo(); }); }");
+test!(lambda_and_bitwise_or: "fun main() { let a = b | c || d; let f = |x: uint32| x | 1; }");
+
 #[test]
 fn r#match() {
     let mut interner = Interner::default();
@@ -581,7 +795,7 @@ fn r#match() {
                 },
                 MatchExpressionItem {
                     left: Pattern::Rest {
-                        span: Span { start: 42, end: 44 }
+                        span: Span { start: 39, end: 41 }
                     },
                     right: Expression::StatementsBlock {
                         span: Span { start: 45, end: 47 },
@@ -592,3 +806,439 @@ fn r#match() {
         })
     );
 }
+
+/// Parses an expression and serializes it into an S-expression.
+fn parse_sexpr(source: &str) -> String {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let expression =
+        parse_expression(source, &mut diagnostics, &mut interner).expect("failed to parse");
+    assert!(diagnostics.is_empty());
+
+    let mut serializer = SExprSerializer::new(&interner);
+    serializer.visit_expression(&expression);
+    serializer.take_output()
+}
+
+#[test]
+fn match_patterns() {
+    assert_eq!(
+        parse_sexpr("match x { 1 => a, \"s\" | 'c' => b, true => c, }"),
+        "(match x (arm (integer 1) a) (arm (or_pattern (string \"s\") (character 'c')) b) \
+         (arm (boolean true) c))"
+    );
+    assert_eq!(
+        parse_sexpr("match x { Some(a) => a, Point { x, y: 0, .. } => x, [a, ..] => a, .. => b }"),
+        "(match x (arm (tuple_like_pattern Some a) a) \
+         (arm (struct_pattern Point (field x) (field y (integer 0)) ..) x) \
+         (arm (list_pattern a ..) a) (arm .. b))"
+    );
+    assert_eq!(
+        parse_sexpr("match x { A | B | C => 1 }"),
+        "(match x (arm (or_pattern A (or_pattern B C)) (integer 1)))"
+    );
+}
+
+#[test]
+fn match_block_bodies() {
+    assert_eq!(
+        parse_sexpr("match x { 1 => { a } 2 => { b }, _ => c }"),
+        "(match x (arm (integer 1) (block_expression (block a))) \
+         (arm (integer 2) (block_expression (block b))) (arm _ c))"
+    );
+    assert_eq!(parse_sexpr("match x {}"), "(match x)");
+    assert_eq!(
+        parse_sexpr("match x { 1 => { a } (c, d) => b }"),
+        "(match x (arm (integer 1) (block_expression (block a))) (arm (tuple_pattern c d) b))"
+    );
+    assert_eq!(
+        parse_sexpr("match x { 1 => { a } -1 => b }"),
+        "(match x (arm (integer 1) (block_expression (block a))) (arm (negative (integer 1)) b))"
+    );
+    assert_eq!(
+        parse_sexpr("match x { 1 => if a { b } [c] => d }"),
+        "(match x (arm (integer 1) (if (branch a (block b)))) (arm (list_pattern c) d))"
+    );
+}
+
+#[test]
+fn range() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("a..=b", &mut diagnostics, &mut interner),
+        Some(Expression::Range {
+            span: Span { start: 0, end: 5 },
+            start: Some(Box::new(Expression::Identifier(IdentifierAst {
+                span: Span { start: 0, end: 1 },
+                symbol: interner.get_or_intern("a")
+            }))),
+            end: Some(Box::new(Expression::Identifier(IdentifierAst {
+                span: Span { start: 4, end: 5 },
+                symbol: interner.get_or_intern("b")
+            }))),
+            inclusive: true
+        })
+    );
+}
+
+#[test]
+fn range_forms() {
+    assert_eq!(parse_sexpr("a..b"), "(range a b)");
+    assert_eq!(parse_sexpr("a..=b"), "(inclusive_range a b)");
+    assert_eq!(parse_sexpr("..b"), "(range _ b)");
+    assert_eq!(parse_sexpr("..=b"), "(inclusive_range _ b)");
+    assert_eq!(parse_sexpr("a.."), "(range a _)");
+    assert_eq!(parse_sexpr(".."), "(range _ _)");
+    assert_eq!(parse_sexpr("(..)"), "(parenthesized (range _ _))");
+    assert_eq!(
+        parse_sexpr("[1.., ..2]"),
+        "(list (range (integer 1) _) (range _ (integer 2)))"
+    );
+}
+
+#[test]
+fn range_precedence() {
+    assert_eq!(
+        parse_sexpr("a + 1..b * 2"),
+        "(range (binary + a (integer 1)) (binary * b (integer 2)))"
+    );
+    assert_eq!(
+        parse_sexpr("x = a..b || c"),
+        "(binary = x (range a (binary || b c)))"
+    );
+    assert_eq!(parse_sexpr("a < b..c"), "(range (binary < a b) c)");
+    assert_eq!(
+        parse_sexpr("-1..f()"),
+        "(range (prefix - (integer 1)) (call f))"
+    );
+    assert_eq!(
+        parse_sexpr("match 0.. { _ => 1 }"),
+        "(match (range (integer 0) _) (arm _ (integer 1)))"
+    );
+}
+
+#[test]
+fn sexpr() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let expression = parse_expression("-a + 2 * foo(1, \"s\")", &mut diagnostics, &mut interner)
+        .expect("failed to parse the expression");
+
+    let mut serializer = SExprSerializer::new(&interner);
+    serializer.visit_expression(&expression);
+
+    assert_eq!(
+        serializer.output(),
+        "(binary + (prefix - a) (binary * (integer 2) (call foo (integer 1) (string \"s\"))))"
+    );
+}
+
+#[test]
+fn unit() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("()", &mut diagnostics, &mut interner),
+        Some(Expression::Tuple {
+            span: Span { start: 0, end: 2 },
+            elements: vec![]
+        })
+    );
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(parse_sexpr("f(())"), "(call f (tuple))");
+}
+
+#[test]
+fn one_element_tuple_requires_top_level_comma() {
+    assert_eq!(parse_sexpr("(a)"), "(parenthesized a)");
+    assert_eq!(parse_sexpr("(a,)"), "(tuple a)");
+    assert_eq!(parse_sexpr("(f(a, b))"), "(parenthesized (call f a b))");
+}
+
+#[test]
+fn right_associative_power() {
+    assert_eq!(
+        parse_sexpr("2 ** 3 ** 2"),
+        "(binary ** (integer 2) (binary ** (integer 3) (integer 2)))"
+    );
+    assert_eq!(
+        parse_sexpr("a * b ** c ** d"),
+        "(binary * a (binary ** b (binary ** c d)))"
+    );
+    assert_eq!(
+        parse_sexpr("(a ** b) ** c"),
+        "(binary ** (parenthesized (binary ** a b)) c)"
+    );
+}
+
+#[test]
+fn tuple_field_access() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("t.0", &mut diagnostics, &mut interner),
+        Some(Expression::TupleFieldAccess {
+            span: Span { start: 0, end: 3 },
+            left: Box::new(Expression::Identifier(IdentifierAst {
+                span: Span { start: 0, end: 1 },
+                symbol: interner.get_or_intern("t")
+            })),
+            index: 0
+        })
+    );
+
+    // `.0` is lexed as a float literal.
+    assert_eq!(
+        parse_expression("t.0.12", &mut diagnostics, &mut interner),
+        Some(Expression::TupleFieldAccess {
+            span: Span { start: 0, end: 6 },
+            left: Box::new(Expression::TupleFieldAccess {
+                span: Span { start: 0, end: 3 },
+                left: Box::new(Expression::Identifier(IdentifierAst {
+                    span: Span { start: 0, end: 1 },
+                    symbol: interner.get_or_intern("t")
+                })),
+                index: 0
+            }),
+            index: 12
+        })
+    );
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(
+        parse_sexpr("t.0.1.x.2()"),
+        "(call (tuple_field_access (field_access (tuple_field_access (tuple_field_access t 0) 1) x) 2))"
+    );
+}
+
+#[test]
+fn invalid_tuple_index() {
+    for source in ["t.1e5", "t.1_0", "t.1i", "t.99999999999999999999999"] {
+        let mut interner = Interner::default();
+        let mut diagnostics = vec![];
+
+        assert_eq!(
+            parse_expression(source, &mut diagnostics, &mut interner),
+            None
+        );
+        assert_eq!(diagnostics.len(), 1, "{source}");
+    }
+}
+
+#[test]
+fn labeled_while() {
+    assert_eq!(
+        parse_sexpr("'outer: while a { while b { break 'outer; }; continue; }"),
+        "(while 'outer a (block (while b (block (break 'outer))) (continue)))"
+    );
+    assert_eq!(
+        parse_sexpr("'a: while 'a' == c { continue 'a; }"),
+        "(while 'a (binary == (character 'a') c) (block (continue 'a)))"
+    );
+}
+
+#[test]
+fn r#for() {
+    assert_eq!(
+        parse_sexpr("for x in list { println(x); }"),
+        "(for x list (block (statement (call println x))))"
+    );
+    assert_eq!(
+        parse_sexpr("for (k, v) in m { map.insert(k, v); }"),
+        "(for (tuple_pattern k v) m (block (statement (call (field_access map insert) k v))))"
+    );
+
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("for x list {}", &mut diagnostics, &mut interner),
+        None
+    );
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn struct_expression_in_condition() {
+    assert_eq!(parse_sexpr("if x {}"), "(if (branch x (block)))");
+    assert_eq!(
+        parse_sexpr("if person == Person { a(); }"),
+        "(if (branch (binary == person Person) (block (statement (call a)))))"
+    );
+    assert_eq!(
+        parse_sexpr("if (Foo { a: 1 }) {}"),
+        "(if (branch (parenthesized (struct_expression Foo (field a (integer 1)))) (block)))"
+    );
+    assert_eq!(
+        parse_sexpr("while f(Foo { a }) && -x {}"),
+        "(while (binary && (call f (struct_expression Foo (field a))) (prefix - x)) (block))"
+    );
+    assert_eq!(
+        parse_sexpr("match (Foo {}) { _ => 1 }"),
+        "(match (parenthesized (struct_expression Foo)) (arm _ (integer 1)))"
+    );
+    assert_eq!(
+        parse_sexpr("for x in a..b { x; }"),
+        "(for x (range a b) (block (statement x)))"
+    );
+}
+
+#[test]
+fn label_must_precede_loop() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("'outer: 1 + 2", &mut diagnostics, &mut interner),
+        None
+    );
+    assert_eq!(diagnostics.len(), 1);
+}
+
+#[test]
+fn negative_literal_patterns() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_pattern("-1", &mut diagnostics, &mut interner),
+        Some(Pattern::NegativeLiteral {
+            span: Span { start: 0, end: 2 },
+            literal: Literal::Integer {
+                value: 1,
+                span: Span { start: 1, end: 2 }
+            }
+        })
+    );
+    assert_eq!(
+        parse_pattern("- 2.5", &mut diagnostics, &mut interner),
+        Some(Pattern::NegativeLiteral {
+            span: Span { start: 0, end: 5 },
+            literal: Literal::Float {
+                value: 2.5,
+                span: Span { start: 2, end: 5 }
+            }
+        })
+    );
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(
+        parse_sexpr("match x { -1 | 1 => a, -2.5 => b }"),
+        "(match x (arm (or_pattern (negative (integer 1)) (integer 1)) a) \
+         (arm (negative (float 2.5)) b))"
+    );
+}
+
+#[test]
+fn minus_without_literal_in_pattern() {
+    for source in ["-", "-x", "-\"s\""] {
+        let mut interner = Interner::default();
+        let mut diagnostics = vec![];
+
+        assert_eq!(parse_pattern(source, &mut diagnostics, &mut interner), None);
+        assert_eq!(diagnostics.len(), 1, "{source}");
+    }
+}
+
+#[test]
+fn block_with_value() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Expression::StatementsBlock { block, .. }) =
+        parse_expression("{ let a = 1; a + 1 }", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a block expression");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(block.len(), 2);
+    assert!(matches!(
+        block_value(&block),
+        Some(Expression::Binary { .. })
+    ));
+
+    assert_eq!(
+        parse_sexpr("{ foo(); { a } }.b"),
+        "(field_access (block_expression (block (statement (call foo)) \
+         (block_expression (block a)))) b)"
+    );
+}
+
+#[test]
+fn block_without_value() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_expression("{}", &mut diagnostics, &mut interner),
+        Some(Expression::StatementsBlock {
+            span: Span { start: 0, end: 2 },
+            block: vec![]
+        })
+    );
+
+    let Some(Expression::StatementsBlock { block, .. }) =
+        parse_expression("{ foo(); }", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a block expression");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(block_value(&block), None);
+}
+
+#[test]
+fn block_statement() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Expression::StatementsBlock { block, .. }) =
+        parse_expression("{ { a; }; b }", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a block expression");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert!(matches!(
+        block.as_slice(),
+        [
+            Statement::Expression {
+                expression: Expression::StatementsBlock { .. },
+                has_semicolon: false
+            },
+            Statement::Expression {
+                expression: Expression::Identifier(..),
+                has_semicolon: false
+            }
+        ]
+    ));
+}
+
+#[test]
+fn chained_cast() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Expression::As { left, right, .. }) =
+        parse_expression("a as B as C", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a cast expression");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert!(matches!(
+        *left,
+        Expression::As { left, .. } if matches!(*left, Expression::Identifier(..))
+    ));
+    assert_eq!(right.span(), Span { start: 10, end: 11 });
+
+    assert_eq!(parse_sexpr("a as B as C"), "(as (as a (type B)) (type C))");
+}
@@ -1,12 +1,14 @@
 use ry_ast::{
-    EnumItem, Expression, Function, FunctionParameter, GenericArgument, GenericParameter,
-    IdentifierAst, Impl, ImportPath, Item, JustFunctionParameter, Path, SelfParameter, Statement,
-    StructField, TraitItem, TupleField, Type, TypeAlias, TypePath, TypePathSegment, Visibility,
-    WhereClauseItem,
+    serialize::{SExprSerializer, Serializer},
+    visit::Visitor,
+    Attribute, EnumItem, Expression, Function, FunctionParameter, GenericArgument,
+    GenericParameter, IdentifierAst, Impl, ImportPath, Item, ItemKind, JustFunctionParameter,
+    Literal, Path, SelfParameter, Statement, StructField, TraitItem, TupleField, Type, TypeAlias,
+    TypePath, TypePathSegment, Visibility, WhereClauseItem,
 };
 use ry_filesystem::span::Span;
 use ry_interner::{symbols, Interner};
-use ry_parser::parse_item;
+use ry_parser::{parse_item, parse_module};
 
 mod r#macro;
 
@@ -91,7 +93,8 @@ fn function() {
                         },
                         generic_arguments: None
                     }]
-                })
+                }),
+                default_value: None
             })],
             return_type: Some(Type::Path(TypePath {
                 span: Span { start: 33, end: 34 },
@@ -126,6 +129,7 @@ fn function() {
                 },
                 has_semicolon: false
             }]),
+            attributes: vec![],
             docstring: None
         }))
     );
@@ -255,6 +259,7 @@ fn r#impl() {
             })),
             where_clause: None,
             items: vec![],
+            attributes: vec![],
             docstring: None
         }))
     );
@@ -268,6 +273,7 @@ fn import() {
     assert_eq!(
         parse_item("import std.io as myio;", &mut diagnostics, &mut interner),
         Some(Item::Import {
+            attributes: vec![],
             path: ImportPath {
                 left: Path {
                     span: Span { start: 7, end: 13 },
@@ -383,6 +389,7 @@ fn r#struct() {
                 }),
                 docstring: None
             }],
+            attributes: vec![],
             docstring: None
         })
     );
@@ -441,8 +448,10 @@ fn into() {
                 })),
                 where_clause: None,
                 body: None,
+                attributes: vec![],
                 docstring: None
             })],
+            attributes: vec![],
             docstring: None
         })
     );
@@ -555,11 +564,82 @@ fn alias() {
                     generic_arguments: None
                 }]
             }),
+            where_clause: None,
+            attributes: vec![],
             docstring: None
         }))
     );
 }
 
+#[test]
+fn alias_with_where_clause() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::TypeAlias(alias)) = parse_item(
+        "type Foo[T] = Bar[T] where T: Clone;",
+        &mut diagnostics,
+        &mut interner,
+    ) else {
+        panic!("expected a type alias");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert!(alias.value.is_some());
+    assert_eq!(
+        alias.where_clause,
+        Some(vec![WhereClauseItem::Satisfies {
+            ty: Type::Path(TypePath {
+                span: Span { start: 27, end: 28 },
+                segments: vec![TypePathSegment {
+                    span: Span { start: 27, end: 28 },
+                    path: Path {
+                        span: Span { start: 27, end: 28 },
+                        identifiers: vec![IdentifierAst {
+                            span: Span { start: 27, end: 28 },
+                            symbol: interner.get_or_intern("T")
+                        }]
+                    },
+                    generic_arguments: None
+                }]
+            }),
+            bounds: vec![TypePath {
+                span: Span { start: 30, end: 35 },
+                segments: vec![TypePathSegment {
+                    span: Span { start: 30, end: 35 },
+                    path: Path {
+                        span: Span { start: 30, end: 35 },
+                        identifiers: vec![IdentifierAst {
+                            span: Span { start: 30, end: 35 },
+                            symbol: interner.get_or_intern("Clone")
+                        }]
+                    },
+                    generic_arguments: None
+                }]
+            }]
+        }])
+    );
+
+    let mut serializer = Serializer::new(&interner);
+    serializer.visit_type_alias(&alias);
+    assert!(serializer.output().contains("WHERE_CLAUSE_ITEM"));
+}
+
+#[test]
+fn alias_without_where_clause() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::TypeAlias(alias)) =
+        parse_item("type Foo[T] = Bar[T];", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a type alias");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(alias.where_clause, None);
+}
+
 #[test]
 fn r#enum() {
     let mut interner = Interner::default();
@@ -646,7 +726,600 @@ fn r#enum() {
                     docstring: None
                 }
             ],
+            attributes: vec![],
             docstring: None
         })
     );
 }
+
+#[test]
+fn enum_discriminants() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Enum { items, .. }) = parse_item(
+        "enum Color { Red = 1, Green, Blue = 1 << 2 }",
+        &mut diagnostics,
+        &mut interner,
+    ) else {
+        panic!("expected an enum");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        items[..2],
+        [
+            EnumItem::Just {
+                name: IdentifierAst {
+                    span: Span { start: 13, end: 16 },
+                    symbol: interner.get_or_intern("Red")
+                },
+                discriminant: Some(Expression::Literal(Literal::Integer {
+                    value: 1,
+                    span: Span { start: 19, end: 20 }
+                })),
+                docstring: None
+            },
+            EnumItem::Just {
+                name: IdentifierAst {
+                    span: Span { start: 22, end: 27 },
+                    symbol: interner.get_or_intern("Green")
+                },
+                discriminant: None,
+                docstring: None
+            }
+        ]
+    );
+
+    let mut serializer = SExprSerializer::new(&interner);
+    serializer.visit_enum_items(&items);
+    assert_eq!(
+        serializer.take_output(),
+        "(variant Red (integer 1)) (variant Green) \
+         (variant Blue (binary << (integer 1) (integer 2)))"
+    );
+}
+
+#[test]
+fn documented_items() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "//! Module docs.
+
+/// A person.
+struct Person {
+    /// Name of the person.
+    name: String,
+    age: uint32,
+}
+
+enum Shape {
+    /// A circle.
+    Circle(float64),
+    Square { /// Side of the square.
+        side: float64 },
+}
+
+impl Person {
+    /// Creates a new person.
+    fun new(name: String): Person { Person { name, age: 0 } }
+}
+
+/// Entry point.
+fun main() {}
+
+fun undocumented() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let resolve = |items: Vec<(&IdentifierAst, &str)>| {
+        items
+            .into_iter()
+            .map(|(name, docstring)| {
+                (
+                    interner.resolve(name.symbol).unwrap().to_owned(),
+                    docstring.to_owned(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        resolve(module.documented_items()),
+        vec![
+            ("Person".to_owned(), "A person.".to_owned()),
+            ("main".to_owned(), "Entry point.".to_owned()),
+        ]
+    );
+    assert_eq!(
+        resolve(module.documented_items_recursive()),
+        vec![
+            ("Person".to_owned(), "A person.".to_owned()),
+            ("name".to_owned(), "Name of the person.".to_owned()),
+            ("Circle".to_owned(), "A circle.".to_owned()),
+            ("side".to_owned(), "Side of the square.".to_owned()),
+            ("new".to_owned(), "Creates a new person.".to_owned()),
+            ("main".to_owned(), "Entry point.".to_owned()),
+        ]
+    );
+}
+
+#[test]
+fn multiline_docstrings() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "//! Module docs.
+//!
+//!   Indented line.
+
+/// First line.
+///Second line.
+///
+/// Fourth line.
+fun main() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        module.docstring.as_deref(),
+        Some("Module docs.\n\n  Indented line.")
+    );
+    assert_eq!(
+        module
+            .documented_items()
+            .into_iter()
+            .map(|(_, docstring)| docstring)
+            .collect::<Vec<_>>(),
+        vec!["First line.\nSecond line.\n\nFourth line."]
+    );
+}
+
+#[test]
+fn function_parameter_default_value() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Function(function)) = parse_item(
+        "fun f(x: uint32 = 0, y: uint32) {}",
+        &mut diagnostics,
+        &mut interner,
+    ) else {
+        panic!("expected a function");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert!(matches!(
+        &function.parameters[..],
+        [
+            FunctionParameter::Just(JustFunctionParameter {
+                default_value: Some(Expression::Literal(Literal::Integer {
+                    value: 0,
+                    span: Span { start: 18, end: 19 }
+                })),
+                ..
+            }),
+            FunctionParameter::Just(JustFunctionParameter {
+                default_value: None,
+                ..
+            })
+        ]
+    ));
+}
+
+#[test]
+fn attribute() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_item("#[foo] struct A {}", &mut diagnostics, &mut interner),
+        Some(Item::Struct {
+            visibility: Visibility::private(),
+            name: IdentifierAst {
+                span: Span { start: 14, end: 15 },
+                symbol: interner.get_or_intern("A")
+            },
+            generic_parameters: None,
+            where_clause: None,
+            fields: vec![],
+            attributes: vec![Attribute {
+                span: Span { start: 0, end: 6 },
+                path: Path {
+                    span: Span { start: 2, end: 5 },
+                    identifiers: vec![IdentifierAst {
+                        span: Span { start: 2, end: 5 },
+                        symbol: interner.get_or_intern("foo")
+                    }]
+                },
+                arguments: vec![]
+            }],
+            docstring: None
+        })
+    );
+}
+
+#[test]
+fn attribute_with_arguments() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Function(function)) =
+        parse_item("#[foo(bar)] fun f() {}", &mut diagnostics, &mut interner)
+    else {
+        panic!("expected a function");
+    };
+
+    assert_eq!(
+        function.attributes,
+        vec![Attribute {
+            span: Span { start: 0, end: 11 },
+            path: Path {
+                span: Span { start: 2, end: 5 },
+                identifiers: vec![IdentifierAst {
+                    span: Span { start: 2, end: 5 },
+                    symbol: interner.get_or_intern("foo")
+                }]
+            },
+            arguments: vec![Expression::Identifier(IdentifierAst {
+                span: Span { start: 6, end: 9 },
+                symbol: interner.get_or_intern("bar")
+            })]
+        }]
+    );
+}
+
+#[test]
+fn multiple_attributes() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Function(function)) = parse_item(
+        "/// Docs.\n#[inline]\n#[deprecated(\"use g\", 2)]\npub fun f() {}",
+        &mut diagnostics,
+        &mut interner,
+    ) else {
+        panic!("expected a function");
+    };
+
+    assert!(diagnostics.is_empty());
+    assert!(function.visibility.span_of_pub().is_some());
+    assert_eq!(
+        function
+            .attributes
+            .iter()
+            .map(|attribute| (
+                interner
+                    .resolve(attribute.path.identifiers[0].symbol)
+                    .unwrap(),
+                attribute.arguments.len()
+            ))
+            .collect::<Vec<_>>(),
+        vec![("inline", 0), ("deprecated", 2)]
+    );
+}
+
+test!(attributes_on_items: "#[a] import b; #[c.d(1)] enum E {} #[e] trait T { #[f] fun g(); } #[h] impl T for E {} #[i] type A = B;");
+
+#[test]
+fn decorator() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_item("@derive struct Foo {}", &mut diagnostics, &mut interner),
+        Some(Item::Struct {
+            visibility: Visibility::private(),
+            name: IdentifierAst {
+                span: Span { start: 15, end: 18 },
+                symbol: interner.get_or_intern("Foo")
+            },
+            generic_parameters: None,
+            where_clause: None,
+            fields: vec![],
+            attributes: vec![Attribute {
+                span: Span { start: 0, end: 7 },
+                path: Path {
+                    span: Span { start: 1, end: 7 },
+                    identifiers: vec![IdentifierAst {
+                        span: Span { start: 1, end: 7 },
+                        symbol: interner.get_or_intern("derive")
+                    }]
+                },
+                arguments: vec![]
+            }],
+            docstring: None
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
+test!(decorators_on_items: "@a import b; @c.d #[e(1)] enum E {} trait T { @f fun g(); } @h @i fun j() {}");
+
+#[test]
+fn decorator_without_name() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "@ struct Foo {}
+@1 fun bar() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["unexpected `struct`", "unexpected <integer literal>"]
+    );
+    assert_eq!(module.items.len(), 2);
+}
+
+#[test]
+fn recovery() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "fun foo(a: ) {}
+fun bar(b: uint32 {}
+struct Valid {}
+#[inline]
+fun baz() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["unexpected `)`", "unexpected `{`"]
+    );
+    assert_eq!(
+        module
+            .items
+            .iter()
+            .map(|item| match item {
+                Item::Struct { name, .. } => name.symbol,
+                Item::Function(function) => function.name.symbol,
+                _ => unreachable!(),
+            })
+            .collect::<Vec<_>>(),
+        vec![
+            interner.get_or_intern("Valid"),
+            interner.get_or_intern("baz")
+        ]
+    );
+}
+
+#[test]
+fn recovery_skips_pattern_bindings() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "fun foo(a: ) { match a { x @ 1 => x } }
+@inline fun bar() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.as_str())
+            .collect::<Vec<_>>(),
+        vec!["unexpected `)`"]
+    );
+    assert!(matches!(
+        &module.items[..],
+        [Item::Function(function)] if function.attributes.len() == 1
+    ));
+}
+
+#[test]
+fn constant() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_item(
+            "pub const MAX_SIZE: uint32 = 1024;",
+            &mut diagnostics,
+            &mut interner
+        ),
+        Some(Item::Const {
+            visibility: Visibility::public(Span { start: 0, end: 3 }),
+            name: IdentifierAst {
+                span: Span { start: 10, end: 18 },
+                symbol: interner.get_or_intern("MAX_SIZE")
+            },
+            ty: Type::Path(TypePath {
+                span: Span { start: 20, end: 26 },
+                segments: vec![TypePathSegment {
+                    span: Span { start: 20, end: 26 },
+                    path: Path {
+                        span: Span { start: 20, end: 26 },
+                        identifiers: vec![IdentifierAst {
+                            span: Span { start: 20, end: 26 },
+                            symbol: symbols::UINT32
+                        }]
+                    },
+                    generic_arguments: None
+                }]
+            }),
+            value: Expression::Literal(Literal::Integer {
+                value: 1024,
+                span: Span { start: 29, end: 33 }
+            }),
+            attributes: vec![],
+            docstring: None
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
+test!(r#static: "/// Greeting.\nstatic GREETING: String = \"hello\";");
+test!(constant_with_expression: "const LIMIT: uint32 = (1 + 2) * MAX_SIZE;");
+
+#[test]
+fn trailing_commas() {
+    let parse = |source: &str| {
+        let mut interner = Interner::default();
+        let mut diagnostics = vec![];
+
+        let module = parse_module(source, &mut diagnostics, &mut interner);
+        (module.items.len(), diagnostics.len())
+    };
+
+    for source in [
+        "fun f[T, U]() {}",
+        "fun f[T, U,]() {}",
+        "fun f(a: A, b: B) {}",
+        "fun f(a: A, b: B,) {}",
+        "fun f() { g(a, b) }",
+        "fun f() { g(a, b,) }",
+        "fun f() { g[A, B]() }",
+        "fun f() { g[A, B,]() }",
+        "type T = List[A, B];",
+        "type T = List[A, B,];",
+        "type T = (A, B);",
+        "type T = (A, B,);",
+    ] {
+        assert_eq!(parse(source), (1, 0), "{source}");
+    }
+
+    for source in [
+        "fun f[,]() {}",
+        "fun f(,) {}",
+        "fun f() { g(,) }",
+        "fun f() { g[,]() }",
+        "type T = List[,];",
+        "type T = (,);",
+        "fun f(a: A,,) {}",
+    ] {
+        assert_ne!(parse(source).1, 0, "{source}");
+    }
+}
+
+#[test]
+fn public_items() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "import std.io;
+
+pub struct Person { name: String }
+
+struct Secret { value: String }
+
+impl Person {
+    pub fun new(name: String): Person { Person { name } }
+}
+
+fun helper() {}
+
+pub fun main() {}",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert!(diagnostics.is_empty());
+
+    let names = module
+        .public_items()
+        .map(|item| match item {
+            Item::Struct { name, .. } => name.symbol,
+            Item::Function(function) => function.name.symbol,
+            _ => unreachable!(),
+        })
+        .map(|symbol| interner.resolve(symbol).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(names, vec!["Person", "main"]);
+}
+
+#[test]
+fn methods_and_associated_functions() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let Some(Item::Impl(implementation)) = parse_item(
+        "impl Person {
+    fun new(name: String): Person { Person { name } }
+    fun name(self): String { self.name }
+}",
+        &mut diagnostics,
+        &mut interner,
+    ) else {
+        panic!("expected impl");
+    };
+
+    assert!(diagnostics.is_empty());
+
+    let functions = implementation
+        .items
+        .iter()
+        .map(|item| match item {
+            TraitItem::AssociatedFunction(function) => (
+                interner.resolve(function.name.symbol).unwrap(),
+                function.is_method(),
+            ),
+            TraitItem::TypeAlias(..) => unreachable!(),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(functions, vec![("new", false), ("name", true)]);
+}
+
+#[test]
+fn item_kinds() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "import std.io;
+enum Option[T] { Some(T), None }
+fun main() {}
+trait Foo {}
+impl Foo for Bar {}
+struct Person { name: String }
+struct Wrapper(uint32);
+type Id = uint32;
+const MAX: uint32 = 1;
+static COUNTER: uint32 = 0;",
+        &mut diagnostics,
+        &mut interner,
+    );
+
+    assert!(diagnostics.is_empty());
+    assert_eq!(
+        module.items.iter().map(ItemKind::from).collect::<Vec<_>>(),
+        vec![
+            ItemKind::Import,
+            ItemKind::Enum,
+            ItemKind::Function,
+            ItemKind::Trait,
+            ItemKind::Impl,
+            ItemKind::Struct,
+            ItemKind::Struct,
+            ItemKind::TypeAlias,
+            ItemKind::Const,
+            ItemKind::Static,
+        ]
+    );
+}
@@ -0,0 +1,152 @@
+use ry_ast::Expression;
+use ry_filesystem::span::Spanned;
+use ry_interner::Interner;
+use ry_parser::parse_expression;
+
+/// Prints an expression with every operator application wrapped in
+/// parentheses, e.g. `(1 + (2 * 3))`. Operands, that are not operator
+/// applications, are printed as they appear in the source.
+fn parenthesize(source: &str, expression: &Expression) -> String {
+    let text = |node: &dyn Spanned| {
+        let span = node.span();
+        source[span.start..span.end].to_owned()
+    };
+
+    match expression {
+        Expression::Binary {
+            left,
+            operator,
+            right,
+            ..
+        } => format!(
+            "({} {} {})",
+            parenthesize(source, left),
+            text(operator),
+            parenthesize(source, right)
+        ),
+        Expression::Prefix {
+            inner, operator, ..
+        } => format!("({}{})", text(operator), parenthesize(source, inner)),
+        Expression::Postfix {
+            inner, operator, ..
+        } => format!("({}{})", parenthesize(source, inner), text(operator)),
+        Expression::As { left, right, .. } => {
+            format!("({} as {})", parenthesize(source, left), text(right))
+        }
+        Expression::Range {
+            start,
+            end,
+            inclusive,
+            ..
+        } => format!(
+            "({}{}{})",
+            start
+                .as_ref()
+                .map_or_else(String::new, |start| parenthesize(source, start)),
+            if *inclusive { "..=" } else { ".." },
+            end.as_ref()
+                .map_or_else(String::new, |end| parenthesize(source, end)),
+        ),
+        _ => text(expression),
+    }
+}
+
+/// Parses an expression and asserts its fully parenthesized form.
+#[track_caller]
+fn assert_parse(source: &str, expected: &str) {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let expression = parse_expression(source, &mut diagnostics, &mut interner)
+        .unwrap_or_else(|| panic!("failed to parse `{source}`"));
+    assert!(diagnostics.is_empty(), "diagnostics for `{source}`");
+
+    assert_eq!(parenthesize(source, &expression), expected, "`{source}`");
+}
+
+#[test]
+fn arithmetic() {
+    for (source, expected) in [
+        ("1 + 2 * 3", "(1 + (2 * 3))"),
+        ("1 * 2 + 3", "((1 * 2) + 3)"),
+        ("1 - 2 - 3", "((1 - 2) - 3)"),
+        ("1 / 2 * 3", "((1 / 2) * 3)"),
+        ("1 + 2 % 3", "(1 + (2 % 3))"),
+        ("2 * 3 % 4", "(2 * (3 % 4))"),
+        ("2 * 3 ** 4", "(2 * (3 ** 4))"),
+        ("2 ** 3 % 4", "(2 ** (3 % 4))"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
+
+#[test]
+fn shifts_and_bitwise() {
+    for (source, expected) in [
+        ("1 << 2 + 3", "(1 << (2 + 3))"),
+        ("a >> 1 < b", "((a >> 1) < b)"),
+        ("a & b | c", "((a & b) | c)"),
+        ("a | b & c", "(a | (b & c))"),
+        ("a & b == c", "(a & (b == c))"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
+
+#[test]
+fn comparison_and_logical() {
+    for (source, expected) in [
+        ("a < b == c > d", "((a < b) == (c > d))"),
+        ("a <= b != c >= d", "((a <= b) != (c >= d))"),
+        ("a == b && c", "((a == b) && c)"),
+        ("a || b && c", "(a || (b && c))"),
+        ("a && b || c", "((a && b) || c)"),
+        ("a | b || c", "((a | b) || c)"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
+
+#[test]
+fn assignment() {
+    for (source, expected) in [
+        ("a = b + c", "(a = (b + c))"),
+        ("a += b * c", "(a += (b * c))"),
+        ("a -= b || c", "(a -= (b || c))"),
+        ("a *= b", "(a *= b)"),
+        ("a /= b - c", "(a /= (b - c))"),
+        ("a |= b | c", "(a |= (b | c))"),
+        ("a &= b & c", "(a &= (b & c))"),
+        ("a %= b % c", "(a %= (b % c))"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
+
+#[test]
+fn unary_and_cast() {
+    for (source, expected) in [
+        ("-a * b", "((-a) * b)"),
+        ("!a && b", "((!a) && b)"),
+        ("a? + b", "((a?) + b)"),
+        ("a as uint8 + b", "((a as uint8) + b)"),
+        ("a + b as uint8", "(a + (b as uint8))"),
+        ("a as B as C", "((a as B) as C)"),
+        ("-a as B", "((-a) as B)"),
+        ("a.b * c(d)", "(a.b * c(d))"),
+        ("a * b ! c", "(a * (b ! c))"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
+
+#[test]
+fn range() {
+    for (source, expected) in [
+        ("a..b + 1", "(a..(b + 1))"),
+        ("a + 1..=b", "((a + 1)..=b)"),
+        ("a == b..c", "((a == b)..c)"),
+    ] {
+        assert_parse(source, expected);
+    }
+}
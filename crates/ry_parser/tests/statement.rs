@@ -1,7 +1,9 @@
-use ry_ast::{Expression, IdentifierAst, Literal, Pattern, Statement};
-use ry_filesystem::span::Span;
+use ry_ast::{
+    serialize::Serializer, visit::Visitor, Expression, IdentifierAst, Literal, Pattern, Statement,
+};
+use ry_filesystem::span::{Span, Spanned};
 use ry_interner::Interner;
-use ry_parser::parse_statement;
+use ry_parser::{parse_pattern, parse_statement};
 
 mod r#macro;
 
@@ -32,6 +34,38 @@ fn defer() {
     );
 }
 
+#[test]
+fn r#return() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_statement("return 42;", &mut diagnostics, &mut interner),
+        Some(Statement::Return {
+            span: Span { start: 0, end: 9 },
+            expression: Some(Expression::Literal(Literal::Integer {
+                value: 42,
+                span: Span { start: 7, end: 9 }
+            }))
+        })
+    );
+}
+
+#[test]
+fn return_without_expression() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_statement("return;", &mut diagnostics, &mut interner),
+        Some(Statement::Return {
+            span: Span { start: 0, end: 6 },
+            expression: None
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn r#break() {
     let mut interner = Interner::default();
@@ -40,11 +74,30 @@ fn r#break() {
     assert_eq!(
         parse_statement("break;", &mut diagnostics, &mut interner),
         Some(Statement::Break {
-            span: Span { start: 0, end: 5 }
+            span: Span { start: 0, end: 5 },
+            label: None
         })
     );
 }
 
+#[test]
+fn labeled_break() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_statement("break 'outer;", &mut diagnostics, &mut interner),
+        Some(Statement::Break {
+            span: Span { start: 0, end: 12 },
+            label: Some(IdentifierAst {
+                span: Span { start: 6, end: 12 },
+                symbol: interner.get_or_intern("outer")
+            })
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn r#continue() {
     let mut interner = Interner::default();
@@ -53,11 +106,30 @@ fn r#continue() {
     assert_eq!(
         parse_statement("continue;", &mut diagnostics, &mut interner),
         Some(Statement::Continue {
-            span: Span { start: 0, end: 8 }
+            span: Span { start: 0, end: 8 },
+            label: None
         })
     );
 }
 
+#[test]
+fn labeled_continue() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_statement("continue 'a;", &mut diagnostics, &mut interner),
+        Some(Statement::Continue {
+            span: Span { start: 0, end: 11 },
+            label: Some(IdentifierAst {
+                span: Span { start: 9, end: 11 },
+                symbol: interner.get_or_intern("a")
+            })
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
 #[test]
 fn r#let() {
     let mut interner = Interner::default();
@@ -82,3 +154,87 @@ fn r#let() {
         })
     );
 }
+
+#[test]
+fn let_wildcard() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_statement("let _ = 1;", &mut diagnostics, &mut interner),
+        Some(Statement::Let {
+            pattern: Pattern::Wildcard {
+                span: Span { start: 4, end: 5 }
+            },
+            value: Expression::Literal(Literal::Integer {
+                value: 1,
+                span: Span { start: 8, end: 9 }
+            }),
+            ty: None
+        })
+    );
+    assert!(diagnostics.is_empty());
+}
+
+#[test]
+fn wildcard_is_not_identifier() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    assert_eq!(
+        parse_pattern("_", &mut diagnostics, &mut interner),
+        Some(Pattern::Wildcard {
+            span: Span { start: 0, end: 1 }
+        })
+    );
+    assert!(matches!(
+        parse_pattern("_x", &mut diagnostics, &mut interner),
+        Some(Pattern::Identifier { .. })
+    ));
+
+    for (source, kind) in [("_", "WILDCARD_PATTERN"), ("x", "IDENTIFIER_PATTERN")] {
+        let pattern = parse_pattern(source, &mut diagnostics, &mut interner)
+            .expect("failed to parse the pattern");
+
+        let mut serializer = Serializer::new(&interner);
+        serializer.visit_pattern(&pattern);
+        assert!(serializer.output().trim_start().starts_with(kind));
+    }
+
+    assert!(diagnostics.is_empty());
+}
+
+/// Returns the source text of any node.
+fn highlight<'s, T: Spanned>(source: &'s str, node: &T) -> &'s str {
+    node.span().slice_or_empty(source)
+}
+
+#[test]
+fn spanned() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let source = "let (a, _): (A, B) = f(1) + 2;";
+    let statement = parse_statement(source, &mut diagnostics, &mut interner)
+        .expect("failed to parse the statement");
+    let Statement::Let { pattern, value, ty } = &statement else {
+        panic!("expected a let statement");
+    };
+
+    assert_eq!(highlight(source, pattern), "(a, _)");
+    assert_eq!(
+        highlight(source, ty.as_ref().expect("type is missing")),
+        "(A, B)"
+    );
+    assert_eq!(highlight(source, value), "f(1) + 2");
+    assert_eq!(highlight(source, &statement), "(a, _): (A, B) = f(1) + 2");
+
+    let Expression::Binary {
+        operator, right, ..
+    } = value
+    else {
+        panic!("expected a binary expression");
+    };
+    assert_eq!(highlight(source, operator), "+");
+    assert_eq!(highlight(source, right.as_ref()), "2");
+}
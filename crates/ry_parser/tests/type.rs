@@ -0,0 +1,168 @@
+use ry_ast::{
+    serialize::{display_type, SExprSerializer, Serializer},
+    visit::Visitor,
+    Type,
+};
+use ry_filesystem::span::Span;
+use ry_interner::Interner;
+use ry_parser::parse_type;
+
+fn parse(source: &str, interner: &mut Interner) -> Type {
+    let mut diagnostics = vec![];
+
+    let ty = parse_type(source, &mut diagnostics, interner).expect("failed to parse the type");
+    assert!(diagnostics.is_empty());
+
+    ty
+}
+
+fn display(source: &str) -> String {
+    let mut interner = Interner::default();
+    let ty = parse(source, &mut interner);
+
+    display_type(&ty, &interner)
+}
+
+#[test]
+fn path() {
+    assert_eq!(display("uint32"), "uint32");
+    assert_eq!(display("std.io.File"), "std.io.File");
+}
+
+#[test]
+fn nested_generics() {
+    assert_eq!(
+        display("HashMap[String, List[Option[uint32]]]"),
+        "HashMap[String, List[Option[uint32]]]"
+    );
+    assert_eq!(
+        display("Iterator[Item = List[uint32]].Item"),
+        "Iterator[Item = List[uint32]].Item"
+    );
+}
+
+#[test]
+fn tuple_and_parenthesized() {
+    assert_eq!(display("()"), "()");
+    assert_eq!(display("(uint32,)"), "(uint32,)");
+    assert_eq!(display("(uint32)"), "(uint32)");
+    assert_eq!(display("(uint32, List[String])"), "(uint32, List[String])");
+}
+
+#[test]
+fn unit() {
+    let mut interner = Interner::default();
+
+    let ty = parse("()", &mut interner);
+    assert_eq!(
+        ty,
+        Type::Tuple {
+            span: Span { start: 0, end: 2 },
+            element_types: vec![]
+        }
+    );
+    assert!(ty.is_unit());
+
+    assert!(!parse("(uint32,)", &mut interner).is_unit());
+    assert!(!parse("(())", &mut interner).is_unit());
+    assert!(!parse("Unit", &mut interner).is_unit());
+}
+
+#[test]
+fn one_element_tuple_requires_top_level_comma() {
+    let mut interner = Interner::default();
+
+    assert!(matches!(
+        parse("(A)", &mut interner),
+        Type::Parenthesized { .. }
+    ));
+    assert!(matches!(
+        parse("(A,)", &mut interner),
+        Type::Tuple { element_types, .. } if element_types.len() == 1
+    ));
+    assert!(matches!(
+        parse("(List[A, B])", &mut interner),
+        Type::Parenthesized { .. }
+    ));
+}
+
+#[test]
+fn function() {
+    assert_eq!(display("(uint32, String): bool"), "(uint32, String): bool");
+    assert_eq!(
+        display("(List[uint32]): (uint32): bool"),
+        "(List[uint32]): (uint32): bool"
+    );
+}
+
+#[test]
+fn trait_object() {
+    assert_eq!(display("dyn Debug + Clone"), "dyn Debug + Clone");
+}
+
+#[test]
+fn qualified_path() {
+    assert_eq!(
+        display("[List[uint32] as IntoIterator].Item"),
+        "[List[uint32] as IntoIterator].Item"
+    );
+}
+
+#[test]
+fn reference() {
+    let mut interner = Interner::default();
+
+    assert!(matches!(
+        parse("&uint32", &mut interner),
+        Type::Reference { mutable: false, inner, .. } if matches!(*inner, Type::Path(..))
+    ));
+    assert!(matches!(
+        parse("&mut T", &mut interner),
+        Type::Reference { mutable: true, .. }
+    ));
+    assert!(matches!(
+        parse("&&mut T", &mut interner),
+        Type::Reference { mutable: false, inner, .. }
+            if matches!(*inner, Type::Reference { mutable: true, .. })
+    ));
+
+    assert_eq!(display("&uint32"), "&uint32");
+    assert_eq!(display("&mut List[T]"), "&mut List[T]");
+    assert_eq!(display("&&T"), "&&T");
+}
+
+#[test]
+fn array() {
+    let mut interner = Interner::default();
+
+    assert!(matches!(
+        parse("[uint8]", &mut interner),
+        Type::Array { size: None, .. }
+    ));
+    assert!(matches!(
+        parse("[uint8; 4]", &mut interner),
+        Type::Array { size: Some(..), .. }
+    ));
+
+    assert_eq!(display("[uint8]"), "[uint8]");
+    assert_eq!(display("[[uint8; 4]; N * 2]"), "[[uint8; 4]; N * 2]");
+    assert_eq!(display("[&mut T]"), "[&mut T]");
+}
+
+#[test]
+fn serialize_reference_and_array() {
+    let mut interner = Interner::default();
+    let ty = parse("&mut [uint8; 4]", &mut interner);
+
+    let mut serializer = Serializer::new(&interner);
+    serializer.visit_type(&ty);
+    assert!(serializer.output().contains("MUTABLE_REFERENCE_TYPE"));
+    assert!(serializer.output().contains("ARRAY_TYPE"));
+
+    let mut serializer = SExprSerializer::new(&interner);
+    serializer.visit_type(&ty);
+    assert_eq!(
+        serializer.take_output(),
+        "(mutable_reference_type (array_type (type uint8) (integer 4)))"
+    );
+}
@@ -0,0 +1,242 @@
+use ry_ast::{
+    unparse::unparse,
+    visit_mut::{walk_expression_mut, VisitorMut},
+    Expression,
+};
+use ry_interner::Interner;
+use ry_parser::parse_module;
+
+/// Removes all parenthesized expressions, so that the unparser has to
+/// insert parentheses by itself.
+struct RemoveParentheses;
+
+impl VisitorMut for RemoveParentheses {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+
+        if let Expression::Parenthesized { inner, .. } = expression {
+            *expression = *inner.clone();
+        }
+    }
+}
+
+/// Checks that unparsing of the parsed source gives the same source back,
+/// so that parsing it again yields an equal AST.
+fn assert_round_trip(source: &str) {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(source, &mut diagnostics, &mut interner);
+    assert!(diagnostics.is_empty());
+
+    let unparsed = unparse(&module, &interner);
+    assert_eq!(unparsed, source);
+
+    assert_eq!(
+        parse_module(&unparsed, &mut diagnostics, &mut interner),
+        module
+    );
+    assert!(diagnostics.is_empty());
+}
+
+/// Checks that unparsing of the parsed source gives an expected output.
+fn assert_unparse(source: &str, expected: &str) {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(source, &mut diagnostics, &mut interner);
+    assert!(diagnostics.is_empty());
+
+    assert_eq!(unparse(&module, &interner), expected);
+}
+
+#[test]
+fn function() {
+    assert_round_trip("fun f(x: uint32): uint32 { x + 1 }\n");
+    assert_round_trip("fun f(x: uint32 = 0, y: String = \"\".into()) {}\n");
+    assert_round_trip("pub fun foo[T: Into[String], B = Option[T]](a: B, b: (T, uint32)): T where T: Clone { a }\n");
+    assert_round_trip(
+        "fun main() {
+    let a = [1, 2, 3];
+    let b: String = \"a\\n\\$b\";
+    let c = {
+        let d = 1;
+        d + 1
+    };
+    while a.len() > 2 {
+        a.pop();
+        continue;
+    };
+    'outer: while true {
+        while false { break 'outer; };
+        continue 'outer;
+    };
+    for (k, v) in m {
+        println(k);
+        println(v);
+    };
+    if a.is_empty() { return 0; } else if b == \"\" { break; } else { defer close(a); };
+    return;
+}
+",
+    );
+}
+
+#[test]
+fn items() {
+    assert_round_trip(
+        "//! Module docs.
+
+import std.io as io;
+
+/// A person.
+pub struct Person[T] {
+    /// Name.
+    pub name: String,
+    age: T,
+}
+
+struct Wrapper(pub uint32, String);
+
+enum Option[T] {
+    Some(T),
+    None,
+    Named {
+        value: T,
+    },
+}
+
+enum Color {
+    Red = 1,
+    Green,
+    Blue = 1 << 2,
+}
+
+trait Into[T] {
+    fun into(self): T;
+
+    type Output: Debug = T;
+}
+
+impl[T] Into[String] for Person[T] {
+    fun into(self): String { self.name }
+}
+",
+    );
+}
+
+#[test]
+fn constants() {
+    assert_round_trip(
+        "/// Maximum size.
+pub const MAX_SIZE: uint32 = 1024 * 4;
+
+static COUNTER: uint32 = 0;
+",
+    );
+}
+
+#[test]
+fn type_aliases() {
+    assert_round_trip(
+        "type Foo[T] = Bar[T];
+
+type Foo[T] = Bar[T] where T: Clone;
+
+type Pair[K, V]: Debug = (K, V) where K: Hash + Eq, V = K;
+
+type Buffer = &mut [uint8; 4 * 1024];
+
+type Bytes = &[uint8];
+",
+    );
+}
+
+#[test]
+fn attributes() {
+    assert_round_trip(
+        "/// Docs.
+#[inline]
+#[deprecated(\"use g\", 2)]
+pub fun f() {}
+
+trait T {
+    #[test]
+    fun g();
+}
+",
+    );
+}
+
+#[test]
+fn expressions() {
+    assert_round_trip(
+        "fun f() {
+    let a = -(-b);
+    let b = (a + b) * c - d / e;
+    let c = a - (b - c);
+    let d = (-a)?.b(c)[uint32] as uint64;
+    let e = |x: uint32|: bool { x > 0 };
+    let f = Point { x: 1, y };
+    let g = (a..b, a..=b, ..b, a.., ..);
+    let h = t.0.1 + t.2.x;
+    let i = list[i + 1][0] + into[uint32](3);
+    match (a, b) {
+        (0, _) | (_, 0) => 0,
+        Point { x, .. } => x,
+        [a, ..] => a,
+        a @ 'c' => 1.5,
+        -1 | -2.5 => 0,
+    }
+}
+",
+    );
+}
+
+#[test]
+fn parentheses() {
+    assert_unparse(
+        "fun f() { ((a + b)) * ((c)) }",
+        "fun f() { ((a + b)) * ((c)) }\n",
+    );
+    assert_unparse(
+        "fun f() {\n if (Point { x: 1 }) { }\n}",
+        "fun f() {\n    if (Point { x: 1 }) {}\n}\n",
+    );
+}
+
+#[test]
+fn inserted_parentheses() {
+    let check = |source: &str, expected: &str| {
+        let mut interner = Interner::default();
+        let mut diagnostics = vec![];
+
+        let mut module = parse_module(source, &mut diagnostics, &mut interner);
+        assert!(diagnostics.is_empty());
+
+        RemoveParentheses.visit_module_mut(&mut module);
+        assert_eq!(unparse(&module, &interner), expected);
+    };
+
+    check("fun f() { (a - b) - c }", "fun f() { a - b - c }\n");
+    check("fun f() { a - (b - c) }", "fun f() { a - (b - c) }\n");
+    check(
+        "fun f() { (a * b) + (c * d) }",
+        "fun f() { a * b + c * d }\n",
+    );
+    check(
+        "fun f() { (a + b) * (c + d) }",
+        "fun f() { (a + b) * (c + d) }\n",
+    );
+    check("fun f() { -(a + b) }", "fun f() { -(a + b) }\n");
+    check("fun f() { (-a).b }", "fun f() { (-a).b }\n");
+    check("fun f() { (a.b)(c) }", "fun f() { a.b(c) }\n");
+    check("fun f() { (a + b) as T }", "fun f() { (a + b) as T }\n");
+    check("fun f() { (a as B) as C }", "fun f() { a as B as C }\n");
+    check("fun f() { (-a) as T }", "fun f() { -a as T }\n");
+    check("fun f() { (a + 1)..(b) }", "fun f() { a + 1..b }\n");
+    check("fun f() { (a..b).c() }", "fun f() { (a..b).c() }\n");
+    check("fun f() { x = (a = b).. }", "fun f() { x = (a = b).. }\n");
+    check("fun f() { a ** (b ** c) }", "fun f() { a ** b ** c }\n");
+    check("fun f() { (a ** b) ** c }", "fun f() { (a ** b) ** c }\n");
+}
@@ -0,0 +1,105 @@
+use ry_ast::{
+    visit_mut::{walk_expression_mut, VisitorMut},
+    BinaryOperator, Expression, RawBinaryOperator,
+};
+use ry_interner::Interner;
+use ry_parser::{parse_expression, parse_module};
+
+struct NoopVisitor;
+
+impl VisitorMut for NoopVisitor {}
+
+/// Rewrites `a += b` into `a = a + b`.
+struct DesugarCompoundAssignment;
+
+impl VisitorMut for DesugarCompoundAssignment {
+    fn visit_expression_mut(&mut self, expression: &mut Expression) {
+        walk_expression_mut(self, expression);
+
+        if let Expression::Binary {
+            span,
+            left,
+            operator:
+                BinaryOperator {
+                    span: operator_span,
+                    raw: RawBinaryOperator::PlusEq,
+                },
+            right,
+        } = expression
+        {
+            *expression = Expression::Binary {
+                span: *span,
+                left: left.clone(),
+                operator: BinaryOperator {
+                    span: *operator_span,
+                    raw: RawBinaryOperator::Eq,
+                },
+                right: Box::new(Expression::Binary {
+                    span: *span,
+                    left: left.clone(),
+                    operator: BinaryOperator {
+                        span: *operator_span,
+                        raw: RawBinaryOperator::Plus,
+                    },
+                    right: right.clone(),
+                }),
+            };
+        }
+    }
+}
+
+#[test]
+fn noop() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let module = parse_module(
+        "struct Point[T] where T: Add[T] { x: T, y: T }
+
+impl[T] Point[T] {
+    fun sum(self): T {
+        let Point { x, .. } = self;
+        match (x, self.y) {
+            (0, y) | (y, 0) => y,
+            _ => x + self.y,
+        }
+    }
+}
+
+fun main() {
+    let a = [1, 2, 3];
+    while true { if a.len() > 2 { break; } else { continue; } };
+    let f = |x: uint32| { x as uint64 };
+}",
+        &mut diagnostics,
+        &mut interner,
+    );
+    assert!(diagnostics.is_empty());
+
+    let mut visited = module.clone();
+    NoopVisitor.visit_module_mut(&mut visited);
+
+    assert_eq!(visited, module);
+}
+
+#[test]
+fn desugar_compound_assignment() {
+    let mut interner = Interner::default();
+    let mut diagnostics = vec![];
+
+    let mut expression = parse_expression("a += b", &mut diagnostics, &mut interner).unwrap();
+    DesugarCompoundAssignment.visit_expression_mut(&mut expression);
+
+    let Expression::Binary {
+        operator, right, ..
+    } = expression
+    else {
+        panic!("expected binary expression");
+    };
+    assert_eq!(operator.raw, RawBinaryOperator::Eq);
+
+    let Expression::Binary { operator, .. } = *right else {
+        panic!("expected binary expression");
+    };
+    assert_eq!(operator.raw, RawBinaryOperator::Plus);
+}
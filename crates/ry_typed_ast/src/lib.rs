@@ -260,6 +260,16 @@ pub enum Pattern {
     ///     ^^ rest pattern
     /// }
     Rest,
+
+    /// A wildcard pattern. Unlike an identifier pattern, doesn't bind
+    /// any variable.
+    ///
+    /// # Example
+    /// ```txt
+    /// let (a, _) = (1, 2);
+    ///         ^ wildcard pattern
+    /// ```
+    Wildcard,
 }
 
 /// Represents a pattern used inside of a struct pattern.